@@ -1,3 +1,4 @@
+use crate::data::WikiSyntax;
 use clap::Clap;
 use derive_more::{Display, Error};
 use directories::ProjectDirs;
@@ -25,12 +26,20 @@ pub struct Config {
 
     /// Wiki paths to load, monitor, and manipulate
     ///
-    /// Format is [name]:path with order mattering as first wiki has index 0,
-    /// second wiki has index 1, etc.
+    /// Format is [name:]path[,syntax=<syntax>][,ext=<ext>][,link_ext=<ext>]
+    /// with order mattering as first wiki has index 0, second wiki has index
+    /// 1, etc. A comma within the path is escaped as \, to keep it from
+    /// starting an option. Syntax is either vimwiki (default) or markdown,
+    /// ext overrides the file extensions given with --ext for the wiki, and
+    /// link_ext is the extension that links between pages are written with.
+    /// Markdown is an approximation rather than a markdown parser: files are
+    /// parsed as vimwiki with markdown-style emphasis, reference links and
+    /// indented code blocks enabled
     #[clap(long = "wiki", number_of_values = 1)]
     pub wikis: Vec<WikiConfig>,
 
-    /// Mode to run server (http = web; stdin = read input from stdin and reply on stdout)
+    /// Mode to run server (http = web; stdin = read input from stdin and reply on stdout;
+    /// schema = print the GraphQL schema to stdout and exit)
     #[clap(long, arg_enum, default_value = "http")]
     pub mode: Mode,
 
@@ -57,6 +66,45 @@ impl Config {
         Config::parse()
     }
 
+    /// Checks that the configuration can be used to run the server,
+    /// returning an error describing the first problem found
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        // Printing the schema does not use any other settings
+        if self.mode == Mode::Schema {
+            return Ok(());
+        }
+
+        for wiki in self.wikis.iter() {
+            if !wiki.path.exists() {
+                return Err(ConfigError::MissingWikiRoot {
+                    path: wiki.path.clone(),
+                });
+            }
+
+            if !wiki.path.is_dir() {
+                return Err(ConfigError::WikiRootNotDirectory {
+                    path: wiki.path.clone(),
+                });
+            }
+        }
+
+        if self.exts.is_empty() || self.exts.iter().any(|x| x.is_empty()) {
+            return Err(ConfigError::MissingExtensions);
+        }
+
+        if self.mode == Mode::Http {
+            if self.host.trim().is_empty() {
+                return Err(ConfigError::EmptyHost);
+            }
+
+            if self.port == 0 {
+                return Err(ConfigError::InvalidPort { port: self.port });
+            }
+        }
+
+        Ok(())
+    }
+
     /// The level to use for logging throughout the server
     pub fn log_level(&self) -> LevelFilter {
         match self.verbose {
@@ -68,11 +116,38 @@ impl Config {
     }
 }
 
-/// Represents the mode to run the server (input from stdin or HTTP)
+/// Represents problems with a configuration that prevent the server from
+/// running
+#[derive(Debug, Display, Error, PartialEq, Eq)]
+pub enum ConfigError {
+    #[display(
+        fmt = "Wiki root {} does not exist, check the path given to --wiki",
+        "path.display()"
+    )]
+    MissingWikiRoot { path: PathBuf },
+    #[display(
+        fmt = "Wiki root {} is not a directory, check the path given to --wiki",
+        "path.display()"
+    )]
+    WikiRootNotDirectory { path: PathBuf },
+    #[display(
+        fmt = "Port {} is out of range, --port must be between 1 and 65535",
+        port
+    )]
+    InvalidPort { port: u16 },
+    #[display(fmt = "Host is empty, --host is required in http mode")]
+    EmptyHost,
+    #[display(fmt = "No wiki file extensions, --ext cannot be empty")]
+    MissingExtensions,
+}
+
+/// Represents the mode to run the server (input from stdin or HTTP), or to
+/// print the schema of the server without running it
 #[derive(Clap, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Mode {
     Stdin,
     Http,
+    Schema,
 }
 
 /// Represents input information about a wiki
@@ -80,6 +155,17 @@ pub enum Mode {
 pub struct WikiConfig {
     pub name: Option<String>,
     pub path: PathBuf,
+
+    /// Syntax that the files of the wiki are parsed with
+    pub syntax: WikiSyntax,
+
+    /// Extension of the files of the wiki, overriding the extensions given
+    /// with --ext for this wiki
+    pub file_extension: Option<String>,
+
+    /// Extension that links between pages of the wiki are written with,
+    /// which can differ from the extension of the files themselves
+    pub link_extension: Option<String>,
 }
 
 /// Represents parsing errors that can occur for a wiki opt
@@ -89,31 +175,61 @@ pub enum ParseWikiConfigError {
     InvalidIndex,
     InvalidName,
     InvalidInput,
+    InvalidSyntax,
+    InvalidOption,
 }
 
 impl std::str::FromStr for WikiConfig {
     type Err = ParseWikiConfigError;
 
-    /// Parse input in form of [<name>]:path
+    /// Parse input in form of
+    /// [<name>:]path[,syntax=<syntax>][,ext=<ext>][,link_ext=<ext>], where
+    /// `\,` is a comma within the path or an option rather than a separator
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split(':').collect();
+        let options = split_unescaped(s, ',');
+        let mut options = options.iter().map(String::as_str);
+        let parts: Vec<&str> =
+            options.next().unwrap_or("").split(':').collect();
         let parts_len: usize = parts.len();
         if !(1..=2).contains(&parts_len) {
             return Err(Self::Err::InvalidInput);
         }
 
-        let instance = if parts.len() == 2 {
+        let mut instance = if parts.len() == 2 {
             Self {
                 name: Some(parts[0].to_string()),
                 path: PathBuf::from(parts[1]),
+                syntax: WikiSyntax::default(),
+                file_extension: None,
+                link_extension: None,
             }
         } else {
             Self {
                 name: None,
                 path: PathBuf::from(parts[0]),
+                syntax: WikiSyntax::default(),
+                file_extension: None,
+                link_extension: None,
             }
         };
 
+        for option in options {
+            let mut kv = option.splitn(2, '=');
+            match (kv.next(), kv.next()) {
+                (Some("syntax"), Some(syntax)) => {
+                    instance.syntax =
+                        syntax.parse().map_err(|_| Self::Err::InvalidSyntax)?;
+                }
+                (Some("ext"), Some(ext)) if !ext.is_empty() => {
+                    instance.file_extension = Some(ext.to_string());
+                }
+                (Some("link_ext"), Some(ext)) if !ext.is_empty() => {
+                    instance.link_extension = Some(ext.to_string());
+                }
+                _ => return Err(Self::Err::InvalidOption),
+            }
+        }
+
         // If name is not none, but is empty, return an error
         if instance
             .name
@@ -132,3 +248,136 @@ impl std::str::FromStr for WikiConfig {
         Ok(instance)
     }
 }
+
+/// Splits the input at each occurrence of the separator that is not escaped
+/// with a backslash, where an escaped separator is kept without its
+/// backslash and any other backslash is kept as is
+fn split_unescaped(s: &str, separator: char) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.peek() == Some(&separator) {
+            parts.last_mut().unwrap().push(separator);
+            chars.next();
+        } else if c == separator {
+            parts.push(String::new());
+        } else {
+            parts.last_mut().unwrap().push(c);
+        }
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_config(wikis: Vec<WikiConfig>) -> Config {
+        Config {
+            verbose: 0,
+            log_dir: None,
+            wikis,
+            mode: Mode::Http,
+            host: String::from("localhost"),
+            port: 8000,
+            exts: vec![String::from("wiki")],
+            cache_dir: PathBuf::new(),
+        }
+    }
+
+    #[test]
+    fn validate_should_fail_if_wiki_root_is_missing() {
+        let path = std::env::temp_dir()
+            .join(format!("vimwiki-server-missing-{}", std::process::id()));
+        let config = make_config(vec![WikiConfig {
+            name: None,
+            path: path.clone(),
+            syntax: WikiSyntax::default(),
+            file_extension: None,
+            link_extension: None,
+        }]);
+
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::MissingWikiRoot { path })
+        );
+    }
+
+    #[test]
+    fn validate_should_fail_if_port_is_out_of_range() {
+        let config = Config {
+            port: 0,
+            ..make_config(Vec::new())
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::InvalidPort { port: 0 })
+        );
+    }
+
+    #[test]
+    fn validate_should_succeed_if_wiki_roots_are_directories() {
+        let config = make_config(vec![WikiConfig {
+            name: None,
+            path: std::env::temp_dir(),
+            syntax: WikiSyntax::default(),
+            file_extension: None,
+            link_extension: None,
+        }]);
+
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn wiki_config_should_parse_syntax_and_extension_options() {
+        let path = std::env::temp_dir();
+        let input = format!(
+            "notes:{},syntax=markdown,ext=md,link_ext=html",
+            path.display()
+        );
+
+        assert_eq!(
+            input.parse::<WikiConfig>().unwrap(),
+            WikiConfig {
+                name: Some(String::from("notes")),
+                path,
+                syntax: WikiSyntax::Markdown,
+                file_extension: Some(String::from("md")),
+                link_extension: Some(String::from("html")),
+            }
+        );
+    }
+
+    #[test]
+    fn wiki_config_should_support_escaped_commas_within_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes, old");
+        std::fs::create_dir(&path).unwrap();
+        let input = format!(
+            "{},ext=md",
+            path.display().to_string().replace(',', "\\,")
+        );
+
+        assert_eq!(
+            input.parse::<WikiConfig>().unwrap(),
+            WikiConfig {
+                name: None,
+                path,
+                syntax: WikiSyntax::default(),
+                file_extension: Some(String::from("md")),
+                link_extension: None,
+            }
+        );
+    }
+
+    #[test]
+    fn wiki_config_should_fail_if_option_is_unknown() {
+        let input = format!("{},color=red", std::env::temp_dir().display());
+
+        assert!(matches!(
+            input.parse::<WikiConfig>(),
+            Err(ParseWikiConfigError::InvalidOption)
+        ));
+    }
+}
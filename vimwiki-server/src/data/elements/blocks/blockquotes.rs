@@ -13,6 +13,9 @@ pub struct Blockquote {
     region: Region,
     lines: Vec<String>,
 
+    /// Source of the quote as it would be read by humans without frills
+    attribution: Option<String>,
+
     /// Page containing the blockquote
     #[ent(edge)]
     page: Page,
@@ -30,16 +33,17 @@ impl<'a> FromVimwikiElement<'a> for Blockquote {
         parent_id: Option<Id>,
         element: Self::Element,
     ) -> Result<Self, GraphqlDatabaseError> {
+        let region = Region::from(element.region());
+        let blockquote = element.into_inner();
+
         GraphqlDatabaseError::wrap(
             Self::build()
-                .region(Region::from(element.region()))
+                .region(region)
                 .lines(
-                    element
-                        .into_inner()
-                        .lines
-                        .iter()
-                        .map(ToString::to_string)
-                        .collect(),
+                    blockquote.lines.iter().map(ToString::to_string).collect(),
+                )
+                .attribution(
+                    blockquote.attribution.as_ref().map(ToString::to_string),
                 )
                 .page(page_id)
                 .parent(parent_id)
@@ -60,6 +64,7 @@ mod tests {
             let element = vimwiki_blockquote! {r#"
                 > First line of text
                 > Second line of text
+                > -- Some Author
             "#};
             let region = Region::from(element.region());
             let ent = Blockquote::from_vimwiki_element(999, Some(123), element)
@@ -72,6 +77,7 @@ mod tests {
                     "Second line of text".to_string()
                 ],
             );
+            assert_eq!(ent.attribution(), &Some("Some Author".to_string()));
             assert_eq!(ent.region(), &region);
             assert_eq!(ent.page_id(), 999);
             assert_eq!(ent.parent_id(), Some(123));
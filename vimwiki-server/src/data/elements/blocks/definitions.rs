@@ -1,7 +1,7 @@
 use crate::data::{
-    Element, ElementQuery, FromVimwikiElement, GqlPageFilter,
-    GraphqlDatabaseError, InlineElement, InlineElementQuery, Page, PageQuery,
-    Region,
+    Element, ElementQuery, FromVimwikiElement, GqlListFilter, GqlPageFilter,
+    GraphqlDatabaseError, InlineElement, InlineElementQuery, List, ListQuery,
+    Page, PageQuery, Region,
 };
 use entity::*;
 use entity_async_graphql::*;
@@ -245,6 +245,10 @@ pub struct Definition {
     #[ent(field(computed = "self.to_string()"))]
     text: String,
 
+    /// The list nested beneath the definition, if it has one
+    #[ent(edge(policy = "deep"))]
+    list: Option<List>,
+
     /// The page containing this definition
     #[ent(edge)]
     page: Page,
@@ -285,13 +289,19 @@ impl<'a> FromVimwikiElement<'a> for Definition {
             Self::build()
                 .region(region)
                 .contents(Vec::new())
+                .list(None)
                 .page(page_id)
                 .parent(parent_id)
                 .finish_and_commit(),
         )?;
 
+        let (value, list) = match element.into_inner() {
+            v::Definition::Inline(value) => (value, None),
+            v::Definition::WithList { value, list } => (value, Some(list)),
+        };
+
         let mut contents = Vec::new();
-        for content in element.into_inner().into_inner().elements {
+        for content in value.into_inner().elements {
             contents.push(
                 InlineElement::from_vimwiki_element(
                     page_id,
@@ -303,6 +313,14 @@ impl<'a> FromVimwikiElement<'a> for Definition {
         }
 
         definition.set_contents_ids(contents);
+        if let Some(list) = list {
+            let list = List::from_vimwiki_element(
+                page_id,
+                Some(definition.id()),
+                list,
+            )?;
+            definition.set_list_id(Some(list.id()));
+        }
         definition
             .commit()
             .map_err(GraphqlDatabaseError::Database)?;
@@ -357,6 +375,7 @@ mod tests {
             for def in defs.iter() {
                 assert_eq!(def.page_id(), 999);
                 assert_eq!(def.parent_id(), Some(ent.id()));
+                assert_eq!(def.list_id(), None);
                 for content in def
                     .load_contents()
                     .expect("Failed to load definition contents")
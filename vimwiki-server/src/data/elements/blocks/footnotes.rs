@@ -0,0 +1,124 @@
+use crate::data::{
+    Element, ElementQuery, FromVimwikiElement, GqlPageFilter,
+    GraphqlDatabaseError, InlineElement, InlineElementQuery, Page, PageQuery,
+    Region,
+};
+
+use entity::*;
+use entity_async_graphql::*;
+use std::fmt;
+use vimwiki::{self as v, Located};
+
+#[gql_ent]
+pub struct FootnoteDef {
+    /// The segment of the document this footnote definition covers
+    #[ent(field(graphql(filter_untyped)))]
+    region: Region,
+
+    /// The label of the footnote being defined
+    label: String,
+
+    /// The content of the footnote as individual elements
+    #[ent(edge(policy = "deep", wrap, graphql(filter_untyped)))]
+    contents: Vec<InlineElement>,
+
+    /// The content of the footnote as it would be read by humans
+    /// without frills
+    #[ent(field(computed = "self.to_string()"))]
+    text: String,
+
+    /// The page containing this footnote definition
+    #[ent(edge)]
+    page: Page,
+
+    /// The parent element containing this footnote definition
+    #[ent(edge(policy = "shallow", wrap, graphql(filter_untyped)))]
+    parent: Option<Element>,
+}
+
+impl fmt::Display for FootnoteDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.load_contents() {
+            Ok(contents) => {
+                for content in contents {
+                    write!(f, "{}", content)?;
+                }
+                Ok(())
+            }
+            Err(x) => {
+                write!(f, "{}", x)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'a> FromVimwikiElement<'a> for FootnoteDef {
+    type Element = Located<v::FootnoteDef<'a>>;
+
+    fn from_vimwiki_element(
+        page_id: Id,
+        parent_id: Option<Id>,
+        element: Self::Element,
+    ) -> Result<Self, GraphqlDatabaseError> {
+        let region = Region::from(element.region());
+        let element = element.into_inner();
+
+        let mut ent = GraphqlDatabaseError::wrap(
+            Self::build()
+                .region(region)
+                .label(element.label.to_string())
+                .contents(Vec::new())
+                .page(page_id)
+                .parent(parent_id)
+                .finish_and_commit(),
+        )?;
+
+        let mut contents = Vec::new();
+        for content in element.content.elements {
+            contents.push(
+                InlineElement::from_vimwiki_element(
+                    page_id,
+                    Some(ent.id()),
+                    content,
+                )?
+                .id(),
+            );
+        }
+
+        ent.set_contents_ids(contents);
+        ent.commit().map_err(GraphqlDatabaseError::Database)?;
+
+        Ok(ent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use entity_inmemory::InmemoryDatabase;
+    use vimwiki_macros::*;
+
+    #[test]
+    fn should_fully_populate_from_vimwiki_element() {
+        global::with_db(InmemoryDatabase::default(), || {
+            let element = vimwiki_footnote_def!(r#"[^note]: *some* footnote"#);
+            let region = Region::from(element.region());
+            let ent =
+                FootnoteDef::from_vimwiki_element(999, Some(123), element)
+                    .expect("Failed to convert from element");
+
+            assert_eq!(ent.region(), &region);
+            assert_eq!(ent.label(), "note");
+            assert_eq!(ent.to_string(), "some footnote");
+            assert_eq!(ent.page_id(), 999);
+            assert_eq!(ent.parent_id(), Some(123));
+
+            for content in ent.load_contents().expect("Failed to load contents")
+            {
+                assert_eq!(content.page_id(), 999);
+                assert_eq!(content.parent_id(), Some(ent.id()));
+            }
+        });
+    }
+}
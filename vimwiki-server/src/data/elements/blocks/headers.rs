@@ -21,6 +21,9 @@ pub struct Header {
     /// Whether or not the header is centered
     centered: bool,
 
+    /// Explicit anchor id given to the header, if it has one
+    custom_id: Option<String>,
+
     /// The content within the header as individual elements
     #[ent(edge(policy = "deep", wrap, graphql(filter_untyped)))]
     contents: Vec<InlineElement>,
@@ -67,12 +70,18 @@ impl<'a> FromVimwikiElement<'a> for Header {
         let region = Region::from(element.region());
         let level = element.as_inner().level as i32;
         let centered = element.as_inner().centered;
+        let custom_id = element
+            .as_inner()
+            .custom_id
+            .as_ref()
+            .map(ToString::to_string);
 
         let mut ent = GraphqlDatabaseError::wrap(
             Self::build()
                 .region(region)
                 .level(level)
                 .centered(centered)
+                .custom_id(custom_id)
                 .contents(Vec::new())
                 .page(page_id)
                 .parent(parent_id)
@@ -115,6 +124,7 @@ mod tests {
             assert_eq!(ent.region(), &region);
             assert_eq!(*ent.level(), 3);
             assert_eq!(*ent.centered(), false);
+            assert_eq!(*ent.custom_id(), None);
             assert_eq!(ent.to_string(), "some header of mine");
             assert_eq!(ent.page_id(), 999);
             assert_eq!(ent.parent_id(), Some(123));
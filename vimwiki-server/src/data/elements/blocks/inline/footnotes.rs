@@ -0,0 +1,75 @@
+use crate::data::{
+    Element, ElementQuery, FromVimwikiElement, GqlPageFilter,
+    GraphqlDatabaseError, Page, PageQuery, Region,
+};
+use entity::*;
+use entity_async_graphql::*;
+use std::fmt;
+use vimwiki::{self as v, Located};
+
+/// Represents a single document reference to a footnote
+#[gql_ent]
+pub struct FootnoteRef {
+    /// The segment of the document this footnote reference covers
+    #[ent(field(graphql(filter_untyped)))]
+    region: Region,
+
+    /// The label of the footnote being referenced
+    label: String,
+
+    /// Page containing the element
+    #[ent(edge)]
+    page: Page,
+
+    /// Parent element to this element
+    #[ent(edge(policy = "shallow", wrap, graphql(filter_untyped)))]
+    parent: Option<Element>,
+}
+
+impl fmt::Display for FootnoteRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[^{}]", self.label())
+    }
+}
+
+impl<'a> FromVimwikiElement<'a> for FootnoteRef {
+    type Element = Located<v::FootnoteRef<'a>>;
+
+    fn from_vimwiki_element(
+        page_id: Id,
+        parent_id: Option<Id>,
+        element: Self::Element,
+    ) -> Result<Self, GraphqlDatabaseError> {
+        GraphqlDatabaseError::wrap(
+            Self::build()
+                .region(Region::from(element.region()))
+                .label(element.into_inner().label.to_string())
+                .page(page_id)
+                .parent(parent_id)
+                .finish_and_commit(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use entity_inmemory::InmemoryDatabase;
+    use vimwiki_macros::*;
+
+    #[test]
+    fn should_fully_populate_from_vimwiki_element() {
+        global::with_db(InmemoryDatabase::default(), || {
+            let element = vimwiki_footnote_ref!(r#"[^note]"#);
+            let region = Region::from(element.region());
+            let ent =
+                FootnoteRef::from_vimwiki_element(999, Some(123), element)
+                    .expect("Failed to convert from element");
+
+            assert_eq!(ent.region(), &region);
+            assert_eq!(ent.label(), "note");
+            assert_eq!(ent.page_id(), 999);
+            assert_eq!(ent.parent_id(), Some(123));
+        });
+    }
+}
@@ -0,0 +1,76 @@
+use crate::data::{
+    Element, ElementQuery, FromVimwikiElement, GqlPageFilter,
+    GraphqlDatabaseError, Page, PageQuery, Region,
+};
+use entity::*;
+use entity_async_graphql::*;
+use std::fmt;
+use vimwiki::{self as v, Located};
+
+/// Represents a single document hashtag
+#[gql_ent]
+pub struct Hashtag {
+    /// The segment of the document this hashtag covers
+    #[ent(field(graphql(filter_untyped)))]
+    region: Region,
+
+    /// The name of the hashtag without the leading `#`
+    name: String,
+
+    /// Page containing the element
+    #[ent(edge)]
+    page: Page,
+
+    /// Parent element to this element
+    #[ent(edge(policy = "shallow", wrap, graphql(filter_untyped)))]
+    parent: Option<Element>,
+}
+
+impl fmt::Display for Hashtag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.name())
+    }
+}
+
+impl<'a> FromVimwikiElement<'a> for Hashtag {
+    type Element = Located<v::Hashtag<'a>>;
+
+    fn from_vimwiki_element(
+        page_id: Id,
+        parent_id: Option<Id>,
+        element: Self::Element,
+    ) -> Result<Self, GraphqlDatabaseError> {
+        GraphqlDatabaseError::wrap(
+            Self::build()
+                .region(Region::from(element.region()))
+                .name(element.into_inner().name.to_string())
+                .page(page_id)
+                .parent(parent_id)
+                .finish_and_commit(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use entity_inmemory::InmemoryDatabase;
+
+    #[test]
+    fn should_fully_populate_from_vimwiki_element() {
+        global::with_db(InmemoryDatabase::default(), || {
+            let element = Located::new(
+                v::Hashtag::from("project/sub"),
+                v::Region::new(5, 12),
+            );
+            let region = Region::from(element.region());
+            let ent = Hashtag::from_vimwiki_element(999, Some(123), element)
+                .expect("Failed to convert from element");
+
+            assert_eq!(ent.region(), &region);
+            assert_eq!(ent.name(), "project/sub");
+            assert_eq!(ent.page_id(), 999);
+            assert_eq!(ent.parent_id(), Some(123));
+        });
+    }
+}
@@ -30,6 +30,7 @@ impl<'a> From<v::Description<'a>> for Description {
         match d {
             v::Description::Text(x) => Self::Text(x.to_string()),
             v::Description::Uri(x) => Self::Uri(Uri::from(x)),
+            v::Description::Inline(x) => Self::Text(x.to_string()),
         }
     }
 }
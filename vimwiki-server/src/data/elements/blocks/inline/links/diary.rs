@@ -14,9 +14,9 @@ pub struct DiaryLink {
     #[ent(field(graphql(filter_untyped)))]
     region: Region,
 
-    /// Date of diary entry
+    /// Date of diary entry, if it is a valid date
     #[ent(field(graphql(filter_untyped)))]
-    date: Date,
+    date: Option<Date>,
 
     /// Optional description associated with the link
     #[ent(field(graphql(filter_untyped)))]
@@ -37,9 +37,10 @@ pub struct DiaryLink {
 
 impl fmt::Display for DiaryLink {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.description().as_ref() {
-            Some(desc) => write!(f, "{}", desc),
-            None => write!(f, "{}", self.date()),
+        match (self.description().as_ref(), self.date().as_ref()) {
+            (Some(desc), _) => write!(f, "{}", desc),
+            (None, Some(date)) => write!(f, "{}", date),
+            (None, None) => Ok(()),
         }
     }
 }
@@ -58,7 +59,7 @@ impl<'a> FromVimwikiElement<'a> for DiaryLink {
         GraphqlDatabaseError::wrap(
             Self::build()
                 .region(region)
-                .date(Date::from(element.date))
+                .date(element.date.map(Date::from))
                 .description(element.description.map(Description::from))
                 .anchor(element.anchor.map(Anchor::from))
                 .page(page_id)
@@ -85,7 +86,10 @@ mod tests {
                 .expect("Failed to convert from element");
 
             assert_eq!(ent.region(), &region);
-            assert_eq!(ent.date(), &"2021-04-03".parse::<Date>().unwrap());
+            assert_eq!(
+                ent.date(),
+                &Some("2021-04-03".parse::<Date>().unwrap())
+            );
             assert_eq!(
                 ent.description(),
                 &Some(Description::Text(String::from("Some description")))
@@ -25,6 +25,9 @@ pub use external::*;
 mod transclusion;
 pub use transclusion::*;
 
+mod reference;
+pub use reference::*;
+
 #[gql_ent]
 #[derive(Debug, Display)]
 pub enum Link {
@@ -35,6 +38,7 @@ pub enum Link {
     Raw(RawLink),
     ExternalFile(ExternalFileLink),
     Transclusion(TransclusionLink),
+    Reference(ReferenceLink),
 }
 
 impl Link {
@@ -47,6 +51,7 @@ impl Link {
             Self::Raw(x) => x.page_id(),
             Self::ExternalFile(x) => x.page_id(),
             Self::Transclusion(x) => x.page_id(),
+            Self::Reference(x) => x.page_id(),
         }
     }
 
@@ -59,6 +64,7 @@ impl Link {
             Self::Raw(x) => x.parent_id(),
             Self::ExternalFile(x) => x.parent_id(),
             Self::Transclusion(x) => x.parent_id(),
+            Self::Reference(x) => x.parent_id(),
         }
     }
 }
@@ -118,6 +124,13 @@ impl<'a> FromVimwikiElement<'a> for Link {
                     Located::new(x, region),
                 )?)
             }
+            v::Link::Reference(x) => {
+                Self::Reference(ReferenceLink::from_vimwiki_element(
+                    page_id,
+                    parent_id,
+                    Located::new(x, region),
+                )?)
+            }
         })
     }
 }
@@ -1,5 +1,5 @@
 use crate::data::{
-    Element, ElementQuery, FromVimwikiElement, GqlPageFilter,
+    Description, Element, ElementQuery, FromVimwikiElement, GqlPageFilter,
     GraphqlDatabaseError, Page, PageQuery, Region, Uri,
 };
 use entity::*;
@@ -18,6 +18,10 @@ pub struct RawLink {
     #[ent(field(graphql(filter_untyped)))]
     uri: Uri,
 
+    /// Optional description associated with the link
+    #[ent(field(graphql(filter_untyped)))]
+    description: Option<Description>,
+
     /// Page containing the element
     #[ent(edge)]
     page: Page,
@@ -29,7 +33,10 @@ pub struct RawLink {
 
 impl fmt::Display for RawLink {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.uri())
+        match self.description().as_ref() {
+            Some(desc) => write!(f, "{}", desc),
+            None => write!(f, "{}", self.uri()),
+        }
     }
 }
 
@@ -42,10 +49,13 @@ impl<'a> FromVimwikiElement<'a> for RawLink {
         element: Self::Element,
     ) -> Result<Self, GraphqlDatabaseError> {
         let region = Region::from(element.region());
+        let element = element.into_inner();
+
         GraphqlDatabaseError::wrap(
             Self::build()
                 .region(region)
-                .uri(Uri::from(element.into_inner().uri))
+                .uri(Uri::from(element.uri))
+                .description(element.description.map(Description::from))
                 .page(page_id)
                 .parent(parent_id)
                 .finish_and_commit(),
@@ -72,6 +82,7 @@ mod tests {
                 ent.uri(),
                 &"https://example.com".parse::<Uri>().unwrap()
             );
+            assert_eq!(ent.description(), &None);
             assert_eq!(ent.page_id(), 999);
             assert_eq!(ent.parent_id(), Some(123));
         });
@@ -0,0 +1,97 @@
+use crate::data::{
+    Description, Element, ElementQuery, FromVimwikiElement, GqlPageFilter,
+    GraphqlDatabaseError, Page, PageQuery, Region,
+};
+use entity::*;
+use entity_async_graphql::*;
+use std::fmt;
+use vimwiki::{self as v, Located};
+
+/// Represents a single document reference link, whose target comes from
+/// the definition of its label within the document
+#[gql_ent]
+pub struct ReferenceLink {
+    /// The segment of the document this link covers
+    #[ent(field(graphql(filter_untyped)))]
+    region: Region,
+
+    /// Description associated with the link
+    #[ent(field(graphql(filter_untyped)))]
+    description: Description,
+
+    /// The label as written within the link, which is empty when the
+    /// description is used as the label
+    label: String,
+
+    /// The target taken from the definition of the label
+    target: String,
+
+    /// Page containing the element
+    #[ent(edge)]
+    page: Page,
+
+    /// Parent element to this element
+    #[ent(edge(policy = "shallow", wrap, graphql(filter_untyped)))]
+    parent: Option<Element>,
+}
+
+impl fmt::Display for ReferenceLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<'a> FromVimwikiElement<'a> for ReferenceLink {
+    type Element = Located<v::ReferenceLink<'a>>;
+
+    fn from_vimwiki_element(
+        page_id: Id,
+        parent_id: Option<Id>,
+        element: Self::Element,
+    ) -> Result<Self, GraphqlDatabaseError> {
+        let region = Region::from(element.region());
+        let element = element.into_inner();
+        GraphqlDatabaseError::wrap(
+            Self::build()
+                .region(region)
+                .description(Description::from(element.description))
+                .label(element.label.to_string())
+                .target(element.target.to_string())
+                .page(page_id)
+                .parent(parent_id)
+                .finish_and_commit(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use entity_inmemory::InmemoryDatabase;
+    use std::borrow::Cow;
+
+    #[test]
+    fn should_fully_populate_from_vimwiki_element() {
+        global::with_db(InmemoryDatabase::default(), || {
+            let element = Located::from(v::ReferenceLink::new(
+                v::Description::from("Some description"),
+                Cow::from("ref"),
+                Cow::from("https://example.com"),
+            ));
+            let region = Region::from(element.region());
+            let ent =
+                ReferenceLink::from_vimwiki_element(999, Some(123), element)
+                    .expect("Failed to convert from element");
+
+            assert_eq!(ent.region(), &region);
+            assert_eq!(
+                ent.description(),
+                &Description::Text(String::from("Some description"))
+            );
+            assert_eq!(ent.label(), "ref");
+            assert_eq!(ent.target(), "https://example.com");
+            assert_eq!(ent.page_id(), 999);
+            assert_eq!(ent.parent_id(), Some(123));
+        });
+    }
+}
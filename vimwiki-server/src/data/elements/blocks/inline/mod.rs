@@ -8,6 +8,10 @@ mod code;
 pub use code::*;
 mod comments;
 pub use comments::*;
+mod footnotes;
+pub use footnotes::*;
+mod hashtags;
+pub use hashtags::*;
 mod links;
 pub use links::*;
 mod math;
@@ -29,6 +33,8 @@ pub enum InlineElement {
     Tags(Tags),
     Code(CodeInline),
     Math(MathInline),
+    FootnoteRef(FootnoteRef),
+    Hashtag(Hashtag),
     #[ent(wrap)]
     #[graphql(flatten)]
     Comment(Comment),
@@ -44,6 +50,8 @@ impl InlineElement {
             Self::Tags(x) => x.page_id(),
             Self::Code(x) => x.page_id(),
             Self::Math(x) => x.page_id(),
+            Self::FootnoteRef(x) => x.page_id(),
+            Self::Hashtag(x) => x.page_id(),
             Self::Comment(x) => x.page_id(),
         }
     }
@@ -57,6 +65,8 @@ impl InlineElement {
             Self::Tags(x) => x.parent_id(),
             Self::Code(x) => x.parent_id(),
             Self::Math(x) => x.parent_id(),
+            Self::FootnoteRef(x) => x.parent_id(),
+            Self::Hashtag(x) => x.parent_id(),
             Self::Comment(x) => x.parent_id(),
         }
     }
@@ -121,6 +131,20 @@ impl<'a> FromVimwikiElement<'a> for InlineElement {
                     Located::new(x, region),
                 )?)
             }
+            v::InlineElement::FootnoteRef(x) => {
+                Self::FootnoteRef(FootnoteRef::from_vimwiki_element(
+                    page_id,
+                    parent_id,
+                    Located::new(x, region),
+                )?)
+            }
+            v::InlineElement::Hashtag(x) => {
+                Self::Hashtag(Hashtag::from_vimwiki_element(
+                    page_id,
+                    parent_id,
+                    Located::new(x, region),
+                )?)
+            }
             v::InlineElement::Comment(x) => {
                 Self::Comment(Comment::from_vimwiki_element(
                     page_id,
@@ -1,5 +1,5 @@
 use crate::data::{
-    Element, ElementQuery, FromVimwikiElement, GqlPageFilter,
+    Element, ElementQuery, FootnoteRef, FromVimwikiElement, GqlPageFilter,
     GraphqlDatabaseError, Keyword, Link, Page, PageQuery, Region, Text,
 };
 use derive_more::Display;
@@ -115,6 +115,7 @@ pub enum DecoratedTextContent {
     #[graphql(flatten)]
     Link(Link),
     DecoratedText(DecoratedText),
+    FootnoteRef(FootnoteRef),
 }
 
 impl DecoratedTextContent {
@@ -124,6 +125,7 @@ impl DecoratedTextContent {
             Self::Keyword(x) => x.page_id(),
             Self::Link(x) => x.page_id(),
             Self::DecoratedText(x) => x.page_id(),
+            Self::FootnoteRef(x) => x.page_id(),
         }
     }
 
@@ -133,6 +135,7 @@ impl DecoratedTextContent {
             Self::Keyword(x) => x.parent_id(),
             Self::Link(x) => x.parent_id(),
             Self::DecoratedText(x) => x.parent_id(),
+            Self::FootnoteRef(x) => x.parent_id(),
         }
     }
 }
@@ -175,6 +178,13 @@ impl<'a> FromVimwikiElement<'a> for DecoratedTextContent {
                     Located::new(x, region),
                 )?)
             }
+            v::DecoratedTextContent::FootnoteRef(x) => {
+                Self::FootnoteRef(FootnoteRef::from_vimwiki_element(
+                    page_id,
+                    parent_id,
+                    Located::new(x, region),
+                )?)
+            }
         })
     }
 }
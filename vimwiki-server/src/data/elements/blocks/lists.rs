@@ -408,6 +408,9 @@ pub enum ListItemTodoStatus {
     /// Flags list item as a TODO item that is partially complete (67-99%)
     PartiallyComplete3,
 
+    /// Flags list item as a TODO item that is being worked on
+    InProgress,
+
     /// Flags list item as a TODO item that is complete
     Complete,
 
@@ -9,6 +9,8 @@ mod definitions;
 pub use definitions::*;
 mod dividers;
 pub use dividers::*;
+mod footnotes;
+pub use footnotes::*;
 mod headers;
 pub use headers::*;
 mod inline;
@@ -23,6 +25,8 @@ mod placeholders;
 pub use placeholders::*;
 mod preformatted;
 pub use preformatted::*;
+mod signatures;
+pub use signatures::*;
 mod tables;
 pub use tables::*;
 
@@ -32,6 +36,7 @@ pub enum BlockElement {
     Blockquote(Blockquote),
     DefinitionList(DefinitionList),
     Divider(Divider),
+    FootnoteDef(FootnoteDef),
     Header(Header),
     List(List),
     Math(MathBlock),
@@ -40,6 +45,7 @@ pub enum BlockElement {
     #[graphql(flatten)]
     Placeholder(Placeholder),
     PreformattedText(PreformattedText),
+    Signature(Signature),
     Table(Table),
 }
 
@@ -49,12 +55,14 @@ impl BlockElement {
             Self::Blockquote(x) => x.page_id(),
             Self::DefinitionList(x) => x.page_id(),
             Self::Divider(x) => x.page_id(),
+            Self::FootnoteDef(x) => x.page_id(),
             Self::Header(x) => x.page_id(),
             Self::List(x) => x.page_id(),
             Self::Math(x) => x.page_id(),
             Self::Paragraph(x) => x.page_id(),
             Self::Placeholder(x) => x.page_id(),
             Self::PreformattedText(x) => x.page_id(),
+            Self::Signature(x) => x.page_id(),
             Self::Table(x) => x.page_id(),
         }
     }
@@ -64,12 +72,14 @@ impl BlockElement {
             Self::Blockquote(x) => x.parent_id(),
             Self::DefinitionList(x) => x.parent_id(),
             Self::Divider(x) => x.parent_id(),
+            Self::FootnoteDef(x) => x.parent_id(),
             Self::Header(x) => x.parent_id(),
             Self::List(x) => x.parent_id(),
             Self::Math(x) => x.parent_id(),
             Self::Paragraph(x) => x.parent_id(),
             Self::Placeholder(x) => x.parent_id(),
             Self::PreformattedText(x) => x.parent_id(),
+            Self::Signature(x) => x.parent_id(),
             Self::Table(x) => x.parent_id(),
         }
     }
@@ -146,6 +156,20 @@ impl<'a> FromVimwikiElement<'a> for BlockElement {
                     Located::new(x, region),
                 )?)
             }
+            v::BlockElement::FootnoteDef(x) => {
+                Self::from(FootnoteDef::from_vimwiki_element(
+                    page_id,
+                    parent_id,
+                    Located::new(x, region),
+                )?)
+            }
+            v::BlockElement::Signature(x) => {
+                Self::from(Signature::from_vimwiki_element(
+                    page_id,
+                    parent_id,
+                    Located::new(x, region),
+                )?)
+            }
             v::BlockElement::Placeholder(x) => {
                 Self::from(Placeholder::from_vimwiki_element(
                     page_id,
@@ -13,6 +13,8 @@ pub enum Placeholder {
     NoHtml(PlaceholderNoHtml),
     Template(PlaceholderTemplate),
     Date(PlaceholderDate),
+    Include(PlaceholderInclude),
+    LinkReference(PlaceholderLinkReference),
     Other(PlaceholderOther),
 }
 
@@ -23,6 +25,8 @@ impl Placeholder {
             Self::NoHtml(x) => x.region(),
             Self::Template(x) => x.region(),
             Self::Date(x) => x.region(),
+            Self::Include(x) => x.region(),
+            Self::LinkReference(x) => x.region(),
             Self::Other(x) => x.region(),
         }
     }
@@ -33,6 +37,8 @@ impl Placeholder {
             Self::NoHtml(x) => x.page_id(),
             Self::Template(x) => x.page_id(),
             Self::Date(x) => x.page_id(),
+            Self::Include(x) => x.page_id(),
+            Self::LinkReference(x) => x.page_id(),
             Self::Other(x) => x.page_id(),
         }
     }
@@ -43,6 +49,8 @@ impl Placeholder {
             Self::NoHtml(x) => x.parent_id(),
             Self::Template(x) => x.parent_id(),
             Self::Date(x) => x.parent_id(),
+            Self::Include(x) => x.parent_id(),
+            Self::LinkReference(x) => x.parent_id(),
             Self::Other(x) => x.parent_id(),
         }
     }
@@ -93,6 +101,27 @@ impl<'a> FromVimwikiElement<'a> for Placeholder {
                     .finish_and_commit(),
             )
             .map(Self::from),
+            v::Placeholder::Include(path) => GraphqlDatabaseError::wrap(
+                PlaceholderInclude::build()
+                    .region(region)
+                    .path(path.to_string())
+                    .page(page_id)
+                    .parent(parent_id)
+                    .finish_and_commit(),
+            )
+            .map(Self::from),
+            v::Placeholder::LinkReference { label, target } => {
+                GraphqlDatabaseError::wrap(
+                    PlaceholderLinkReference::build()
+                        .region(region)
+                        .label(label.to_string())
+                        .target(target.to_string())
+                        .page(page_id)
+                        .parent(parent_id)
+                        .finish_and_commit(),
+                )
+                .map(Self::from)
+            }
             v::Placeholder::Other { name, value } => {
                 GraphqlDatabaseError::wrap(
                     PlaceholderOther::build()
@@ -183,6 +212,48 @@ pub struct PlaceholderDate {
     parent: Option<Element>,
 }
 
+/// Represents a single document include placeholder
+#[gql_ent]
+pub struct PlaceholderInclude {
+    /// The segment of the document this placeholder covers
+    #[ent(field(graphql(filter_untyped)))]
+    region: Region,
+
+    /// The path of the file to include
+    path: String,
+
+    /// Page containing the placeholder
+    #[ent(edge)]
+    page: Page,
+
+    /// Parent element to this placeholder
+    #[ent(edge(policy = "shallow", wrap, graphql(filter_untyped)))]
+    parent: Option<Element>,
+}
+
+/// Represents a single document placeholder defining the target of
+/// reference links with a label
+#[gql_ent]
+pub struct PlaceholderLinkReference {
+    /// The segment of the document this placeholder covers
+    #[ent(field(graphql(filter_untyped)))]
+    region: Region,
+
+    /// The label that reference links use to refer to the target
+    label: String,
+
+    /// The target of reference links using the label
+    target: String,
+
+    /// Page containing the placeholder
+    #[ent(edge)]
+    page: Page,
+
+    /// Parent element to this placeholder
+    #[ent(edge(policy = "shallow", wrap, graphql(filter_untyped)))]
+    parent: Option<Element>,
+}
+
 /// Represents a single document other placeholder
 #[gql_ent]
 pub struct PlaceholderOther {
@@ -258,6 +329,17 @@ mod tests {
             assert_eq!(ent.parent_id(), Some(123));
             assert!(matches!(ent, Placeholder::Date(_)));
 
+            let element = vimwiki_placeholder!(r#"#include other.wiki"#);
+            let region = Region::from(element.region());
+            let ent =
+                Placeholder::from_vimwiki_element(999, Some(123), element)
+                    .expect("Failed to convert from element");
+
+            assert_eq!(ent.region(), &region);
+            assert_eq!(ent.page_id(), 999);
+            assert_eq!(ent.parent_id(), Some(123));
+            assert!(matches!(ent, Placeholder::Include(_)));
+
             let element = vimwiki_placeholder!(r#"%other text"#);
             let region = Region::from(element.region());
             let ent =
@@ -0,0 +1,78 @@
+use crate::data::{
+    Element, ElementQuery, FromVimwikiElement, GqlPageFilter,
+    GraphqlDatabaseError, Page, PageQuery, Region,
+};
+use entity::*;
+use entity_async_graphql::*;
+use std::fmt;
+use vimwiki::{self as v, Located};
+
+/// Represents a single document signature ending a page
+#[gql_ent]
+pub struct Signature {
+    /// The segment of the document this signature covers
+    #[ent(field(graphql(filter_untyped)))]
+    region: Region,
+
+    /// The text of the signature, excluding its leading dashes
+    text: String,
+
+    /// Page containing the element
+    #[ent(edge)]
+    page: Page,
+
+    /// Parent element to this element
+    #[ent(edge(policy = "shallow", wrap, graphql(filter_untyped)))]
+    parent: Option<Element>,
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+impl<'a> FromVimwikiElement<'a> for Signature {
+    type Element = Located<v::Signature<'a>>;
+
+    fn from_vimwiki_element(
+        page_id: Id,
+        parent_id: Option<Id>,
+        element: Self::Element,
+    ) -> Result<Self, GraphqlDatabaseError> {
+        GraphqlDatabaseError::wrap(
+            Self::build()
+                .region(Region::from(element.region()))
+                .text(element.into_inner().0.to_string())
+                .page(page_id)
+                .parent(parent_id)
+                .finish_and_commit(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use entity_inmemory::InmemoryDatabase;
+
+    #[test]
+    fn should_fully_populate_from_vimwiki_element() {
+        global::with_db(InmemoryDatabase::default(), || {
+            // NOTE: Signatures are opt-in when parsing, so we build the
+            //       element directly instead of using a macro
+            let element = Located::new(
+                v::Signature::from("Author, Date"),
+                v::Region::new(3, 15),
+            );
+            let region = Region::from(element.region());
+            let ent = Signature::from_vimwiki_element(999, Some(123), element)
+                .expect("Failed to convert from element");
+
+            assert_eq!(ent.region(), &region);
+            assert_eq!(ent.text(), "Author, Date");
+            assert_eq!(ent.page_id(), 999);
+            assert_eq!(ent.parent_id(), Some(123));
+        });
+    }
+}
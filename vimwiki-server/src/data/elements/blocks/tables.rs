@@ -23,6 +23,9 @@ pub struct Table {
     /// Whether or not the table is centered
     centered: bool,
 
+    /// Caption of the table as it would be read by humans without frills
+    caption: Option<String>,
+
     /// Page containing this table
     #[ent(edge)]
     page: Page,
@@ -42,11 +45,14 @@ impl<'a> FromVimwikiElement<'a> for Table {
     ) -> Result<Self, GraphqlDatabaseError> {
         let region = Region::from(element.region());
         let centered = element.as_inner().centered;
+        let caption =
+            element.as_inner().caption.as_ref().map(ToString::to_string);
 
         let mut ent = GraphqlDatabaseError::wrap(
             Self::build()
                 .region(region)
                 .centered(centered)
+                .caption(caption)
                 .rows(Vec::new())
                 .page(page_id)
                 .parent(parent_id)
@@ -416,6 +422,7 @@ mod tests {
 
             assert_eq!(ent.region(), &region);
             assert_eq!(ent.centered(), &false);
+            assert_eq!(ent.caption(), &None);
             assert_eq!(ent.page_id(), 999);
             assert_eq!(ent.parent_id(), Some(123));
 
@@ -1,14 +1,21 @@
 #![allow(clippy::large_enum_variant)]
 
-use crate::{database::gql_db, utils, Config};
+use crate::{
+    config::WikiConfig,
+    database::{gql_db, Store},
+    utils, Config,
+};
 use entity::{TypedPredicate as P, *};
 use entity_async_graphql::*;
+use serde::{Deserialize, Serialize};
 use sha1::{Digest, Sha1};
 use std::{
     convert::TryFrom,
     path::{Path, PathBuf},
+    time::UNIX_EPOCH,
 };
-use vimwiki::{self as v, Language, ParseError};
+use strum::{Display, EnumString};
+use vimwiki::{self as v, Language, ParseConfig, ParseError, ParseState};
 
 mod errors;
 pub use errors::*;
@@ -16,12 +23,93 @@ pub use errors::*;
 mod elements;
 pub use elements::*;
 
+/// Represents the syntax that the files of a wiki are written in
+#[derive(
+    async_graphql::Enum,
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    Display,
+    EnumString,
+    Serialize,
+    Deserialize,
+    Default,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum WikiSyntax {
+    /// Files are parsed as vimwiki
+    #[default]
+    Vimwiki,
+
+    /// Files are parsed as vimwiki along with the markdown-style reference
+    /// links, indented code blocks and emphasis supported by the parser, as
+    /// there is no parser for markdown itself
+    Markdown,
+}
+
+impl WikiSyntax {
+    /// Returns the configuration used to parse files written in this syntax
+    pub fn parse_config(self) -> ParseConfig {
+        match self {
+            Self::Vimwiki => ParseConfig::default(),
+            Self::Markdown => ParseConfig {
+                reference_links: true,
+                indented_code_blocks: true,
+                markdown_emphasis: true,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Parses the text of a file written in this syntax into a page
+    pub fn parse_page(
+        self,
+        text: &str,
+    ) -> async_graphql::Result<v::Page<'static>> {
+        let state = ParseState::new(self.parse_config());
+        Language::from_vimwiki_str(text)
+            .parse_with_state(&state)
+            .map(v::Page::into_owned)
+            .map_err(|x: ParseError| async_graphql::Error::new(x.to_string()))
+    }
+}
+
+impl ValueLike for WikiSyntax {
+    fn into_value(self) -> Value {
+        Value::from(self.to_string())
+    }
+
+    fn try_from_value(value: Value) -> Result<Self, Value> {
+        match value {
+            Value::Text(x) => x.as_str().parse().map_err(|_| Value::Text(x)),
+            x => Err(x),
+        }
+    }
+}
+
 #[gql_ent]
 pub struct Wiki {
     index: usize,
     name: Option<String>,
     path: String,
 
+    /// Syntax that the files of the wiki are parsed with
+    #[ent(field(mutable, graphql(filter_untyped)))]
+    syntax: WikiSyntax,
+
+    /// Extension of the files of the wiki, used in place of the extensions
+    /// given when loading the wiki if present
+    #[ent(field(mutable))]
+    file_extension: Option<String>,
+
+    /// Extension that links between pages of the wiki are written with,
+    /// which can differ from the extension of the files themselves
+    #[ent(field(mutable))]
+    link_extension: Option<String>,
+
     #[ent(edge(policy = "deep"))]
     files: Vec<ParsedFile>,
 }
@@ -29,6 +117,7 @@ pub struct Wiki {
 impl Wiki {
     pub async fn load_all_from_config<F1, F2, F3, R1>(
         config: &Config,
+        store: &dyn Store,
         before_loading_files: F1,
         on_file_loaded: F2,
         after_loading_files: F3,
@@ -44,8 +133,8 @@ impl Wiki {
             wikis.push(
                 Self::load(
                     i,
-                    &wc.path,
-                    wc.name.as_ref(),
+                    wc,
+                    store,
                     &config.exts,
                     before_loading_files,
                     on_file_loaded,
@@ -57,8 +146,12 @@ impl Wiki {
         Ok(wikis)
     }
 
+    /// Loads the wiki described by the config, putting each of its files
+    /// into the store to be parsed with the syntax of the wiki. Files are
+    /// those with the file extension of the wiki if it has one, otherwise
+    /// those with any of the extensions
+    #[allow(clippy::too_many_arguments)]
     pub async fn load<
-        N: AsRef<str>,
         E: AsRef<str>,
         F1: Fn(usize) -> R1,
         F2: Fn(&R1, usize, &Path),
@@ -66,28 +159,61 @@ impl Wiki {
         R1,
     >(
         index: usize,
-        path: impl AsRef<Path>,
-        name: Option<N>,
+        config: &WikiConfig,
+        store: &dyn Store,
         exts: &[E],
         before_loading_files: F1,
         on_file_loaded: F2,
         after_loading_files: F3,
     ) -> async_graphql::Result<Self> {
-        let c_path: PathBuf = tokio::fs::canonicalize(path.as_ref())
+        let c_path: PathBuf = tokio::fs::canonicalize(config.path.as_path())
             .await
             .map_err(|x| async_graphql::Error::new(x.to_string()))?;
 
-        let paths = utils::walk_and_resolve_paths(c_path.as_path(), exts);
+        // NOTE: Files look up the syntax of the wiki containing them when
+        //       parsed, so the wiki is stored before its files are loaded
+        let mut wiki =
+            Self::load_without_files(index, c_path.as_path(), config)?;
+
+        let paths = match config.file_extension.as_deref() {
+            Some(ext) => {
+                utils::walk_and_resolve_paths(c_path.as_path(), &[ext])
+            }
+            None => utils::walk_and_resolve_paths(c_path.as_path(), exts),
+        };
         let tracker = before_loading_files(paths.len());
 
         let mut file_ids = Vec::new();
         for (i, path) in paths.into_iter().enumerate() {
-            file_ids.push(ParsedFile::load(path.as_path()).await?.id());
+            store.put_page(path.as_path()).await?;
+
+            // NOTE: Only a store keeping files within the database has ents
+            //       that the wiki can refer to
+            if let Some(file) = ParsedFile::find(path.as_path()).await? {
+                file_ids.push(file.id());
+            }
             on_file_loaded(&tracker, i, path.as_path());
         }
         after_loading_files(tracker);
 
-        // Check if the wiki already exists, and if so update its files
+        wiki.set_files_ids(file_ids);
+        wiki.commit()
+            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
+        Ok(wiki)
+    }
+
+    /// Stores the wiki at the given path with its settings, keeping the
+    /// files of the wiki if it was already stored
+    fn load_without_files(
+        index: usize,
+        c_path: &Path,
+        config: &WikiConfig,
+    ) -> async_graphql::Result<Self> {
+        let syntax = config.syntax;
+        let file_extension = config.file_extension.clone();
+        let link_extension = config.link_extension.clone();
+
+        // Check if the wiki already exists, and if so update its settings
         let maybe_wiki = gql_db()?
             .find_all_typed::<Wiki>(
                 Wiki::query()
@@ -99,7 +225,9 @@ impl Wiki {
             .next();
 
         if let Some(mut wiki) = maybe_wiki {
-            wiki.set_files_ids(file_ids);
+            wiki.set_syntax(syntax);
+            wiki.set_file_extension(file_extension);
+            wiki.set_link_extension(link_extension);
             let _ = wiki
                 .commit()
                 .map_err(|x| async_graphql::Error::new(x.to_string()))?;
@@ -108,14 +236,31 @@ impl Wiki {
             GraphqlDatabaseError::wrap(
                 Self::build()
                     .index(index)
-                    .name(name.map(|x| x.as_ref().to_string()))
+                    .name(config.name.clone())
                     .path(c_path.to_string_lossy().to_string())
-                    .files(file_ids)
+                    .syntax(syntax)
+                    .file_extension(file_extension)
+                    .link_extension(link_extension)
+                    .files(Vec::new())
                     .finish_and_commit(),
             )
             .map_err(|x| async_graphql::Error::new(x.to_string()))
         }
     }
+
+    /// Returns the syntax of the wiki containing the file at the given path,
+    /// where the deepest wiki wins if wikis are nested. Files outside of any
+    /// wiki are parsed as vimwiki
+    pub fn syntax_for_path(path: &Path) -> async_graphql::Result<WikiSyntax> {
+        Ok(gql_db()?
+            .find_all_typed::<Wiki>(Wiki::query().into())
+            .map_err(|x| async_graphql::Error::new(x.to_string()))?
+            .into_iter()
+            .filter(|wiki| path.starts_with(wiki.path()))
+            .max_by_key(|wiki| wiki.path().len())
+            .map(|wiki| *wiki.syntax())
+            .unwrap_or_default())
+    }
 }
 
 #[gql_ent]
@@ -124,12 +269,37 @@ pub struct ParsedFile {
     path: String,
     checksum: String,
 
+    /// Milliseconds since the unix epoch when the file was last modified
+    #[ent(field(mutable))]
+    modified: u64,
+
+    /// Date of the diary entry when the file is named in the form of
+    /// `YYYY-MM-DD`, otherwise none
+    #[ent(field(graphql(filter_untyped)))]
+    diary_date: Option<Date>,
+
     #[ent(edge(policy = "deep"))]
     page: Page,
 }
 
 impl ParsedFile {
+    /// Returns true if the file is a diary entry, meaning that its name is
+    /// in the form of `YYYY-MM-DD`
+    pub fn is_diary(&self) -> bool {
+        self.diary_date().is_some()
+    }
+
+    /// Extracts the date of a diary entry from the name of the file at the
+    /// given path, returning none if the name is not in the form of
+    /// `YYYY-MM-DD`
+    pub fn diary_date_from_path(path: impl AsRef<Path>) -> Option<Date> {
+        path.as_ref().file_stem()?.to_str()?.parse().ok()
+    }
+
+    /// Creates the file at the given path with the contents and puts it into
+    /// the store, failing if the file exists unless overwriting it
     pub async fn create(
+        store: &dyn Store,
         path: impl AsRef<Path>,
         contents: impl AsRef<[u8]>,
         overwrite: bool,
@@ -149,26 +319,24 @@ impl ParsedFile {
             .await
             .map_err(|x| async_graphql::Error::new(x.to_string()))?;
 
-        Self::load(path).await
-    }
-
-    pub async fn load_all<P: AsRef<Path>>(
-        paths: &[P],
-    ) -> async_graphql::Result<Vec<Self>> {
-        let mut files = Vec::new();
-        for p in paths {
-            files.push(Self::load(p).await?);
-        }
-        Ok(files)
+        store.put_page(path.as_ref()).await?;
+        Self::find(path).await?.ok_or_else(|| {
+            async_graphql::Error::new("File missing after being stored")
+        })
     }
 
-    pub async fn load(path: impl AsRef<Path>) -> async_graphql::Result<Self> {
-        let c_path: PathBuf = tokio::fs::canonicalize(path)
+    /// Finds the file loaded from the given path, if there is one
+    pub async fn find(
+        path: impl AsRef<Path>,
+    ) -> async_graphql::Result<Option<Self>> {
+        // NOTE: A removed file can no longer be canonicalized, so we fall
+        //       back to the path as given
+        let path = path.as_ref();
+        let c_path = tokio::fs::canonicalize(path)
             .await
-            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
+            .unwrap_or_else(|_| path.to_path_buf());
 
-        // First, search for an existing ent at the specified path
-        let maybe_ent = gql_db()?
+        Ok(gql_db()?
             .find_all_typed::<ParsedFile>(
                 ParsedFile::query()
                     .where_path(P::equals(c_path.to_string_lossy().to_string()))
@@ -176,29 +344,96 @@ impl ParsedFile {
             )
             .map_err(|x| async_graphql::Error::new(x.to_string()))?
             .into_iter()
-            .next();
+            .next())
+    }
+
+    /// Reads the file at the given path and parses it with the syntax of the
+    /// wiki containing it, such as to walk a page that the database only
+    /// keeps as ents
+    pub async fn parse(
+        path: impl AsRef<Path>,
+    ) -> async_graphql::Result<v::Page<'static>> {
+        let path = path.as_ref();
+        let text = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
+        Wiki::syntax_for_path(path)?.parse_page(&text)
+    }
+
+    /// Moves the file loaded from one path to another path, such as when the
+    /// file is renamed
+    pub async fn rename(
+        from_path: impl AsRef<Path>,
+        to_path: impl AsRef<Path>,
+    ) -> async_graphql::Result<()> {
+        let c_to_path = tokio::fs::canonicalize(to_path)
+            .await
+            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
 
-        // Second, load the contents of the file into memory
+        if let Some(mut ent) = Self::find(from_path).await? {
+            ent.set_path(c_to_path.to_string_lossy().to_string());
+            ent.commit()
+                .map_err(|x| async_graphql::Error::new(x.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn load(path: impl AsRef<Path>) -> async_graphql::Result<Self> {
+        let c_path: PathBuf = tokio::fs::canonicalize(path)
+            .await
+            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
+
+        // First, search for an existing ent at the specified path
+        let maybe_ent = Self::find(c_path.as_path()).await?;
+
+        // Second, load the contents of the file into memory, where the
+        // checksum covers the syntax so that a file is parsed again if the
+        // syntax of its wiki changes
         let text = tokio::fs::read_to_string(c_path.as_path())
             .await
             .map_err(|x| async_graphql::Error::new(x.to_string()))?;
-        let checksum = format!("{:x}", Sha1::digest(text.as_bytes()));
+        let syntax = Wiki::syntax_for_path(c_path.as_path())?;
+        let checksum = match syntax {
+            WikiSyntax::Vimwiki => {
+                format!("{:x}", Sha1::digest(text.as_bytes()))
+            }
+            _ => format!(
+                "{:x}",
+                Sha1::new()
+                    .chain(syntax.to_string())
+                    .chain(text.as_bytes())
+                    .finalize()
+            ),
+        };
+        let modified = tokio::fs::metadata(c_path.as_path())
+            .await
+            .and_then(|x| x.modified())
+            .map_err(|x| async_graphql::Error::new(x.to_string()))?
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_millis() as u64)
+            .unwrap_or_default();
 
         // Third, determine if the content has changed from what we know. If it
         // has, we remove the old ent in preparation for creating a new one. If
-        // it hasn't, we return the current ent.
-        if let Some(ent) = maybe_ent {
+        // it hasn't, we return the current ent with its modified time updated.
+        if let Some(mut ent) = maybe_ent {
             if ent.checksum() == &checksum {
+                if ent.modified() != &modified {
+                    ent.set_modified(modified);
+                    ent.commit().map_err(|x| {
+                        async_graphql::Error::new(x.to_string())
+                    })?;
+                }
                 return Ok(ent);
             } else {
                 let _ = ent.remove()?;
             }
         }
 
-        // Fourth, convert file contents into a vimwiki page
-        let page: v::Page = Language::from_vimwiki_str(&text).parse().map_err(
-            |x: ParseError| async_graphql::Error::new(x.to_string()),
-        )?;
+        // Fourth, convert file contents into a vimwiki page using the syntax
+        // of the wiki containing the file
+        let page = syntax.parse_page(&text)?;
 
         // Fifth, save the vimwiki page as a graphql page
         let page_id = Page::try_from(page)?.id();
@@ -208,71 +443,11 @@ impl ParsedFile {
             Self::build()
                 .path(c_path.to_string_lossy().to_string())
                 .checksum(checksum)
+                .modified(modified)
+                .diary_date(Self::diary_date_from_path(c_path.as_path()))
                 .page(page_id)
                 .finish_and_commit(),
         )
         .map_err(|x| async_graphql::Error::new(x.to_string()))
     }
-
-    pub async fn rename<P1: AsRef<Path>, P2: AsRef<Path>>(
-        from_path: P1,
-        to_path: P2,
-    ) -> async_graphql::Result<()> {
-        let c_from_path = tokio::fs::canonicalize(from_path)
-            .await
-            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
-
-        let c_to_path = tokio::fs::canonicalize(to_path)
-            .await
-            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
-
-        let ents = gql_db()?
-            .find_all_typed::<ParsedFile>(
-                ParsedFile::query()
-                    .where_path(P::equals(
-                        c_from_path.to_string_lossy().to_string(),
-                    ))
-                    .into(),
-            )
-            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
-
-        for mut ent in ents {
-            ent.set_path(c_to_path.to_string_lossy().to_string());
-            ent.commit()
-                .map_err(|x| async_graphql::Error::new(x.to_string()))?;
-        }
-
-        Ok(())
-    }
-
-    pub async fn remove_all<P: AsRef<Path>>(
-        paths: &[P],
-    ) -> async_graphql::Result<()> {
-        for p in paths {
-            Self::remove(p).await?;
-        }
-
-        Ok(())
-    }
-
-    pub async fn remove(path: impl AsRef<Path>) -> async_graphql::Result<()> {
-        let c_path = tokio::fs::canonicalize(path)
-            .await
-            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
-
-        let ents = gql_db()?
-            .find_all_typed::<ParsedFile>(
-                ParsedFile::query()
-                    .where_path(P::equals(c_path.to_string_lossy().to_string()))
-                    .into(),
-            )
-            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
-
-        for ent in ents {
-            ent.remove()
-                .map_err(|x| async_graphql::Error::new(x.to_string()))?;
-        }
-
-        Ok(())
-    }
 }
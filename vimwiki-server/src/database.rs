@@ -1,9 +1,24 @@
-use crate::{data::Wiki, utils, Config};
+use crate::{
+    data::{ParsedFile, Wiki},
+    utils, Config,
+};
 use async_graphql::ErrorExtensions;
 use entity::*;
 use entity_inmemory::InmemoryDatabase;
 use snafu::{ResultExt, Snafu};
-use std::path::PathBuf;
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(feature = "graph")]
+mod graph;
+#[cfg(feature = "graph")]
+pub use graph::{link_graph, LinkGraph};
+mod search;
+pub use search::{search, SearchHit};
+mod store;
+pub use store::{InmemoryStore, Store, StoreFuture};
 
 #[derive(Debug, Snafu)]
 pub enum VimwikiDatabaseError {
@@ -46,8 +61,12 @@ pub fn gql_db() -> async_graphql::Result<DatabaseRc> {
         .ok_or_else(|| VimwikiDatabaseError::DatabaseUnavailable.extend())
 }
 
-/// Load database state using given config
-pub async fn load(config: &Config) -> async_graphql::Result<DatabaseRc> {
+/// Load database state using given config, putting the files of each wiki
+/// into the given store
+pub async fn load(
+    config: &Config,
+    store: &dyn Store,
+) -> async_graphql::Result<DatabaseRc> {
     // If we already have a database loaded, just return it
     if let Ok(db) = gql_db() {
         return Ok(db);
@@ -79,6 +98,7 @@ pub async fn load(config: &Config) -> async_graphql::Result<DatabaseRc> {
     // Determine the paths of the pre-known wikis we will be parsing and indexing
     let _ = Wiki::load_all_from_config(
         &config,
+        store,
         |file_cnt| utils::new_progress_bar(file_cnt as u64),
         |tracker, _idx, path| {
             tracker.set_message(&format!("Loaded {}", path.to_string_lossy()));
@@ -89,7 +109,7 @@ pub async fn load(config: &Config) -> async_graphql::Result<DatabaseRc> {
     .await?;
 
     // Store our new database as the cache
-    let _ = store(&config).await?;
+    self::store(config).await?;
 
     gql_db()
 }
@@ -120,8 +140,180 @@ pub async fn store(config: &Config) -> async_graphql::Result<()> {
     Ok(())
 }
 
+/// Returns the paths of the loaded files that were last modified at or after
+/// the given time, for use in incremental syncing
+pub fn modified_since(
+    database: &DatabaseRc,
+    ts: SystemTime,
+) -> async_graphql::Result<Vec<PathBuf>> {
+    let ts = ts
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_millis() as u64)
+        .unwrap_or_default();
+
+    Ok(database
+        .find_all_typed::<ParsedFile>(ParsedFile::query().into())
+        .map_err(|x| async_graphql::Error::new(x.to_string()))?
+        .into_iter()
+        .filter(|x| *x.modified() >= ts)
+        .map(|x| PathBuf::from(x.path()))
+        .collect())
+}
+
 /// Represents the path to the cache file for the database
 #[inline]
 fn cache_file(config: &Config) -> PathBuf {
     config.cache_dir.join("vimwiki.database")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use filetime::FileTime;
+    use std::time::Duration;
+
+    #[test]
+    fn modified_since_should_return_files_changed_after_time() {
+        let root = std::env::temp_dir()
+            .join(format!("vimwiki-server-modified-{}", std::process::id()));
+        std::fs::create_dir_all(&root).expect("Failed to create wiki dir");
+        let a = root.join("A.wiki");
+        let b = root.join("B.wiki");
+        std::fs::write(&a, "some page\n").expect("Failed to write file");
+        std::fs::write(&b, "other page\n").expect("Failed to write file");
+
+        // NOTE: Modification times are set explicitly rather than waiting
+        //       for the clock, so that they are ordered on any filesystem
+        let loaded = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let ts = loaded + Duration::from_secs(60);
+        let touched = ts + Duration::from_secs(60);
+        let set_modified = |path: &PathBuf, time: SystemTime| {
+            filetime::set_file_mtime(path, FileTime::from_system_time(time))
+                .expect("Failed to set modified time");
+        };
+        set_modified(&a, loaded);
+        set_modified(&b, loaded);
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let paths = global::with_db(InmemoryDatabase::default(), || {
+            runtime.block_on(async {
+                ParsedFile::load(&a).await?;
+                ParsedFile::load(&b).await?;
+
+                std::fs::write(&a, "some changed page\n").unwrap();
+                set_modified(&a, touched);
+                ParsedFile::load(&a).await?;
+
+                modified_since(&gql_db()?, ts)
+            })
+        });
+        let a = std::fs::canonicalize(&a);
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert_eq!(
+            paths.expect("Failed to query modified files"),
+            vec![a.unwrap()]
+        );
+    }
+
+    #[test]
+    fn load_should_extract_diary_date_from_file_name() {
+        let root = std::env::temp_dir()
+            .join(format!("vimwiki-server-diary-{}", std::process::id()));
+        std::fs::create_dir_all(&root).expect("Failed to create wiki dir");
+        let diary = root.join("2024-03-15.wiki");
+        let other = root.join("Notes.wiki");
+        std::fs::write(&diary, "some entry\n").expect("Failed to write file");
+        std::fs::write(&other, "some page\n").expect("Failed to write file");
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let files = global::with_db(InmemoryDatabase::default(), || {
+            runtime.block_on(async {
+                Ok::<_, async_graphql::Error>((
+                    ParsedFile::load(&diary).await?,
+                    ParsedFile::load(&other).await?,
+                ))
+            })
+        });
+        let _ = std::fs::remove_dir_all(&root);
+
+        let (diary, other) = files.expect("Failed to load files");
+        assert!(diary.is_diary());
+        assert_eq!(
+            diary.diary_date().as_ref().map(ToString::to_string),
+            Some(String::from("2024-03-15"))
+        );
+        assert!(!other.is_diary());
+        assert_eq!(other.diary_date(), &None);
+    }
+
+    #[test]
+    fn load_should_parse_files_using_syntax_of_owning_wiki() {
+        use crate::{
+            config::WikiConfig,
+            data::{BlockElement, WikiSyntax},
+        };
+
+        let root = std::env::temp_dir()
+            .join(format!("vimwiki-server-syntax-{}", std::process::id()));
+        let vimwiki_root = root.join("vimwiki");
+        let markdown_root = root.join("markdown");
+        std::fs::create_dir_all(&vimwiki_root).expect("Failed to create dir");
+        std::fs::create_dir_all(&markdown_root).expect("Failed to create dir");
+
+        // NOTE: An indented line is a blockquote in vimwiki, but code when
+        //       parsed as markdown
+        let text = "some text\n\n    indented code\n";
+        std::fs::write(vimwiki_root.join("Page.wiki"), text)
+            .expect("Failed to write file");
+        std::fs::write(markdown_root.join("Page.md"), text)
+            .expect("Failed to write file");
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let contents = global::with_db(InmemoryDatabase::default(), || {
+            runtime.block_on(async {
+                let mut contents = Vec::new();
+                for (i, (path, syntax, ext)) in vec![
+                    (&vimwiki_root, WikiSyntax::Vimwiki, None),
+                    (&markdown_root, WikiSyntax::Markdown, Some("md")),
+                ]
+                .into_iter()
+                .enumerate()
+                {
+                    let config = WikiConfig {
+                        name: None,
+                        path: path.to_path_buf(),
+                        syntax,
+                        file_extension: ext.map(ToString::to_string),
+                        link_extension: ext.map(ToString::to_string),
+                    };
+                    let wiki = Wiki::load(
+                        i,
+                        &config,
+                        &InmemoryStore::default(),
+                        &["wiki"],
+                        |_| {},
+                        |_, _, _| {},
+                        |_| {},
+                    )
+                    .await?;
+                    let files = wiki.load_files()?;
+                    assert_eq!(files.len(), 1, "Unexpected files for {}", i);
+                    contents.push(files[0].load_page()?.load_contents()?);
+                }
+                Ok::<_, async_graphql::Error>(contents)
+            })
+        });
+        let _ = std::fs::remove_dir_all(&root);
+
+        let contents = contents.expect("Failed to load wikis");
+        assert!(
+            matches!(contents[0][1], BlockElement::Blockquote(_)),
+            "Expected blockquote for vimwiki syntax"
+        );
+        assert!(
+            matches!(contents[1][1], BlockElement::PreformattedText(_)),
+            "Expected preformatted text for markdown syntax"
+        );
+    }
+}
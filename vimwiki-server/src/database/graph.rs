@@ -0,0 +1,156 @@
+use super::Store;
+use crate::data::Wiki;
+use entity::*;
+use petgraph::graph::{DiGraph, NodeIndex};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+use vimwiki::{self as v, LinkKind};
+
+/// Represents the links between the pages of the loaded wikis, where each
+/// node is the path of a page and each edge is a link from one page to
+/// another
+#[derive(Clone, Debug, Default)]
+pub struct LinkGraph {
+    /// Graph of pages connected by the links between them
+    pub graph: DiGraph<PathBuf, ()>,
+
+    /// Nodes whose page is the target of a link but has no backing file
+    pub broken: HashSet<NodeIndex>,
+
+    /// Node of each page within the graph, keyed by the path of the page
+    nodes: HashMap<PathBuf, NodeIndex>,
+}
+
+impl LinkGraph {
+    /// Returns the node of the page at the given path, adding it if missing
+    fn node(&mut self, path: PathBuf) -> NodeIndex {
+        let graph = &mut self.graph;
+        *self
+            .nodes
+            .entry(path)
+            .or_insert_with_key(|path| graph.add_node(path.to_path_buf()))
+    }
+}
+
+/// Builds a graph of the wiki links between pages of every loaded wiki, using
+/// the pages kept within the store, where links to pages without a file are
+/// included and marked as broken
+pub async fn link_graph(
+    database: &DatabaseRc,
+    store: &dyn Store,
+) -> async_graphql::Result<LinkGraph> {
+    let wikis = database
+        .find_all_typed::<Wiki>(Wiki::query().into())
+        .map_err(|x| async_graphql::Error::new(x.to_string()))?;
+
+    let mut links = HashMap::new();
+    for wiki in wikis {
+        let root = PathBuf::from(wiki.path());
+        let files = wiki
+            .load_files()
+            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
+
+        for file in files {
+            let path = PathBuf::from(file.path());
+            let targets = match store.get_page(&path).await? {
+                Some(page) => page_links(root.as_path(), &path, &page),
+                None => Vec::new(),
+            };
+            links.insert(path, targets);
+        }
+    }
+
+    let mut graph = LinkGraph::default();
+    for path in links.keys() {
+        graph.node(path.to_path_buf());
+    }
+
+    for (path, targets) in links.iter() {
+        let from = graph.node(path.to_path_buf());
+        for target in targets {
+            let to = graph.node(target.to_path_buf());
+            if !links.contains_key(target) {
+                graph.broken.insert(to);
+            }
+
+            graph.graph.update_edge(from, to, ());
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Resolves the paths of the pages targeted by wiki links within the page
+/// of the file at the given path
+fn page_links(root: &Path, path: &Path, page: &v::Page) -> Vec<PathBuf> {
+    page.links()
+        .filter(|(kind, _)| *kind == LinkKind::Wiki)
+        .filter_map(|(_, link)| link.resolve_wiki_path(root, path))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::WikiSyntax,
+        database::{gql_db, InmemoryStore},
+        utils::make_wiki,
+    };
+    use entity_inmemory::InmemoryDatabase;
+
+    #[test]
+    fn link_graph_should_connect_pages_and_mark_broken_links() {
+        let (root, graph) =
+            global::with_db(InmemoryDatabase::default(), || {
+                let root = make_wiki(
+                    0,
+                    WikiSyntax::Vimwiki,
+                    &[
+                        ("A.wiki", "[[B]] and [[C]]\n"),
+                        ("B.wiki", "[[A#anchor]]\n"),
+                        ("C.wiki", "[[/D]] and [[#top]]\n"),
+                    ],
+                );
+                let runtime = tokio::runtime::Runtime::new().unwrap();
+                let graph = runtime.block_on(link_graph(
+                    &gql_db().unwrap(),
+                    &InmemoryStore::default(),
+                ));
+                (root, graph)
+            });
+        let root = root.path();
+        let graph = graph.expect("Failed to build link graph");
+
+        let name = |idx: NodeIndex| {
+            graph.graph[idx]
+                .strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        let mut edges: Vec<(String, String)> = graph
+            .graph
+            .edge_indices()
+            .filter_map(|e| graph.graph.edge_endpoints(e))
+            .map(|(from, to)| (name(from), name(to)))
+            .collect();
+        edges.sort();
+        assert_eq!(
+            edges,
+            vec![
+                ("A.wiki".to_string(), "B.wiki".to_string()),
+                ("A.wiki".to_string(), "C.wiki".to_string()),
+                ("B.wiki".to_string(), "A.wiki".to_string()),
+                ("C.wiki".to_string(), "D.wiki".to_string()),
+            ]
+        );
+
+        let broken: Vec<String> =
+            graph.broken.iter().copied().map(name).collect();
+        assert_eq!(broken, vec!["D.wiki".to_string()]);
+    }
+}
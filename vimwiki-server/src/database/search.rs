@@ -0,0 +1,163 @@
+use super::Store;
+use crate::data::Region;
+use log::warn;
+use vimwiki::{PlainConfig, ToPlainString};
+
+/// Represents a block of a page whose text matched a search
+#[derive(Clone, Debug, PartialEq, Eq, async_graphql::SimpleObject)]
+pub struct SearchHit {
+    /// Path of the file containing the block
+    pub path: String,
+
+    /// The segment of the file covered by the block
+    pub region: Region,
+
+    /// Line of the block's text containing the match
+    pub snippet: String,
+}
+
+/// Searches the text of every block within the pages of the store for the
+/// query, ignoring case, returning a hit for each block that contains it in
+/// order of path and then position within the file. Files whose page cannot
+/// be provided, such as those no longer readable, are skipped
+pub async fn search(
+    store: &dyn Store,
+    query: &str,
+) -> async_graphql::Result<Vec<SearchHit>> {
+    let query = query.to_lowercase();
+    if query.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = store.all_paths().await?;
+    paths.sort();
+
+    let config = PlainConfig::default();
+    let mut hits = Vec::new();
+    for path in paths {
+        let page = match store.get_page(&path).await {
+            Ok(Some(page)) => page,
+            Ok(None) => continue,
+            Err(x) => {
+                warn!("Skipping {} in search: {}", path.display(), x.message);
+                continue;
+            }
+        };
+
+        for element in page.elements() {
+            let text = element.to_plain_string(&config);
+            if !text.to_lowercase().contains(&query) {
+                continue;
+            }
+
+            // NOTE: A query spanning lines has no single matching line, so
+            //       we fall back to the entire text of the block
+            let snippet = text
+                .lines()
+                .find(|line| line.to_lowercase().contains(&query))
+                .unwrap_or(text.as_str())
+                .trim()
+                .to_string();
+
+            hits.push(SearchHit {
+                path: path.to_string_lossy().to_string(),
+                region: Region::from(element.region()),
+                snippet,
+            });
+        }
+    }
+
+    Ok(hits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data::ParsedFile, database::InmemoryStore};
+    use entity::*;
+    use entity_inmemory::InmemoryDatabase;
+    use vimwiki::{self as v, Language};
+
+    #[test]
+    fn search_should_return_matching_blocks_of_each_file() {
+        let root = tempfile::tempdir().expect("Failed to create wiki dir");
+        let a_text = "= Apples =\n\nSome apple pie\n\nno match\n";
+        let b_text = "nothing here\n\n- an APPLE a day\n";
+        std::fs::write(root.path().join("A.wiki"), a_text)
+            .expect("Failed to write file");
+        std::fs::write(root.path().join("B.wiki"), b_text)
+            .expect("Failed to write file");
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let hits = global::with_db(InmemoryDatabase::default(), || {
+            runtime.block_on(async {
+                let store = InmemoryStore::default();
+                let a = ParsedFile::load(root.path().join("A.wiki")).await?;
+                let b = ParsedFile::load(root.path().join("B.wiki")).await?;
+                let hits = search(&store, "Apple").await?;
+                Ok::<_, async_graphql::Error>((a, b, hits))
+            })
+        });
+        let (a, b, hits) = hits.expect("Failed to search");
+
+        let region_of = |text: &str, idx: usize| {
+            let page: v::Page =
+                Language::from_vimwiki_str(text).parse().unwrap();
+            Region::from(page.elements()[idx].region())
+        };
+        assert_eq!(
+            hits,
+            vec![
+                SearchHit {
+                    path: a.path().to_string(),
+                    region: region_of(a_text, 0),
+                    snippet: String::from("# Apples"),
+                },
+                SearchHit {
+                    path: a.path().to_string(),
+                    region: region_of(a_text, 1),
+                    snippet: String::from("Some apple pie"),
+                },
+                SearchHit {
+                    path: b.path().to_string(),
+                    region: region_of(b_text, 1),
+                    snippet: String::from("- an APPLE a day"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn search_should_return_nothing_for_empty_query() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let hits = global::with_db(InmemoryDatabase::default(), || {
+            runtime.block_on(search(&InmemoryStore::default(), "  "))
+        });
+        assert_eq!(hits.expect("Failed to search"), Vec::new());
+    }
+
+    #[test]
+    fn search_should_skip_files_that_can_no_longer_be_read() {
+        let root = tempfile::tempdir().expect("Failed to create wiki dir");
+        std::fs::write(root.path().join("A.wiki"), "an apple\n")
+            .expect("Failed to write file");
+        std::fs::write(root.path().join("B.wiki"), "another apple\n")
+            .expect("Failed to write file");
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let hits = global::with_db(InmemoryDatabase::default(), || {
+            runtime.block_on(async {
+                let store = InmemoryStore::default();
+                let a = ParsedFile::load(root.path().join("A.wiki")).await?;
+                ParsedFile::load(root.path().join("B.wiki")).await?;
+                std::fs::remove_file(root.path().join("B.wiki"))?;
+                let hits = search(&store, "apple").await?;
+                Ok::<_, async_graphql::Error>((a, hits))
+            })
+        });
+        let (a, hits) = hits.expect("Failed to search");
+
+        let paths: Vec<&str> = hits.iter().map(|x| x.path.as_str()).collect();
+        assert_eq!(paths, vec![a.path()]);
+    }
+}
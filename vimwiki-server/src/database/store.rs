@@ -0,0 +1,129 @@
+use crate::data::ParsedFile;
+use entity::*;
+use std::{
+    collections::HashMap,
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+};
+use vimwiki as v;
+
+/// Represents the future returned by the operations of a `Store`
+pub type StoreFuture<'a, T> =
+    Pin<Box<dyn Future<Output = async_graphql::Result<T>> + Send + 'a>>;
+
+/// Represents a backend holding the pages parsed from files, keyed by the
+/// path of the file each page was parsed from
+pub trait Store: Send + Sync {
+    /// Returns the page stored for the given path, if there is one
+    fn get_page<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> StoreFuture<'a, Option<Arc<v::Page<'static>>>>;
+
+    /// Parses the file at the given path and stores the resulting page,
+    /// replacing whatever was stored for the path
+    fn put_page<'a>(&'a self, path: &'a Path) -> StoreFuture<'a, ()>;
+
+    /// Moves whatever was stored for one path to another path, such as when
+    /// a file is renamed
+    fn rename<'a>(
+        &'a self,
+        from: &'a Path,
+        to: &'a Path,
+    ) -> StoreFuture<'a, ()>;
+
+    /// Returns the paths of all files that have been stored
+    fn all_paths(&self) -> StoreFuture<'_, Vec<PathBuf>>;
+
+    /// Removes whatever was stored for the given path
+    fn remove<'a>(&'a self, path: &'a Path) -> StoreFuture<'a, ()>;
+}
+
+/// Store keeping pages as ents within the global in-memory database, which
+/// is the default store of the server
+///
+/// NOTE: The ents of a page cannot be turned back into a page, so pages are
+///       parsed from their file again when first requested and kept until
+///       their file is stored again
+#[derive(Debug, Default)]
+pub struct InmemoryStore {
+    pages: Mutex<HashMap<PathBuf, Arc<v::Page<'static>>>>,
+}
+
+impl InmemoryStore {
+    fn pages(&self) -> MutexGuard<'_, HashMap<PathBuf, Arc<v::Page<'static>>>> {
+        match self.pages.lock() {
+            Ok(x) => x,
+            Err(x) => x.into_inner(),
+        }
+    }
+}
+
+impl Store for InmemoryStore {
+    fn get_page<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> StoreFuture<'a, Option<Arc<v::Page<'static>>>> {
+        Box::pin(async move {
+            let file = match ParsedFile::find(path).await? {
+                Some(file) => file,
+                None => return Ok(None),
+            };
+            let path = PathBuf::from(file.path());
+
+            if let Some(page) = self.pages().get(&path) {
+                return Ok(Some(Arc::clone(page)));
+            }
+
+            let page = Arc::new(ParsedFile::parse(&path).await?);
+            self.pages().insert(path, Arc::clone(&page));
+            Ok(Some(page))
+        })
+    }
+
+    fn put_page<'a>(&'a self, path: &'a Path) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            let file = ParsedFile::load(path).await?;
+            self.pages().remove(Path::new(file.path()));
+            Ok(())
+        })
+    }
+
+    fn rename<'a>(
+        &'a self,
+        from: &'a Path,
+        to: &'a Path,
+    ) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            if let Some(file) = ParsedFile::find(from).await? {
+                self.pages().remove(Path::new(file.path()));
+            }
+            ParsedFile::rename(from, to).await
+        })
+    }
+
+    fn all_paths(&self) -> StoreFuture<'_, Vec<PathBuf>> {
+        Box::pin(async move {
+            Ok(ParsedFile::query()
+                .execute()
+                .map_err(|x| async_graphql::Error::new(x.to_string()))?
+                .into_iter()
+                .map(|x| PathBuf::from(x.path()))
+                .collect())
+        })
+    }
+
+    fn remove<'a>(&'a self, path: &'a Path) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            if let Some(file) = ParsedFile::find(path).await? {
+                self.pages().remove(Path::new(file.path()));
+                file.remove()
+                    .map_err(|x| async_graphql::Error::new(x.to_string()))?;
+            }
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{data::Wiki, database::Store};
+use entity::{TypedPredicate as P, *};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread,
+};
+use vimwiki::{self as v, HtmlConfig, HtmlFormatter};
+
+/// Name of the generated page listing all other pages of a wiki
+const INDEX_PAGE: &str = "index.html";
+
+/// Exports the wikis within a loaded database to HTML, where links between
+/// pages point to the output file of the linked page
+pub struct WikiExporter {
+    database: DatabaseRc,
+    store: Arc<dyn Store>,
+    config: HtmlConfig,
+    render_concurrency: usize,
+}
+
+impl WikiExporter {
+    /// Creates an exporter of the wikis within the database, rendering the
+    /// pages of their files as kept within the store
+    pub fn new(database: DatabaseRc, store: Arc<dyn Store>) -> Self {
+        Self {
+            database,
+            store,
+            config: HtmlConfig::default(),
+            render_concurrency: 1,
+        }
+    }
+
+    /// Sets the configuration used to render each page to HTML
+    pub fn with_config(mut self, config: HtmlConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the maximum number of pages rendered at the same time, where
+    /// anything less than two renders pages one after another
+    pub fn with_render_concurrency(
+        mut self,
+        render_concurrency: usize,
+    ) -> Self {
+        self.render_concurrency = render_concurrency;
+        self
+    }
+
+    /// Exports every page of the wiki with the given index, producing a
+    /// mapping of output path (relative to the root of the wiki) to HTML.
+    ///
+    /// Pages containing a `%nohtml` placeholder are excluded from the
+    /// output. An `index.html` listing each page is generated unless the
+    /// wiki has its own index page, in which case that page is kept instead
+    pub async fn export_wiki(
+        &self,
+        index: usize,
+    ) -> async_graphql::Result<HashMap<PathBuf, String>> {
+        let wiki = self
+            .database
+            .find_all_typed::<Wiki>(
+                Wiki::query().where_index(P::equals(index)).into(),
+            )
+            .map_err(|x| async_graphql::Error::new(x.to_string()))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                async_graphql::Error::new(format!(
+                    "No wiki with index {}",
+                    index
+                ))
+            })?;
+
+        let root = PathBuf::from(wiki.path());
+        let files = wiki
+            .load_files()
+            .map_err(|x| async_graphql::Error::new(x.to_string()))?;
+
+        let mut pages = Vec::new();
+        for file in files {
+            let path = PathBuf::from(file.path());
+            let page = self.store.get_page(&path).await?.ok_or_else(|| {
+                async_graphql::Error::new(format!(
+                    "No page stored for {}",
+                    path.display()
+                ))
+            })?;
+            pages.push((path, page));
+        }
+
+        let rendered = if self.render_concurrency > 1 {
+            self.render_parallel(root.as_path(), pages)?
+        } else {
+            pages
+                .iter()
+                .map(|(path, page)| {
+                    render_page(&self.config, root.as_path(), path, page)
+                })
+                .collect()
+        };
+
+        let mut output = HashMap::new();
+        for (path, html) in rendered.into_iter().flatten() {
+            output.insert(path, html);
+        }
+
+        let index_path = PathBuf::from(INDEX_PAGE);
+        if !output.contains_key(&index_path) {
+            let mut pages: Vec<_> = output.keys().cloned().collect();
+            pages.sort();
+            output.insert(index_path, make_index(&pages));
+        }
+
+        Ok(output)
+    }
+
+    /// Renders pages across up to `render_concurrency` threads, returning
+    /// the output of each page in the same order as the given pages
+    fn render_parallel(
+        &self,
+        root: &Path,
+        pages: Vec<(PathBuf, Arc<v::Page<'static>>)>,
+    ) -> async_graphql::Result<Vec<Option<(PathBuf, String)>>> {
+        let chunk_size = pages.len().div_ceil(self.render_concurrency);
+        let handles: Vec<_> = pages
+            .chunks(chunk_size.max(1))
+            .map(|chunk| {
+                let config = self.config.clone();
+                let root = root.to_path_buf();
+                let chunk = chunk.to_vec();
+                thread::spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|(path, page)| {
+                            render_page(&config, root.as_path(), path, page)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut rendered = Vec::new();
+        for handle in handles {
+            rendered.extend(handle.join().map_err(|_| {
+                async_graphql::Error::new("Thread rendering pages panicked")
+            })?);
+        }
+        Ok(rendered)
+    }
+}
+
+/// Renders the page of a single file of a wiki, returning the path of its
+/// output relative to the root of the wiki alongside its HTML, or none if
+/// the page is marked with `%nohtml`
+fn render_page(
+    config: &HtmlConfig,
+    root: &Path,
+    path: &Path,
+    page: &v::Page,
+) -> Option<(PathBuf, String)> {
+    let is_nohtml = page.elements().iter().any(|x| {
+        matches!(
+            x.as_inner(),
+            v::BlockElement::Placeholder(v::Placeholder::NoHtml)
+        )
+    });
+    if is_nohtml {
+        return None;
+    }
+
+    let output_path = path
+        .strip_prefix(root)
+        .unwrap_or_else(|_| Path::new(path.file_name().unwrap_or_default()))
+        .with_extension("html");
+    let title = output_path.with_extension("");
+
+    Some((
+        output_path,
+        make_document(&title.to_string_lossy(), &page.to_html(config)),
+    ))
+}
+
+/// Produces a listing of links to each of the given pages
+fn make_index(pages: &[PathBuf]) -> String {
+    let mut body = String::from("<ul>\n");
+    for page in pages {
+        body.push_str("<li><a href=\"");
+        body.push_str(&escape(&page.to_string_lossy()));
+        body.push_str("\">");
+        body.push_str(&escape(&page.with_extension("").to_string_lossy()));
+        body.push_str("</a></li>\n");
+    }
+    body.push_str("</ul>");
+
+    make_document("Index", &body)
+}
+
+/// Wraps the body of a page in a complete HTML document
+fn make_document(title: &str, body: &str) -> String {
+    format!(
+        "<html>\n<head>\n<title>{}</title>\n</head>\n<body>\n{}\n</body>\n</html>\n",
+        escape(title),
+        body,
+    )
+}
+
+/// Escapes text so it can be placed within HTML
+fn escape(text: &str) -> String {
+    let config = HtmlConfig::default();
+    let mut f = HtmlFormatter::new(&config);
+
+    // NOTE: Writing to a string buffer never fails
+    let _ = f.write_escaped(text);
+    f.into_content()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::WikiSyntax,
+        database::{gql_db, InmemoryStore},
+        utils::make_wiki,
+    };
+    use entity_inmemory::InmemoryDatabase;
+
+    /// Exports a wiki made of the given files once per given setup of the
+    /// exporter, rendering pages kept within an in-memory store
+    fn export(
+        syntax: WikiSyntax,
+        files: &[(&str, &str)],
+        setups: &[fn(WikiExporter) -> WikiExporter],
+    ) -> Vec<HashMap<PathBuf, String>> {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        global::with_db(InmemoryDatabase::default(), || {
+            let _root = make_wiki(0, syntax, files);
+            let store: Arc<dyn Store> = Arc::new(InmemoryStore::default());
+
+            setups
+                .iter()
+                .map(|setup| {
+                    let exporter = setup(WikiExporter::new(
+                        gql_db().unwrap(),
+                        Arc::clone(&store),
+                    ));
+                    runtime
+                        .block_on(exporter.export_wiki(0))
+                        .expect("Failed to export wiki")
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn export_wiki_should_skip_pages_with_nohtml_placeholder() {
+        let output = export(
+            WikiSyntax::Vimwiki,
+            &[("A.wiki", "some page\n"), ("B.wiki", "%nohtml\nsecret\n")],
+            &[|x| x],
+        )
+        .remove(0);
+
+        assert!(output.contains_key(Path::new("A.html")));
+        assert!(!output.contains_key(Path::new("B.html")));
+        assert!(!output[Path::new("index.html")].contains("B.html"));
+    }
+
+    #[test]
+    fn export_wiki_should_rewrite_links_between_pages_and_generate_index() {
+        let output = export(
+            WikiSyntax::Vimwiki,
+            &[("A.wiki", "see [[B]]\n"), ("B.wiki", "= B =\n")],
+            &[|x| x],
+        )
+        .remove(0);
+
+        let mut paths: Vec<_> = output.keys().cloned().collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("A.html"),
+                PathBuf::from("B.html"),
+                PathBuf::from("index.html"),
+            ]
+        );
+
+        assert!(
+            output[Path::new("A.html")].contains("<a href=\"B.html\">"),
+            "Link not rewritten: {}",
+            output[Path::new("A.html")]
+        );
+        assert!(output[Path::new("index.html")]
+            .contains("<li><a href=\"A.html\">A</a></li>"));
+    }
+
+    #[test]
+    fn export_wiki_should_parse_pages_using_syntax_of_wiki() {
+        // NOTE: An indented line is a blockquote in vimwiki, but code when
+        //       parsed as markdown
+        let outputs = export(
+            WikiSyntax::Markdown,
+            &[("A.md", "text\n\n    code\n")],
+            &[|x| x, |x| x.with_render_concurrency(2)],
+        );
+
+        let html = &outputs[0][Path::new("A.html")];
+        assert!(html.contains("<pre>"), "Missing code: {}", html);
+        assert!(!html.contains("<blockquote>"), "Unexpected quote: {}", html);
+        assert_eq!(outputs[0], outputs[1]);
+    }
+
+    #[test]
+    fn export_wiki_should_produce_same_output_when_rendering_in_parallel() {
+        let files: Vec<(String, String)> = (0..7)
+            .map(|i| {
+                (
+                    format!("Page{}.wiki", i),
+                    format!(
+                        "= Page {} =\nsee [[Page{}]] and *bold*\n",
+                        i,
+                        (i + 1) % 7
+                    ),
+                )
+            })
+            .collect();
+        let files: Vec<(&str, &str)> = files
+            .iter()
+            .map(|(name, text)| (name.as_str(), text.as_str()))
+            .collect();
+        let outputs = export(
+            WikiSyntax::Vimwiki,
+            &files,
+            &[|x| x, |x| x.with_render_concurrency(3)],
+        );
+
+        assert_eq!(outputs[0].len(), 8);
+        assert_eq!(outputs[0], outputs[1]);
+    }
+
+    #[test]
+    fn export_wiki_should_render_pages_using_given_config() {
+        let outputs = export(
+            WikiSyntax::Vimwiki,
+            &[("A.wiki", "= Title =\n")],
+            &[
+                |x| x,
+                |x| {
+                    x.with_config(HtmlConfig {
+                        header_permalinks: true,
+                        ..Default::default()
+                    })
+                },
+            ],
+        );
+
+        let default = &outputs[0][Path::new("A.html")];
+        let configured = &outputs[1][Path::new("A.html")];
+        assert!(!default.contains("¶"), "Unexpected permalink: {}", default);
+        assert!(
+            configured.contains("¶"),
+            "Missing permalink: {}",
+            configured
+        );
+    }
+}
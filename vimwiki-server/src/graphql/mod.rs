@@ -1,3 +1,6 @@
+use crate::database::{InmemoryStore, Store};
+use std::sync::Arc;
+
 mod mutation;
 mod query;
 
@@ -8,7 +11,28 @@ pub use query::Query;
 pub type Schema =
     async_graphql::Schema<Query, Mutation, async_graphql::EmptySubscription>;
 
-pub fn new_schema() -> Schema {
+/// Creates the schema of the GraphQL server, where resolvers keep pages
+/// within the given store
+pub fn new_schema(store: Arc<dyn Store>) -> Schema {
     Schema::build(Query::default(), Mutation, async_graphql::EmptySubscription)
+        .data(store)
         .finish()
 }
+
+/// Produces the schema of the GraphQL server in SDL form, which does not
+/// require a database as no queries are executed
+pub fn schema_sdl() -> String {
+    new_schema(Arc::new(InmemoryStore::default())).sdl()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_sdl_should_include_page_query_field() {
+        let sdl = schema_sdl();
+        assert!(sdl.contains("type Query"), "Missing query type: {}", sdl);
+        assert!(sdl.contains("page(id: "), "Missing page field: {}", sdl);
+    }
+}
@@ -1,5 +1,10 @@
-use crate::data::*;
+use crate::{config::WikiConfig, data::*, database::Store};
+use async_graphql::Context;
 use log::trace;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub struct Mutation;
 
@@ -10,24 +15,42 @@ fn default_exts() -> Vec<String> {
 
 #[async_graphql::Object]
 impl Mutation {
-    /// Imports/re-imports a wiki from the specified path
+    /// Imports/re-imports a wiki from the specified path, parsing its files
+    /// using the given syntax. If a file extension is provided, it is used
+    /// in place of the extensions to find the files of the wiki
+    #[allow(clippy::too_many_arguments)]
     async fn import_wiki(
         &self,
+        ctx: &Context<'_>,
         path: String,
         index: u32,
         name: Option<String>,
+        #[graphql(default)] syntax: WikiSyntax,
+        file_extension: Option<String>,
+        link_extension: Option<String>,
         #[graphql(default_with = "default_exts()")] exts: Vec<String>,
     ) -> async_graphql::Result<Wiki> {
         trace!(
-            "import_wiki(path: {:?}, index: {}, name: {:?})",
+            "import_wiki(path: {:?}, index: {}, name: {:?}, syntax: {}, file_extension: {:?}, link_extension: {:?})",
             path,
             index,
-            name
+            name,
+            syntax,
+            file_extension,
+            link_extension,
         );
+        let config = WikiConfig {
+            name,
+            path: PathBuf::from(path),
+            syntax,
+            file_extension,
+            link_extension,
+        };
+        let store = ctx.data::<Arc<dyn Store>>()?;
         Wiki::load(
             index as usize,
-            path,
-            name,
+            &config,
+            store.as_ref(),
             &exts,
             |_| {},
             |_, _, _| {},
@@ -39,10 +62,15 @@ impl Mutation {
     /// Imports/re-imports a standalone wiki file from the specified path
     async fn import_file(
         &self,
+        ctx: &Context<'_>,
         path: String,
     ) -> async_graphql::Result<ParsedFile> {
         trace!("import_file(path: {:?})", path);
-        ParsedFile::load(path).await
+        let store = ctx.data::<Arc<dyn Store>>()?;
+        store.put_page(Path::new(&path)).await?;
+        ParsedFile::find(&path).await?.ok_or_else(|| {
+            async_graphql::Error::new("File missing after being stored")
+        })
     }
 
     /// Creates a new vimwiki file at the specified path using the given text
@@ -51,6 +79,7 @@ impl Mutation {
     /// be overwritten and instead will return an error.
     async fn create_file(
         &self,
+        ctx: &Context<'_>,
         path: String,
         contents: String,
         #[graphql(default)] overwrite: bool,
@@ -61,6 +90,7 @@ impl Mutation {
             contents,
             overwrite
         );
-        ParsedFile::create(path, contents, overwrite).await
+        let store = ctx.data::<Arc<dyn Store>>()?;
+        ParsedFile::create(store.as_ref(), path, contents, overwrite).await
     }
 }
@@ -1,5 +1,10 @@
-use crate::data::Element;
+use crate::{
+    data::Element,
+    database::{self, SearchHit, Store},
+};
+use async_graphql::Context;
 use entity::{TypedPredicate as P, *};
+use std::sync::Arc;
 
 #[derive(Default)]
 pub struct MiscQuery;
@@ -31,4 +36,15 @@ impl MiscQuery {
             .map(|x| x.into_iter().next())
             .map_err(|x| async_graphql::Error::new(x.to_string()))
     }
+
+    /// Searches the text of every block within the loaded files for the
+    /// query, ignoring case
+    async fn search(
+        &self,
+        ctx: &Context<'_>,
+        query: String,
+    ) -> async_graphql::Result<Vec<SearchHit>> {
+        let store = ctx.data::<Arc<dyn Store>>()?;
+        database::search(store.as_ref(), &query).await
+    }
 }
@@ -337,6 +337,32 @@ impl ObjQuery {
             .map_err(|x| async_graphql::Error::new(x.to_string()))
     }
 
+    /// Queries for instances of FootnoteDef that match the filter, or return all
+    /// instances if no filter provided
+    async fn footnote_defs(
+        &self,
+        filter: Option<GqlFootnoteDefFilter>,
+    ) -> async_graphql::Result<Vec<FootnoteDef>> {
+        let query: entity::Query = match filter {
+            Some(x) => x.into(),
+            None => FootnoteDef::query().into(),
+        };
+
+        gql_db()?
+            .find_all_typed::<FootnoteDef>(query)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
+    /// Queries for a single instance of FootnoteDef by its id
+    async fn footnote_def(
+        &self,
+        id: Id,
+    ) -> async_graphql::Result<Option<FootnoteDef>> {
+        gql_db()?
+            .get_typed::<FootnoteDef>(id)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
     /// Queries for instances of Header that match the filter, or return all
     /// instances if no filter provided
     async fn headers(
@@ -647,6 +673,58 @@ impl ObjQuery {
             .map_err(|x| async_graphql::Error::new(x.to_string()))
     }
 
+    /// Queries for instances of PlaceholderInclude that match the filter, or return all
+    /// instances if no filter provided
+    async fn placeholder_includes(
+        &self,
+        filter: Option<GqlPlaceholderIncludeFilter>,
+    ) -> async_graphql::Result<Vec<PlaceholderInclude>> {
+        let query: entity::Query = match filter {
+            Some(x) => x.into(),
+            None => PlaceholderInclude::query().into(),
+        };
+
+        gql_db()?
+            .find_all_typed::<PlaceholderInclude>(query)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
+    /// Queries for a single instance of PlaceholderInclude by its id
+    async fn placeholder_include(
+        &self,
+        id: Id,
+    ) -> async_graphql::Result<Option<PlaceholderInclude>> {
+        gql_db()?
+            .get_typed::<PlaceholderInclude>(id)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
+    /// Queries for instances of PlaceholderLinkReference that match the filter, or return all
+    /// instances if no filter provided
+    async fn placeholder_link_references(
+        &self,
+        filter: Option<GqlPlaceholderLinkReferenceFilter>,
+    ) -> async_graphql::Result<Vec<PlaceholderLinkReference>> {
+        let query: entity::Query = match filter {
+            Some(x) => x.into(),
+            None => PlaceholderLinkReference::query().into(),
+        };
+
+        gql_db()?
+            .find_all_typed::<PlaceholderLinkReference>(query)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
+    /// Queries for a single instance of PlaceholderLinkReference by its id
+    async fn placeholder_link_reference(
+        &self,
+        id: Id,
+    ) -> async_graphql::Result<Option<PlaceholderLinkReference>> {
+        gql_db()?
+            .get_typed::<PlaceholderLinkReference>(id)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
     /// Queries for instances of PlaceholderOther that match the filter, or return all
     /// instances if no filter provided
     async fn placeholder_others(
@@ -699,6 +777,32 @@ impl ObjQuery {
             .map_err(|x| async_graphql::Error::new(x.to_string()))
     }
 
+    /// Queries for instances of Signature that match the filter, or return all
+    /// instances if no filter provided
+    async fn signatures(
+        &self,
+        filter: Option<GqlSignatureFilter>,
+    ) -> async_graphql::Result<Vec<Signature>> {
+        let query: entity::Query = match filter {
+            Some(x) => x.into(),
+            None => Signature::query().into(),
+        };
+
+        gql_db()?
+            .find_all_typed::<Signature>(query)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
+    /// Queries for a single instance of Signature by its id
+    async fn signature(
+        &self,
+        id: Id,
+    ) -> async_graphql::Result<Option<Signature>> {
+        gql_db()?
+            .get_typed::<Signature>(id)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
     /// Queries for instances of Table that match the filter, or return all
     /// instances if no filter provided
     async fn tables(
@@ -1206,6 +1310,32 @@ impl ObjQuery {
             .map_err(|x| async_graphql::Error::new(x.to_string()))
     }
 
+    /// Queries for instances of ReferenceLink that match the filter, or return all
+    /// instances if no filter provided
+    async fn reference_links(
+        &self,
+        filter: Option<GqlReferenceLinkFilter>,
+    ) -> async_graphql::Result<Vec<ReferenceLink>> {
+        let query: entity::Query = match filter {
+            Some(x) => x.into(),
+            None => ReferenceLink::query().into(),
+        };
+
+        gql_db()?
+            .find_all_typed::<ReferenceLink>(query)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
+    /// Queries for a single instance of ReferenceLink by its id
+    async fn reference_link(
+        &self,
+        id: Id,
+    ) -> async_graphql::Result<Option<ReferenceLink>> {
+        gql_db()?
+            .get_typed::<ReferenceLink>(id)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
     /// Queries for instances of Tags that match the filter, or return all
     /// instances if no filter provided
     async fn tags(
@@ -1281,6 +1411,55 @@ impl ObjQuery {
             .map_err(|x| async_graphql::Error::new(x.to_string()))
     }
 
+    /// Queries for instances of FootnoteRef that match the filter, or return all
+    /// instances if no filter provided
+    async fn footnote_refs(
+        &self,
+        filter: Option<GqlFootnoteRefFilter>,
+    ) -> async_graphql::Result<Vec<FootnoteRef>> {
+        let query: entity::Query = match filter {
+            Some(x) => x.into(),
+            None => FootnoteRef::query().into(),
+        };
+
+        gql_db()?
+            .find_all_typed::<FootnoteRef>(query)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
+    /// Queries for a single instance of FootnoteRef by its id
+    async fn footnote_ref(
+        &self,
+        id: Id,
+    ) -> async_graphql::Result<Option<FootnoteRef>> {
+        gql_db()?
+            .get_typed::<FootnoteRef>(id)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
+    /// Queries for instances of Hashtag that match the filter, or return all
+    /// instances if no filter provided
+    async fn hashtags(
+        &self,
+        filter: Option<GqlHashtagFilter>,
+    ) -> async_graphql::Result<Vec<Hashtag>> {
+        let query: entity::Query = match filter {
+            Some(x) => x.into(),
+            None => Hashtag::query().into(),
+        };
+
+        gql_db()?
+            .find_all_typed::<Hashtag>(query)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
+    /// Queries for a single instance of Hashtag by its id
+    async fn hashtag(&self, id: Id) -> async_graphql::Result<Option<Hashtag>> {
+        gql_db()?
+            .get_typed::<Hashtag>(id)
+            .map_err(|x| async_graphql::Error::new(x.to_string()))
+    }
+
     /// Queries for instances of Comment that matches the given filter, or
     /// return all instances if no filter provided
     async fn comments(
@@ -0,0 +1,257 @@
+use crate::{data::Region, database::Store};
+use async_graphql::{Context, Object};
+use std::{path::Path, sync::Arc};
+use vimwiki::{self as v, Located};
+
+#[derive(Default)]
+pub struct ViewQuery;
+
+#[Object]
+impl ViewQuery {
+    /// Provides a read-only view of the page parsed from the file at the
+    /// given path, borrowing from the page kept within the store rather
+    /// than loading elements out of the database
+    async fn page_view(
+        &self,
+        ctx: &Context<'_>,
+        path: String,
+    ) -> async_graphql::Result<Option<PageView>> {
+        let store = ctx.data::<Arc<dyn Store>>()?;
+        Ok(store
+            .get_page(Path::new(&path))
+            .await?
+            .map(|page| PageView { path, page }))
+    }
+}
+
+/// Represents a read-only view of a page kept within the store
+pub struct PageView {
+    path: String,
+    page: Arc<v::Page<'static>>,
+}
+
+#[Object]
+impl PageView {
+    /// Path of the file the page was parsed from
+    async fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Headers of the page in the order they appear
+    async fn headers(&self) -> Vec<HeaderView<'_>> {
+        self.page
+            .elements()
+            .iter()
+            .filter_map(|x| match x.as_inner() {
+                v::BlockElement::Header(header) => {
+                    Some(HeaderView(Located::new(header, x.region())))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Links within the page in the order they appear, including those
+    /// nested within other elements
+    async fn links(&self) -> Vec<LinkView<'_>> {
+        self.page
+            .links()
+            .map(|(kind, link)| LinkView { kind, link })
+            .collect()
+    }
+}
+
+/// Represents a read-only view of a header borrowed from a stored page
+pub struct HeaderView<'a>(Located<&'a v::Header<'static>>);
+
+#[Object]
+impl<'a> HeaderView<'a> {
+    /// The segment of the document this header covers
+    async fn region(&self) -> Region {
+        Region::from(self.0.region())
+    }
+
+    /// The depth of the header in the document (1 being top level, max
+    /// depth of 6)
+    async fn level(&self) -> i32 {
+        self.0.level as i32
+    }
+
+    /// Whether or not the header is centered
+    async fn centered(&self) -> bool {
+        self.0.centered
+    }
+
+    /// Explicit anchor id given to the header, if it has one
+    async fn custom_id(&self) -> Option<&str> {
+        self.0.custom_id.as_deref()
+    }
+
+    /// The content within the header as it would be read by humans
+    /// without frills
+    async fn text(&self) -> String {
+        self.0.content.to_string()
+    }
+}
+
+/// Represents the kind of target a link points to
+#[derive(async_graphql::Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LinkViewKind {
+    Wiki,
+    InterWiki,
+    Diary,
+    Url,
+    Anchor,
+}
+
+impl From<v::LinkKind> for LinkViewKind {
+    fn from(kind: v::LinkKind) -> Self {
+        match kind {
+            v::LinkKind::Wiki => Self::Wiki,
+            v::LinkKind::InterWiki => Self::InterWiki,
+            v::LinkKind::Diary => Self::Diary,
+            v::LinkKind::Url => Self::Url,
+            v::LinkKind::Anchor => Self::Anchor,
+        }
+    }
+}
+
+/// Represents a read-only view of a link borrowed from a stored page
+pub struct LinkView<'a> {
+    kind: v::LinkKind,
+    link: Located<&'a v::Link<'static>>,
+}
+
+#[Object]
+impl<'a> LinkView<'a> {
+    /// The segment of the document this link covers
+    async fn region(&self) -> Region {
+        Region::from(self.link.region())
+    }
+
+    /// The kind of target the link points to
+    async fn kind(&self) -> LinkViewKind {
+        LinkViewKind::from(self.kind)
+    }
+
+    /// The target of the link with percent-encoded characters decoded
+    async fn target(&self) -> String {
+        self.link.decoded_target().into_owned()
+    }
+
+    /// The description of the link, if it has one
+    async fn description(&self) -> Option<String> {
+        self.link.description().map(ToString::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::ParsedFile,
+        database::InmemoryStore,
+        graphql::{new_schema, Schema},
+    };
+    use entity::*;
+    use entity_inmemory::InmemoryDatabase;
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    /// Allocator counting the allocations made by each thread, such that
+    /// tests running in parallel do not affect each other's counts
+    struct CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|x| x.set(x.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Executes the query, returning its data and how many allocations
+    /// were made to do so
+    async fn execute_counted(
+        schema: &Schema,
+        query: &str,
+    ) -> (async_graphql::Value, usize) {
+        let before = ALLOCATIONS.with(Cell::get);
+        let response = schema.execute(query).await;
+        let count = ALLOCATIONS.with(Cell::get) - before;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        (response.data, count)
+    }
+
+    #[test]
+    fn page_view_should_list_headers_with_fewer_allocations_than_ents() {
+        let dir = tempfile::tempdir().expect("Failed to create wiki dir");
+        let path = dir.path().join("Page.wiki");
+        let text: String = (1..=50)
+            .map(|i| format!("= Header *{}* =\nsome text\n", i))
+            .collect();
+        std::fs::write(&path, text).expect("Failed to write file");
+
+        // NOTE: Resolving the ents of a page nests deeply enough to overflow
+        //       the default stack of a test thread, so the queries run on a
+        //       thread of their own with a larger stack
+        let (ents, view) = std::thread::Builder::new()
+            .stack_size(16 * 1024 * 1024)
+            .spawn(move || {
+                let runtime = tokio::runtime::Runtime::new().unwrap();
+                global::with_db(InmemoryDatabase::default(), || {
+                    runtime.block_on(async {
+                        let store: Arc<dyn Store> =
+                            Arc::new(InmemoryStore::default());
+                        let schema = new_schema(Arc::clone(&store));
+                        let file = ParsedFile::load(&path).await.unwrap();
+
+                        let ents_query = "{ headers { level text } }";
+                        let view_query = format!(
+                    "{{ pageView(path: {:?}) {{ headers {{ level text }} }} }}",
+                    file.path()
+                );
+
+                        // NOTE: The first query of the view parses the page into the
+                        //       store, so both queries are run once before counting
+                        execute_counted(&schema, ents_query).await;
+                        execute_counted(&schema, &view_query).await;
+
+                        (
+                            execute_counted(&schema, ents_query).await,
+                            execute_counted(&schema, &view_query).await,
+                        )
+                    })
+                })
+            })
+            .expect("Failed to spawn query thread")
+            .join()
+            .expect("Query thread panicked");
+
+        let total = |value: serde_json::Value| {
+            value.as_array().map(Vec::len).unwrap_or_default()
+        };
+        let ents_data = serde_json::to_value(&ents.0).unwrap();
+        let view_data = serde_json::to_value(&view.0).unwrap();
+        assert_eq!(total(ents_data["headers"].clone()), 50);
+        assert_eq!(total(view_data["pageView"]["headers"].clone()), 50);
+        assert!(
+            view.1 < ents.1,
+            "Expected view ({} allocations) to allocate less than ents ({})",
+            view.1,
+            ents.1
+        );
+    }
+}
@@ -1,9 +1,16 @@
 mod config;
 mod data;
 mod database;
+mod export;
 mod graphql;
 mod program;
 mod utils;
 
-pub use config::Config;
+pub use config::{Config, ConfigError};
+#[cfg(feature = "graph")]
+pub use database::{link_graph, LinkGraph};
+pub use database::{
+    modified_since, search, InmemoryStore, SearchHit, Store, StoreFuture,
+};
+pub use export::WikiExporter;
 pub use program::Program;
@@ -21,7 +21,8 @@ async fn main() {
         .start()
         .unwrap_or_else(|e| panic!("Logger initialization failed with {}", e));
 
-    Program::run(config)
-        .await
-        .expect("Program failed unexpectedly");
+    if let Err(x) = Program::run(config).await {
+        eprintln!("{}", x);
+        std::process::exit(1);
+    }
 }
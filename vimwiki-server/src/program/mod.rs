@@ -3,15 +3,22 @@ mod stdin;
 mod watcher;
 use watcher::*;
 
-use crate::{config::Mode, database, Config};
+use crate::{
+    config::{ConfigError, Mode},
+    database::{self, InmemoryStore, Store},
+    graphql, Config,
+};
 use derive_more::{Display, From};
 use entity::DatabaseRc;
+use std::sync::Arc;
 
 /// Alias for a result with a program error
 pub type ProgramResult<T, E = ProgramError> = std::result::Result<T, E>;
 
 #[derive(Debug, Display, From)]
 pub enum ProgramError {
+    #[display(fmt = "Invalid configuration: {}", _0)]
+    InvalidConfig(ConfigError),
     #[display(fmt = "Could not load database: {:?}", _0)]
     LoadDatabase(async_graphql::Error),
     #[display(fmt = "Could not start file watcher: {}", _0)]
@@ -21,23 +28,47 @@ pub enum ProgramError {
 pub struct Program;
 
 impl Program {
-    /// Runs our program
+    /// Runs our program, keeping parsed pages within the in-memory store
     pub async fn run(config: Config) -> ProgramResult<()> {
+        Self::run_with_store(config, Arc::new(InmemoryStore::default())).await
+    }
+
+    /// Runs our program, keeping parsed pages within the given store
+    pub async fn run_with_store(
+        config: Config,
+        store: Arc<dyn Store>,
+    ) -> ProgramResult<()> {
+        // Catch problems with the configuration before doing any work, as
+        // they would otherwise surface as failures deep within loading
+        config.validate().map_err(ProgramError::from)?;
+
+        // Printing the schema does not need any wikis, so we skip loading
+        // the database entirely
+        if config.mode == Mode::Schema {
+            println!("{}", graphql::schema_sdl());
+            return Ok(());
+        }
+
         // Load our database using the provided configuration and any
         // cached data from a previous run
-        let database =
-            database::load(&config).await.map_err(ProgramError::from)?;
+        let database = database::load(&config, store.as_ref())
+            .await
+            .map_err(ProgramError::from)?;
 
-        // Initialize our watcher to update the database based on changes
+        // Initialize our watcher to update the store based on changes
         // that occur in wikis and standalone files
-        let _watcher =
-            Watcher::initialize(&config, DatabaseRc::clone(&database))
-                .await
-                .map_err(ProgramError::from)?;
+        let _watcher = Watcher::initialize(
+            &config,
+            DatabaseRc::clone(&database),
+            Arc::clone(&store),
+        )
+        .await
+        .map_err(ProgramError::from)?;
 
         match config.mode {
-            Mode::Stdin => stdin::run(config).await,
-            Mode::Http => server::run(config).await,
+            Mode::Stdin => stdin::run(config, store).await,
+            Mode::Http => server::run(config, store).await,
+            Mode::Schema => unreachable!("Schema mode exits before loading"),
         }
 
         Ok(())
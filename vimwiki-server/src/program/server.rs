@@ -1,11 +1,11 @@
-use crate::{graphql, Config};
+use crate::{database::Store, graphql, Config};
 use log::info;
-use std::convert::Infallible;
+use std::{convert::Infallible, sync::Arc};
 use warp::{reply::Reply, Filter};
 
 macro_rules! graphql_endpoint {
-    ($path:expr, $program:expr) => {{
-        let schema = graphql::new_schema();
+    ($path:expr, $store:expr) => {{
+        let schema = graphql::new_schema($store);
         warp::path($path).and(
             async_graphql_warp::graphql(schema).and_then(
                 |(schema, request): (
@@ -45,11 +45,11 @@ macro_rules! graphql_playground_endpoint {
     }};
 }
 
-pub async fn run(config: Config) {
+pub async fn run(config: Config, store: Arc<dyn Store>) {
     let endpoint = format!("http://{}:{}/graphql", config.host, config.port);
     let endpoint_2 = endpoint.clone();
 
-    let graphql_filter = graphql_endpoint!("graphql", program);
+    let graphql_filter = graphql_endpoint!("graphql", store);
     let graphiql_filter = graphiql_endpoint!("graphiql", &endpoint);
     let graphql_playground_filter =
         graphql_playground_endpoint!("graphql_playground", &endpoint_2);
@@ -1,6 +1,7 @@
-use crate::{graphql, Config};
+use crate::{database::Store, graphql, Config};
 use log::{error, info};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 struct Input {
@@ -21,8 +22,8 @@ struct Output {
 }
 
 /// Spawns a worker to process stdin and communicate back over stdout & stderr
-pub async fn run(_config: Config) {
-    let schema = graphql::new_schema();
+pub async fn run(_config: Config, store: Arc<dyn Store>) {
+    let schema = graphql::new_schema(store);
 
     info!("Monitoring stdin...");
     // NOTE: For now, we are using std lib's stdin & stdout due to
@@ -1,8 +1,5 @@
-use crate::{
-    data::{ParsedFile, Wiki},
-    Config,
-};
-use entity::{TypedPredicate as P, *};
+use crate::{data::Wiki, database::Store, Config};
+use entity::*;
 use log::{error, trace};
 use notify::{
     event::{CreateKind, ModifyKind, RemoveKind, RenameMode},
@@ -127,14 +124,16 @@ impl Watcher {
 }
 
 impl Watcher {
-    /// Attempts to initialize a file/directory watcher using the given database
+    /// Attempts to initialize a file/directory watcher using the given
+    /// database, keeping the pages of changed files updated in the store
     pub async fn initialize(
         config: &Config,
         database: DatabaseRc,
+        store: Arc<dyn Store>,
     ) -> Result<Self, Error> {
         let (tx, rx) = mpsc::unbounded_channel::<notify::Event>();
         let internal_watcher = Self::new_internal_watcher(tx)?;
-        let _handle = Self::spawn_handle(config, Arc::clone(&database), rx);
+        let _handle = Self::spawn_handle(config, Arc::clone(&store), rx);
         let watcher = Self {
             watcher: Arc::new(Mutex::new(internal_watcher)),
             _handle,
@@ -142,12 +141,16 @@ impl Watcher {
             standalone: Mutex::new(HashSet::new()),
         };
 
-        watcher.watch_from_database(database).await;
+        watcher.watch_from_database(database, store.as_ref()).await;
 
         Ok(watcher)
     }
 
-    async fn watch_from_database(&self, database: DatabaseRc) {
+    async fn watch_from_database(
+        &self,
+        database: DatabaseRc,
+        store: &dyn Store,
+    ) {
         let wiki_paths: Vec<PathBuf> = database
             .find_all_typed::<Wiki>(Wiki::query().into())
             .expect("Database failed to query for wikis")
@@ -163,19 +166,12 @@ impl Watcher {
             }
         }
 
-        let standalone_file_paths: Vec<PathBuf> = database
-            .find_all_typed::<ParsedFile>(
-                ParsedFile::query()
-                    .where_path(P::lambda(move |path_str: String| {
-                        !wiki_paths
-                            .iter()
-                            .any(|p| p.ends_with(path_str.as_str()))
-                    }))
-                    .into(),
-            )
-            .expect("Database failed to query for standalone files")
+        let standalone_file_paths: Vec<PathBuf> = store
+            .all_paths()
+            .await
+            .expect("Store failed to provide paths of standalone files")
             .into_iter()
-            .map(|x| PathBuf::from(x.path()))
+            .filter(|path| !wiki_paths.iter().any(|p| path.starts_with(p)))
             .collect();
 
         for path in standalone_file_paths.iter() {
@@ -204,62 +200,171 @@ impl Watcher {
 
     fn spawn_handle(
         config: &Config,
-        _database: DatabaseRc,
+        store: Arc<dyn Store>,
         mut rx: mpsc::UnboundedReceiver<Event>,
     ) -> JoinHandle<()> {
         let exts = config.exts.clone();
         tokio::spawn(async move {
             while let Some(event) = rx.recv().await {
-                // Ensure that the event we receive is for a supported
-                // file extension
-                let not_for_valid_file_exts = event.paths.iter().any(|p| {
-                    p.extension()
-                        .map(|ex| !exts.iter().any(|ext| ext.as_str() == ex))
-                        .unwrap_or(true)
-                });
-                if not_for_valid_file_exts {
-                    continue;
-                }
+                handle_event(store.as_ref(), &exts, event).await;
+            }
+        })
+    }
+}
+
+/// Updates the store based on a change to files, ignoring the change if any
+/// of the files does not have one of the given extensions
+async fn handle_event(store: &dyn Store, exts: &[String], event: Event) {
+    // Ensure that the event we receive is for a supported file extension
+    let not_for_valid_file_exts = event.paths.iter().any(|p| {
+        p.extension()
+            .map(|ex| !exts.iter().any(|ext| ext.as_str() == ex))
+            .unwrap_or(true)
+    });
+    if not_for_valid_file_exts {
+        return;
+    }
+
+    trace!("Got event {:?} for paths {:?}", event.kind, event.paths);
 
-                trace!(
-                    "Got event {:?} for paths {:?}",
-                    event.kind,
-                    event.paths
-                );
-
-                match event.kind {
-                    EventKind::Create(CreateKind::File)
-                    | EventKind::Modify(ModifyKind::Data(_)) => {
-                        if let Err(x) = ParsedFile::load_all(&event.paths).await
-                        {
-                            error!("{}", x.into_server_error());
-                        }
-                    }
-                    EventKind::Remove(RemoveKind::File) => {
-                        if let Err(x) =
-                            ParsedFile::remove_all(&event.paths).await
-                        {
-                            error!("{}", x.into_server_error());
-                        }
-                    }
-                    EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
-                        if event.paths.len() == 2 {
-                            if let (Some(from), Some(to)) =
-                                (event.paths.first(), event.paths.last())
-                            {
-                                if let Err(x) =
-                                    ParsedFile::rename(from, to).await
-                                {
-                                    error!("{}", x.into_server_error());
-                                }
-                            }
-                        } else {
-                            error!("Unexpected total paths for a file rename");
-                        }
-                    }
-                    _ => {}
+    match event.kind {
+        EventKind::Create(CreateKind::File)
+        | EventKind::Modify(ModifyKind::Data(_))
+        | EventKind::Modify(ModifyKind::Metadata(_)) => {
+            for path in event.paths.iter() {
+                if let Err(x) = store.put_page(path).await {
+                    error!("{}", x.into_server_error());
                 }
             }
-        })
+        }
+        EventKind::Remove(RemoveKind::File) => {
+            for path in event.paths.iter() {
+                if let Err(x) = store.remove(path).await {
+                    error!("{}", x.into_server_error());
+                }
+            }
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+            if let [from, to] = event.paths.as_slice() {
+                if let Err(x) = store.rename(from, to).await {
+                    error!("{}", x.into_server_error());
+                }
+            } else {
+                error!("Unexpected total paths for a file rename");
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::StoreFuture;
+    use notify::event::DataChange;
+
+    /// Store that only records the paths it was asked to change
+    #[derive(Default)]
+    struct MockStore {
+        put: std::sync::Mutex<Vec<PathBuf>>,
+        renamed: std::sync::Mutex<Vec<(PathBuf, PathBuf)>>,
+        removed: std::sync::Mutex<Vec<PathBuf>>,
+    }
+
+    impl Store for MockStore {
+        fn get_page<'a>(
+            &'a self,
+            _path: &'a Path,
+        ) -> StoreFuture<'a, Option<Arc<vimwiki::Page<'static>>>> {
+            Box::pin(async { Ok(None) })
+        }
+
+        fn put_page<'a>(&'a self, path: &'a Path) -> StoreFuture<'a, ()> {
+            Box::pin(async move {
+                self.put.lock().unwrap().push(path.to_path_buf());
+                Ok(())
+            })
+        }
+
+        fn rename<'a>(
+            &'a self,
+            from: &'a Path,
+            to: &'a Path,
+        ) -> StoreFuture<'a, ()> {
+            Box::pin(async move {
+                self.renamed
+                    .lock()
+                    .unwrap()
+                    .push((from.to_path_buf(), to.to_path_buf()));
+                Ok(())
+            })
+        }
+
+        fn all_paths(&self) -> StoreFuture<'_, Vec<PathBuf>> {
+            Box::pin(async move { Ok(self.put.lock().unwrap().clone()) })
+        }
+
+        fn remove<'a>(&'a self, path: &'a Path) -> StoreFuture<'a, ()> {
+            Box::pin(async move {
+                self.removed.lock().unwrap().push(path.to_path_buf());
+                Ok(())
+            })
+        }
+    }
+
+    #[test]
+    fn handle_event_should_put_page_of_changed_file_into_store() {
+        let store = MockStore::default();
+        let exts = vec!["wiki".to_string()];
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        runtime.block_on(async {
+            let event = Event::new(EventKind::Modify(ModifyKind::Data(
+                DataChange::Content,
+            )));
+            handle_event(
+                &store,
+                &exts,
+                event.clone().add_path(PathBuf::from("/wiki/page.wiki")),
+            )
+            .await;
+            handle_event(
+                &store,
+                &exts,
+                event.add_path(PathBuf::from("/wiki/notes.txt")),
+            )
+            .await;
+        });
+
+        assert_eq!(
+            *store.put.lock().unwrap(),
+            vec![PathBuf::from("/wiki/page.wiki")]
+        );
+        assert!(store.removed.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn handle_event_should_rename_page_of_renamed_file_within_store() {
+        let store = MockStore::default();
+        let exts = vec!["wiki".to_string()];
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        runtime.block_on(handle_event(
+            &store,
+            &exts,
+            Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+                .add_path(PathBuf::from("/wiki/old.wiki"))
+                .add_path(PathBuf::from("/wiki/new.wiki")),
+        ));
+
+        assert_eq!(
+            *store.renamed.lock().unwrap(),
+            vec![(
+                PathBuf::from("/wiki/old.wiki"),
+                PathBuf::from("/wiki/new.wiki")
+            )]
+        );
+        assert!(store.put.lock().unwrap().is_empty());
+        assert!(store.removed.lock().unwrap().is_empty());
     }
 }
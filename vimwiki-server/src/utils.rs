@@ -36,3 +36,61 @@ pub fn walk_and_resolve_paths<E: AsRef<str>>(
         })
         .collect()
 }
+
+/// Writes the file and adds it to the database as if it was loaded, parsing
+/// it as vimwiki
+#[cfg(test)]
+pub fn make_file(path: &Path, text: &str) -> crate::data::ParsedFile {
+    use crate::data::{GraphqlDatabaseError, Page, ParsedFile};
+    use entity::*;
+    use std::convert::TryFrom;
+    use vimwiki::{self as v, Language};
+
+    fs::write(path, text).expect("Failed to write file");
+
+    let page: v::Page = Language::from_vimwiki_str(text).parse().unwrap();
+    let page = Page::try_from(page).expect("Failed to create page");
+    GraphqlDatabaseError::wrap(
+        ParsedFile::build()
+            .path(path.to_string_lossy().to_string())
+            .checksum(String::new())
+            .modified(0)
+            .diary_date(ParsedFile::diary_date_from_path(path))
+            .page(page.id())
+            .finish_and_commit(),
+    )
+    .expect("Failed to create file")
+}
+
+/// Writes each of the given files into a new temporary directory and adds
+/// them to the database as the files of a wiki with the given syntax and
+/// index, returning the directory of the wiki, which is removed once dropped
+#[cfg(test)]
+pub fn make_wiki(
+    index: usize,
+    syntax: crate::data::WikiSyntax,
+    files: &[(&str, &str)],
+) -> tempfile::TempDir {
+    use crate::data::{GraphqlDatabaseError, Wiki};
+    use entity::*;
+
+    let root = tempfile::tempdir().expect("Failed to create wiki dir");
+    let ids = files
+        .iter()
+        .map(|(name, text)| make_file(&root.path().join(name), text).id())
+        .collect();
+    GraphqlDatabaseError::wrap(
+        Wiki::build()
+            .index(index)
+            .name(None)
+            .syntax(syntax)
+            .file_extension(None)
+            .link_extension(None)
+            .path(root.path().to_string_lossy().to_string())
+            .files(ids)
+            .finish_and_commit(),
+    )
+    .expect("Failed to create wiki");
+
+    root
+}
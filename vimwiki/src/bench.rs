@@ -0,0 +1,116 @@
+use crate::{Language, Page};
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Represents a document of a corpus that failed to parse
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorpusError {
+    /// Name of the document that failed to parse
+    pub name: String,
+
+    /// Description of why the document failed to parse
+    pub message: String,
+}
+
+impl fmt::Display for CorpusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse {}: {}", self.name, self.message)
+    }
+}
+
+impl Error for CorpusError {}
+
+/// Parses each named document as a vimwiki page, returning the total time
+/// spent parsing across all documents
+///
+/// Fails with the name of the first document that does not parse, as
+/// timings would otherwise not be comparable between runs
+pub fn parse_corpus<N: AsRef<str>, T: AsRef<str>>(
+    documents: &[(N, T)],
+) -> Result<Duration, CorpusError> {
+    let mut total = Duration::default();
+
+    for (name, document) in documents {
+        let language = Language::from_vimwiki_str(document.as_ref());
+
+        let start = Instant::now();
+        let result = language.parse::<Page>();
+        total += start.elapsed();
+
+        if let Err(x) = result {
+            return Err(CorpusError {
+                name: name.as_ref().to_string(),
+                message: x.to_string(),
+            });
+        }
+    }
+
+    Ok(total)
+}
+
+/// Loads the contents of every `.wiki` fixture used by the tests of this
+/// crate, each named by its path relative to the fixtures directory and
+/// ordered by path so the corpus is the same between runs
+pub fn load_fixtures() -> io::Result<Vec<(String, String)>> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    let mut paths = Vec::new();
+    collect_wiki_files(&root, &mut paths)?;
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let name = path.strip_prefix(&root).unwrap_or(path);
+            Ok((
+                name.to_string_lossy().into_owned(),
+                fs::read_to_string(path)?,
+            ))
+        })
+        .collect()
+}
+
+/// Recursively gathers the paths of `.wiki` files within a directory
+fn collect_wiki_files(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_wiki_files(&path, paths)?;
+        } else if path.extension().is_some_and(|x| x == "wiki") {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_corpus_should_parse_all_fixtures_in_nonzero_time() {
+        let fixtures = load_fixtures().expect("Failed to load fixtures");
+        assert!(!fixtures.is_empty(), "No fixtures were loaded");
+        assert!(fixtures.iter().all(|(name, _)| name.ends_with(".wiki")));
+
+        let total = parse_corpus(&fixtures).unwrap_or_else(|x| panic!("{}", x));
+        assert!(total > Duration::default());
+    }
+
+    #[test]
+    fn corpus_error_should_name_the_document_that_failed() {
+        let error = CorpusError {
+            name: String::from("vimwiki/page.wiki"),
+            message: String::from("unexpected input"),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse vimwiki/page.wiki: unexpected input"
+        );
+    }
+}
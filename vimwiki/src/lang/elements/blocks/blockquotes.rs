@@ -1,4 +1,10 @@
-use crate::StrictEq;
+use crate::{
+    lang::elements::{
+        InlineElement, InlineElementContainer, IntoChildren, Located, Region,
+        RegionsMut,
+    },
+    StrictEq,
+};
 use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -8,6 +14,9 @@ use std::borrow::Cow;
 )]
 pub struct Blockquote<'a> {
     pub lines: Vec<Cow<'a, str>>,
+
+    /// Source of the quote taken from a trailing `-- Author` line
+    pub attribution: Option<InlineElementContainer<'a>>,
 }
 
 impl<'a> Blockquote<'a> {
@@ -15,6 +24,11 @@ impl<'a> Blockquote<'a> {
     pub fn lines(&self) -> &[Cow<'a, str>] {
         &self.lines
     }
+
+    /// Returns the attribution of the blockquote if it has one
+    pub fn attribution(&self) -> Option<&InlineElementContainer<'a>> {
+        self.attribution.as_ref()
+    }
 }
 
 impl Blockquote<'_> {
@@ -31,8 +45,9 @@ impl Blockquote<'_> {
                 })
             })
             .collect();
+        let attribution = self.attribution.as_ref().map(|x| x.to_borrowed());
 
-        Blockquote { lines }
+        Blockquote { lines, attribution }
     }
 
     pub fn into_owned(self) -> Blockquote<'static> {
@@ -41,15 +56,38 @@ impl Blockquote<'_> {
             .into_iter()
             .map(|x| Cow::from(x.into_owned()))
             .collect();
+        let attribution = self.attribution.map(|x| x.into_owned());
+
+        Blockquote { lines, attribution }
+    }
+}
+
+impl<'a> IntoChildren for Blockquote<'a> {
+    type Child = Located<InlineElement<'a>>;
+
+    fn into_children(self) -> Vec<Self::Child> {
+        self.attribution
+            .map(|x| x.into_children())
+            .unwrap_or_default()
+    }
+}
 
-        Blockquote { lines }
+impl<'a> RegionsMut for Blockquote<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        if let Some(x) = self.attribution.as_mut() {
+            x.for_each_region_mut(f);
+        }
     }
 }
 
 impl<'a> StrictEq for Blockquote<'a> {
-    /// Same as PartialEq
-    #[inline]
+    /// Performs strict_eq on lines and attribution
     fn strict_eq(&self, other: &Self) -> bool {
-        self == other
+        self.lines == other.lines
+            && match (&self.attribution, &other.attribution) {
+                (Some(x), Some(y)) => x.strict_eq(y),
+                (None, None) => true,
+                _ => false,
+            }
     }
 }
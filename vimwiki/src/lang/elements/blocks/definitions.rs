@@ -1,7 +1,7 @@
 use crate::{
     lang::elements::{
-        InlineBlockElement, InlineElement, InlineElementContainer,
-        IntoChildren, Located,
+        Element, InlineBlockElement, InlineElement, InlineElementContainer,
+        IntoChildren, List, Located, Region, RegionsMut,
     },
     StrictEq,
 };
@@ -9,6 +9,7 @@ use derive_more::{Constructor, Display, IntoIterator};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{hash_map, HashMap},
+    fmt,
     hash::{Hash, Hasher},
 };
 
@@ -88,6 +89,20 @@ impl<'a> From<&'a str> for DefinitionListValue<'a> {
     }
 }
 
+impl<'a> From<Located<InlineElement<'a>>> for DefinitionListValue<'a> {
+    /// Creates a new term by wrapping the given element in
+    /// `InlineElementContainer`
+    fn from(element: Located<InlineElement<'a>>) -> Self {
+        Self::new(InlineElementContainer::from(element))
+    }
+}
+
+impl<'a> RegionsMut for DefinitionListValue<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        self.0.for_each_region_mut(f);
+    }
+}
+
 impl<'a> StrictEq for DefinitionListValue<'a> {
     /// Performs strict_eq on inner container
     #[inline]
@@ -99,8 +114,164 @@ impl<'a> StrictEq for DefinitionListValue<'a> {
 /// Represents the type alias used for a single term
 pub type Term<'a> = DefinitionListValue<'a>;
 
-/// Represents the type alias used for a single definition
-pub type Definition<'a> = DefinitionListValue<'a>;
+/// Represents a single definition of a term, which is either inline content
+/// alone or inline content followed by a list indented beneath it
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Definition<'a> {
+    /// Definition comprised of inline content alone
+    Inline(DefinitionListValue<'a>),
+
+    /// Definition whose inline content is followed by a nested list
+    WithList {
+        value: DefinitionListValue<'a>,
+        list: Located<List<'a>>,
+    },
+}
+
+impl Definition<'_> {
+    pub fn to_borrowed(&self) -> Definition<'_> {
+        match self {
+            Self::Inline(x) => Definition::Inline(x.to_borrowed()),
+            Self::WithList { value, list } => Definition::WithList {
+                value: value.to_borrowed(),
+                list: list.as_ref().map(List::to_borrowed),
+            },
+        }
+    }
+
+    pub fn into_owned(self) -> Definition<'static> {
+        match self {
+            Self::Inline(x) => Definition::Inline(x.into_owned()),
+            Self::WithList { value, list } => Definition::WithList {
+                value: value.into_owned(),
+                list: list.map(List::into_owned),
+            },
+        }
+    }
+}
+
+impl<'a> Definition<'a> {
+    /// Returns the inline content of the definition
+    pub fn value(&self) -> &DefinitionListValue<'a> {
+        match self {
+            Self::Inline(x) => x,
+            Self::WithList { value, .. } => value,
+        }
+    }
+
+    /// Returns the list nested beneath the definition, if it has one
+    pub fn list(&self) -> Option<&Located<List<'a>>> {
+        match self {
+            Self::Inline(_) => None,
+            Self::WithList { list, .. } => Some(list),
+        }
+    }
+
+    pub fn as_inner(&self) -> &InlineElementContainer<'a> {
+        self.value().as_inner()
+    }
+
+    /// Converts into the inline content of the definition, discarding any
+    /// nested list
+    pub fn into_inner(self) -> InlineElementContainer<'a> {
+        match self {
+            Self::Inline(x) => x.into_inner(),
+            Self::WithList { value, .. } => value.into_inner(),
+        }
+    }
+}
+
+impl<'a> fmt::Display for Definition<'a> {
+    /// Displays the inline content of the definition
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<'a> IntoChildren for Definition<'a> {
+    type Child = Located<Element<'a>>;
+
+    fn into_children(self) -> Vec<Self::Child> {
+        let (value, list) = match self {
+            Self::Inline(x) => (x, None),
+            Self::WithList { value, list } => (value, Some(list)),
+        };
+
+        value
+            .into_children()
+            .into_iter()
+            .map(|x| x.map(Element::from))
+            .chain(list.map(|x| x.map(Element::from)))
+            .collect()
+    }
+}
+
+impl<'a, 'b> PartialEq<InlineElementContainer<'b>> for Definition<'a> {
+    fn eq(&self, other: &InlineElementContainer<'b>) -> bool {
+        self.list().is_none() && self.value() == other
+    }
+}
+
+impl<'a> PartialEq<String> for Definition<'a> {
+    fn eq(&self, other: &String) -> bool {
+        self.list().is_none() && self.value() == other
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for Definition<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self.list().is_none() && self.value() == other
+    }
+}
+
+impl<'a> From<DefinitionListValue<'a>> for Definition<'a> {
+    fn from(value: DefinitionListValue<'a>) -> Self {
+        Self::Inline(value)
+    }
+}
+
+impl<'a> From<&'a str> for Definition<'a> {
+    /// Creates a new inline definition from the given str
+    fn from(s: &'a str) -> Self {
+        Self::Inline(DefinitionListValue::from(s))
+    }
+}
+
+impl<'a> From<Located<InlineElement<'a>>> for Definition<'a> {
+    /// Creates a new inline definition from the given element
+    fn from(element: Located<InlineElement<'a>>) -> Self {
+        Self::Inline(DefinitionListValue::from(element))
+    }
+}
+
+impl<'a> RegionsMut for Definition<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        match self {
+            Self::Inline(x) => x.for_each_region_mut(f),
+            Self::WithList { value, list } => {
+                value.for_each_region_mut(f);
+                list.for_each_region_mut(f);
+            }
+        }
+    }
+}
+
+impl<'a> StrictEq for Definition<'a> {
+    /// Performs strict_eq on the inline content and any nested list
+    fn strict_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Inline(x), Self::Inline(y)) => x.strict_eq(y),
+            (
+                Self::WithList { value, list },
+                Self::WithList {
+                    value: other_value,
+                    list: other_list,
+                },
+            ) => value.strict_eq(other_value) && list.strict_eq(other_list),
+            _ => false,
+        }
+    }
+}
 
 /// Represents a list of terms and definitions, where a term can have multiple
 /// definitions associated with it
@@ -130,9 +301,7 @@ impl DefinitionList<'_> {
                     key.as_ref().map(DefinitionListValue::to_borrowed),
                     value
                         .iter()
-                        .map(|x| {
-                            x.as_ref().map(DefinitionListValue::to_borrowed)
-                        })
+                        .map(|x| x.as_ref().map(Definition::to_borrowed))
                         .collect(),
                 )
             })
@@ -150,7 +319,7 @@ impl DefinitionList<'_> {
                     key.map(DefinitionListValue::into_owned),
                     value
                         .into_iter()
-                        .map(|x| x.map(DefinitionListValue::into_owned))
+                        .map(|x| x.map(Definition::into_owned))
                         .collect(),
                 )
             })
@@ -228,12 +397,50 @@ impl<'a> From<Vec<(Located<Term<'a>>, Vec<Located<Definition<'a>>>)>>
     }
 }
 
+impl<'a> Hash for DefinitionList<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Grab all terms and sort them so we get a reproducible iteration
+        // over the mapping
+        let mut entries = self
+            .mapping
+            .iter()
+            .map(|(term, definitions)| {
+                (term.as_inner().to_string(), definitions)
+            })
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        for (term, definitions) in entries {
+            term.hash(state);
+            definitions.hash(state);
+        }
+    }
+}
+
+impl<'a> RegionsMut for DefinitionList<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        // NOTE: Terms are the keys of the mapping and cannot be changed in
+        //       place, so the mapping is rebuilt from the stripped entries
+        self.mapping = self
+            .mapping
+            .drain()
+            .map(|(mut term, mut definitions)| {
+                term.for_each_region_mut(f);
+                for definition in definitions.iter_mut() {
+                    definition.for_each_region_mut(f);
+                }
+                (term, definitions)
+            })
+            .collect();
+    }
+}
+
 impl<'a> StrictEq for DefinitionList<'a> {
     /// Performs strict_eq on inner mapping
     fn strict_eq(&self, other: &Self) -> bool {
         self.mapping.len() == other.mapping.len()
             && self.mapping.iter().all(|(key, value)| {
-                other.mapping.get_key_value(key).map_or(false, |(k, v)| {
+                other.mapping.get_key_value(key).is_some_and(|(k, v)| {
                     key.strict_eq(k) && value.strict_eq(v)
                 })
             })
@@ -243,7 +450,8 @@ impl<'a> StrictEq for DefinitionList<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{InlineElement, Located};
+    use crate::{InlineElement, Link, Located, WikiLink};
+    use std::path::PathBuf;
 
     #[test]
     fn term_should_equal_other_instance_if_string_representations_are_same() {
@@ -281,6 +489,15 @@ mod tests {
         assert_eq!(term, other);
     }
 
+    #[test]
+    fn term_should_be_constructable_from_a_located_inline_element() {
+        let link = Link::from(WikiLink::from(PathBuf::from("some/page")));
+        let term = Term::from(Located::from(InlineElement::Link(link)));
+
+        assert_eq!(term.as_inner().elements.len(), 1);
+        assert_eq!(term.to_string(), "some/page");
+    }
+
     #[test]
     fn term_should_hash_using_its_string_representation() {
         let t1 = Term::from("term");
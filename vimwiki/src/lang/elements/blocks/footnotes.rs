@@ -0,0 +1,69 @@
+use crate::{
+    lang::elements::{
+        InlineElement, InlineElementContainer, IntoChildren, Located, Region,
+        RegionsMut,
+    },
+    StrictEq,
+};
+use derive_more::Constructor;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Represents the definition of a footnote, which is referenced elsewhere
+/// by its label
+///
+/// In vimwiki, [^1]: some text would become
+///
+/// FootnoteDef { label: 1, content: [ Text(some text) ] }
+///
+#[derive(
+    Constructor, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub struct FootnoteDef<'a> {
+    pub label: Cow<'a, str>,
+    pub content: InlineElementContainer<'a>,
+}
+
+impl FootnoteDef<'_> {
+    pub fn to_borrowed(&self) -> FootnoteDef<'_> {
+        use self::Cow::*;
+
+        let label = Cow::Borrowed(match &self.label {
+            Borrowed(x) => *x,
+            Owned(x) => x.as_str(),
+        });
+
+        FootnoteDef {
+            label,
+            content: self.content.to_borrowed(),
+        }
+    }
+
+    pub fn into_owned(self) -> FootnoteDef<'static> {
+        FootnoteDef {
+            label: Cow::from(self.label.into_owned()),
+            content: self.content.into_owned(),
+        }
+    }
+}
+
+impl<'a> IntoChildren for FootnoteDef<'a> {
+    type Child = Located<InlineElement<'a>>;
+
+    fn into_children(self) -> Vec<Self::Child> {
+        self.content.into_children()
+    }
+}
+
+impl<'a> RegionsMut for FootnoteDef<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        self.content.for_each_region_mut(f);
+    }
+}
+
+impl<'a> StrictEq for FootnoteDef<'a> {
+    /// Performs strict_eq on label and content
+    fn strict_eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.content.strict_eq(&other.content)
+    }
+}
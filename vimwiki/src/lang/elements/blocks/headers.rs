@@ -1,11 +1,13 @@
 use crate::{
     lang::elements::{
-        InlineElement, InlineElementContainer, IntoChildren, Located,
+        InlineElement, InlineElementContainer, IntoChildren, Located, Region,
+        RegionsMut,
     },
     StrictEq,
 };
 use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 #[derive(
     Constructor, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
@@ -14,6 +16,10 @@ pub struct Header<'a> {
     pub level: usize,
     pub content: InlineElementContainer<'a>,
     pub centered: bool,
+
+    /// Explicit anchor id taken from a `#custom-id` following the closing
+    /// marker of the header, used in place of the slug of its content
+    pub custom_id: Option<Cow<'a, str>>,
 }
 
 impl Header<'_> {
@@ -22,6 +28,7 @@ impl Header<'_> {
             level: self.level,
             content: self.content.to_borrowed(),
             centered: self.centered,
+            custom_id: self.custom_id.as_deref().map(Cow::Borrowed),
         }
     }
 
@@ -30,6 +37,7 @@ impl Header<'_> {
             level: self.level,
             content: self.content.into_owned(),
             centered: self.centered,
+            custom_id: self.custom_id.map(|x| Cow::from(x.into_owned())),
         }
     }
 }
@@ -50,11 +58,37 @@ impl<'a> IntoChildren for Header<'a> {
     }
 }
 
+impl<'a> RegionsMut for Header<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        self.content.for_each_region_mut(f);
+    }
+}
+
 impl<'a> StrictEq for Header<'a> {
-    /// Performs strict_eq on level, centered status, and content
+    /// Performs strict_eq on level, centered status, custom id, and content
     fn strict_eq(&self, other: &Self) -> bool {
         self.level == other.level
             && self.centered == other.centered
+            && self.custom_id == other.custom_id
             && self.content.strict_eq(&other.content)
     }
 }
+
+/// Represents a header within a tree of headers, where children are the
+/// headers nested beneath it by level
+#[derive(
+    Constructor, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub struct OutlineNode {
+    /// The text of the header
+    pub text: String,
+
+    /// The level of the header
+    pub level: usize,
+
+    /// The region of the header within the page
+    pub region: Region,
+
+    /// Headers nested beneath this header
+    pub children: Vec<OutlineNode>,
+}
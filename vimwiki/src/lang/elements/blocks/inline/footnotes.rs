@@ -0,0 +1,59 @@
+use crate::StrictEq;
+use derive_more::{Constructor, Display};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Represents a reference to a footnote by its label
+///
+/// In vimwiki, `[^1]` would become
+///
+/// `FootnoteRef { label: 1 }`
+///
+#[derive(
+    Constructor,
+    Clone,
+    Debug,
+    Display,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[display(fmt = "[^{}]", label)]
+pub struct FootnoteRef<'a> {
+    pub label: Cow<'a, str>,
+}
+
+impl FootnoteRef<'_> {
+    pub fn as_borrowed(&self) -> FootnoteRef<'_> {
+        use self::Cow::*;
+
+        let label = Cow::Borrowed(match &self.label {
+            Borrowed(x) => *x,
+            Owned(x) => x.as_str(),
+        });
+
+        FootnoteRef { label }
+    }
+
+    pub fn into_owned(self) -> FootnoteRef<'static> {
+        let label = Cow::from(self.label.into_owned());
+
+        FootnoteRef { label }
+    }
+}
+
+impl<'a> From<&'a str> for FootnoteRef<'a> {
+    fn from(s: &'a str) -> Self {
+        Self::new(Cow::from(s))
+    }
+}
+
+impl<'a> StrictEq for FootnoteRef<'a> {
+    /// Same as PartialEq
+    #[inline]
+    fn strict_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
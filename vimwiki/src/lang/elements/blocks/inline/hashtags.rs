@@ -0,0 +1,59 @@
+use crate::StrictEq;
+use derive_more::{Constructor, Display};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Represents a hashtag found within text
+///
+/// In markdown-flavored wikis, #project/sub would become
+///
+/// Hashtag { name: project/sub }
+///
+#[derive(
+    Constructor,
+    Clone,
+    Debug,
+    Display,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[display(fmt = "#{}", name)]
+pub struct Hashtag<'a> {
+    pub name: Cow<'a, str>,
+}
+
+impl Hashtag<'_> {
+    pub fn as_borrowed(&self) -> Hashtag<'_> {
+        use self::Cow::*;
+
+        let name = Cow::Borrowed(match &self.name {
+            Borrowed(x) => *x,
+            Owned(x) => x.as_str(),
+        });
+
+        Hashtag { name }
+    }
+
+    pub fn into_owned(self) -> Hashtag<'static> {
+        let name = Cow::from(self.name.into_owned());
+
+        Hashtag { name }
+    }
+}
+
+impl<'a> From<&'a str> for Hashtag<'a> {
+    fn from(s: &'a str) -> Self {
+        Self::new(Cow::from(s))
+    }
+}
+
+impl<'a> StrictEq for Hashtag<'a> {
+    /// Same as PartialEq
+    #[inline]
+    fn strict_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
@@ -1,41 +1,85 @@
 use super::{Anchor, Description};
 use crate::StrictEq;
 use chrono::naive::NaiveDate;
-use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
-use std::{convert::TryFrom, fmt};
+use std::{borrow::Cow, convert::TryFrom, fmt};
+
+/// Format of the date of a diary entry
+const DATE_FORMAT: &str = "%Y-%m-%d";
 
 /// Represents a link to an entry in the diary wiki
-#[derive(
-    Constructor, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct DiaryLink<'a> {
-    pub date: NaiveDate,
+    /// Date of the diary entry, or none if the date as written is not a
+    /// valid date in the form `YYYY-MM-DD`
+    pub date: Option<NaiveDate>,
+
+    /// Date of the diary entry as written, which is kept as-is when malformed
+    pub date_str: Cow<'a, str>,
+
     pub description: Option<Description<'a>>,
     pub anchor: Option<Anchor<'a>>,
 }
 
+impl<'a> DiaryLink<'a> {
+    pub fn new(
+        date: NaiveDate,
+        description: Option<Description<'a>>,
+        anchor: Option<Anchor<'a>>,
+    ) -> Self {
+        Self {
+            date: Some(date),
+            date_str: Cow::from(date.format(DATE_FORMAT).to_string()),
+            description,
+            anchor,
+        }
+    }
+
+    /// Creates a link from a date as written, parsing it as a date in the
+    /// form `YYYY-MM-DD` and keeping the link without a date if malformed
+    pub fn from_date_str(
+        date_str: impl Into<Cow<'a, str>>,
+        description: Option<Description<'a>>,
+        anchor: Option<Anchor<'a>>,
+    ) -> Self {
+        let date_str = date_str.into();
+        Self {
+            date: NaiveDate::parse_from_str(&date_str, DATE_FORMAT).ok(),
+            date_str,
+            description,
+            anchor,
+        }
+    }
+}
+
 impl DiaryLink<'_> {
     pub fn to_borrowed(&self) -> DiaryLink {
-        let date = self.date;
+        use self::Cow::*;
+
+        let date_str = Cow::Borrowed(match &self.date_str {
+            Borrowed(x) => *x,
+            Owned(x) => x.as_str(),
+        });
         let description =
             self.description.as_ref().map(Description::to_borrowed);
         let anchor = self.anchor.as_ref().map(Anchor::to_borrowed);
 
         DiaryLink {
-            date,
+            date: self.date,
+            date_str,
             description,
             anchor,
         }
     }
 
     pub fn into_owned(self) -> DiaryLink<'static> {
-        let date = self.date;
+        let date_str = Cow::from(self.date_str.into_owned());
         let description = self.description.map(Description::into_owned);
         let anchor = self.anchor.map(Anchor::into_owned);
 
         DiaryLink {
-            date,
+            date: self.date,
+            date_str,
             description,
             anchor,
         }
@@ -47,7 +91,7 @@ impl<'a> fmt::Display for DiaryLink<'a> {
         if let Some(desc) = self.description.as_ref() {
             write!(f, "{}", desc)
         } else {
-            write!(f, "{}", self.date)?;
+            write!(f, "{}", self.date_str)?;
             if let Some(anchor) = self.anchor.as_ref() {
                 write!(f, "{}", anchor)?;
             }
@@ -66,7 +110,7 @@ impl TryFrom<&str> for DiaryLink<'static> {
     type Error = chrono::format::ParseError;
 
     fn try_from(str_date: &str) -> Result<Self, Self::Error> {
-        let date = NaiveDate::parse_from_str(str_date, "%Y-%m-%d")?;
+        let date = NaiveDate::parse_from_str(str_date, DATE_FORMAT)?;
         Ok(Self::from(date))
     }
 }
@@ -2,7 +2,7 @@ use super::{Anchor, Description, WikiLink};
 use crate::StrictEq;
 use derive_more::{Constructor, Display, From};
 use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, fmt, path::Path};
+use std::{borrow::Cow, collections::HashMap, fmt, path::Path};
 
 /// Represents a link to a file or directory in another wiki
 #[derive(
@@ -55,6 +55,13 @@ impl<'a> InterWikiLink<'a> {
         }
     }
 
+    pub fn link_mut(&mut self) -> &mut WikiLink<'a> {
+        match self {
+            Self::Indexed(x) => &mut x.link,
+            Self::Named(x) => &mut x.link,
+        }
+    }
+
     pub fn path(&self) -> &Path {
         &self.link().path
     }
@@ -66,6 +73,24 @@ impl<'a> InterWikiLink<'a> {
     pub fn anchor(&self) -> Option<&Anchor> {
         self.link().anchor.as_ref()
     }
+
+    /// Expands the wiki referenced by this link into its root using the
+    /// registry, returning none if the wiki is not registered
+    pub fn resolve(
+        &self,
+        registry: &InterWikiRegistry,
+    ) -> Option<ResolvedLink> {
+        let root = match self {
+            Self::Indexed(x) => registry.indexed_root(x.index)?,
+            Self::Named(x) => registry.named_root(&x.name)?,
+        };
+
+        Some(ResolvedLink {
+            root: root.to_string(),
+            path: self.link().decoded_target().into_owned(),
+            anchor: self.anchor().map(|x| x.clone().into_owned()),
+        })
+    }
 }
 
 impl<'a> StrictEq for InterWikiLink<'a> {
@@ -178,3 +203,129 @@ impl<'a> StrictEq for NamedInterWikiLink<'a> {
         self == other
     }
 }
+
+/// Represents the mapping of wikis referenced by interwiki links to their
+/// roots, which can be either a path or a url
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InterWikiRegistry {
+    indexed: HashMap<u32, String>,
+    named: HashMap<String, String>,
+}
+
+impl InterWikiRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the root of the wiki referenced by index as in `wiki1:`,
+    /// returning the previous root if there was one
+    pub fn insert_indexed(
+        &mut self,
+        index: u32,
+        root: impl Into<String>,
+    ) -> Option<String> {
+        self.indexed.insert(index, root.into())
+    }
+
+    /// Registers the root of the wiki referenced by name as in `wn.name:`,
+    /// returning the previous root if there was one
+    pub fn insert_named(
+        &mut self,
+        name: impl Into<String>,
+        root: impl Into<String>,
+    ) -> Option<String> {
+        self.named.insert(name.into(), root.into())
+    }
+
+    /// Returns the root of the wiki with the given index
+    pub fn indexed_root(&self, index: u32) -> Option<&str> {
+        self.indexed.get(&index).map(String::as_str)
+    }
+
+    /// Returns the root of the wiki with the given name
+    pub fn named_root(&self, name: &str) -> Option<&str> {
+        self.named.get(name).map(String::as_str)
+    }
+}
+
+/// Represents an interwiki link whose wiki has been expanded into its root
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ResolvedLink {
+    /// Root of the wiki, either a path or a url
+    pub root: String,
+
+    /// Path of the page within the wiki
+    pub path: String,
+
+    /// Anchor within the page, if there is one
+    pub anchor: Option<Anchor<'static>>,
+}
+
+impl fmt::Display for ResolvedLink {
+    /// Writes the root joined with the path, followed by the anchor
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.root.trim_end_matches('/'))?;
+        if !self.path.is_empty() {
+            write!(f, "/{}", self.path.trim_start_matches('/'))?;
+        }
+        if let Some(anchor) = self.anchor.as_ref() {
+            write!(f, "{}", anchor)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::elements::Link;
+    use std::path::PathBuf;
+
+    fn indexed_link(index: u32, path: &str) -> Link<'static> {
+        Link::from(InterWikiLink::from(IndexedInterWikiLink::new(
+            index,
+            WikiLink::from(PathBuf::from(path)),
+        )))
+    }
+
+    #[test]
+    fn resolve_interwiki_should_expand_registered_prefix_to_root() {
+        let mut registry = InterWikiRegistry::new();
+        registry.insert_indexed(1, "https://example.com/wiki/");
+        registry.insert_named("notes", "/home/user/notes");
+
+        let resolved = indexed_link(1, "Some Page")
+            .resolve_interwiki(&registry)
+            .expect("Failed to resolve indexed link");
+        assert_eq!(resolved.root, "https://example.com/wiki/");
+        assert_eq!(resolved.path, "Some Page");
+        assert_eq!(resolved.to_string(), "https://example.com/wiki/Some Page");
+
+        let link = Link::from(InterWikiLink::from(NamedInterWikiLink::new(
+            Cow::from("notes"),
+            WikiLink::new(
+                Cow::from(Path::new("todo")),
+                None,
+                Some(Anchor::from("today")),
+            ),
+        )));
+        let resolved = link
+            .resolve_interwiki(&registry)
+            .expect("Failed to resolve named link");
+        assert_eq!(resolved.to_string(), "/home/user/notes/todo#today");
+    }
+
+    #[test]
+    fn resolve_interwiki_should_return_none_for_unregistered_prefix() {
+        let mut registry = InterWikiRegistry::new();
+        registry.insert_indexed(1, "/wiki");
+
+        assert_eq!(indexed_link(2, "Page").resolve_interwiki(&registry), None);
+        assert_eq!(
+            Link::from(WikiLink::from(PathBuf::from("Page")))
+                .resolve_interwiki(&registry),
+            None
+        );
+    }
+}
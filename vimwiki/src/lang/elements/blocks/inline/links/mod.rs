@@ -1,11 +1,20 @@
-use crate::StrictEq;
+use crate::{
+    lang::elements::{InlineElementContainer, Region, RegionsMut},
+    StrictEq,
+};
+use chrono::NaiveDate;
 use derive_more::{
     Constructor, Deref, DerefMut, Display, From, Index, IndexMut, Into,
     IntoIterator, TryInto,
 };
 use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, fmt};
-use uriparse::URI;
+use std::{
+    borrow::Cow,
+    convert::TryFrom,
+    fmt,
+    path::{Component, Path, PathBuf},
+};
+use uriparse::{Fragment, URI};
 
 mod diary;
 pub use diary::DiaryLink;
@@ -14,11 +23,17 @@ mod external;
 pub use external::{ExternalFileLink, ExternalFileLinkScheme};
 
 mod interwiki;
-pub use interwiki::{IndexedInterWikiLink, InterWikiLink, NamedInterWikiLink};
+pub use interwiki::{
+    IndexedInterWikiLink, InterWikiLink, InterWikiRegistry, NamedInterWikiLink,
+    ResolvedLink,
+};
 
 mod raw;
 pub use raw::RawLink;
 
+mod reference;
+pub use reference::ReferenceLink;
+
 mod transclusion;
 pub use transclusion::TransclusionLink;
 
@@ -41,6 +56,10 @@ pub use wiki::WikiLink;
 pub enum Description<'a> {
     Text(Cow<'a, str>),
     Uri(URI<'a>),
+
+    /// Description containing decorations or other inline elements beyond
+    /// plain text, such as `[[link|*bold* text]]`
+    Inline(InlineElementContainer<'a>),
 }
 
 impl Description<'_> {
@@ -53,6 +72,7 @@ impl Description<'_> {
                 Owned(x) => x.as_str(),
             })),
             Self::Uri(ref x) => Description::from(uri_to_borrowed(x)),
+            Self::Inline(ref x) => Description::from(x.to_borrowed()),
         }
     }
 
@@ -60,6 +80,7 @@ impl Description<'_> {
         match self {
             Self::Text(x) => Description::from(Cow::from(x.into_owned())),
             Self::Uri(x) => Description::from(x.into_owned()),
+            Self::Inline(x) => Description::from(x.into_owned()),
         }
     }
 }
@@ -86,10 +107,13 @@ impl<'a> From<&'a str> for Description<'a> {
 }
 
 impl<'a> StrictEq for Description<'a> {
-    /// Same as PartialEq
-    #[inline]
+    /// Performs strict_eq check on inline elements, otherwise same as
+    /// PartialEq
     fn strict_eq(&self, other: &Self) -> bool {
-        self == other
+        match (self, other) {
+            (Self::Inline(x), Self::Inline(y)) => x.strict_eq(y),
+            _ => self == other,
+        }
     }
 }
 
@@ -184,6 +208,20 @@ pub enum Link<'a> {
     Raw(RawLink<'a>),
     ExternalFile(ExternalFileLink<'a>),
     Transclusion(TransclusionLink<'a>),
+    Reference(ReferenceLink<'a>),
+}
+
+/// Represents the kind of target a link points to, determined by the shape
+/// of the target rather than the syntax used to write the link
+#[derive(
+    Copy, Clone, Debug, Display, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub enum LinkKind {
+    Wiki,
+    InterWiki,
+    Diary,
+    Url,
+    Anchor,
 }
 
 impl Link<'_> {
@@ -195,6 +233,7 @@ impl Link<'_> {
             Self::Raw(x) => Link::from(x.to_borrowed()),
             Self::ExternalFile(x) => Link::from(x.to_borrowed()),
             Self::Transclusion(x) => Link::from(x.to_borrowed()),
+            Self::Reference(x) => Link::from(x.to_borrowed()),
         }
     }
 
@@ -206,19 +245,140 @@ impl Link<'_> {
             Self::Raw(x) => Link::from(x.into_owned()),
             Self::ExternalFile(x) => Link::from(x.into_owned()),
             Self::Transclusion(x) => Link::from(x.into_owned()),
+            Self::Reference(x) => Link::from(x.into_owned()),
         }
     }
 }
 
 impl<'a> Link<'a> {
+    /// Creates a link to a page within the current wiki, such as `[[page]]`
+    pub fn wiki(path: impl Into<WikiLink<'a>>) -> Self {
+        Self::Wiki(path.into())
+    }
+
+    /// Creates a link to a url, such as `https://example.com`
+    pub fn url(uri: URI<'a>) -> Self {
+        Self::Raw(RawLink::from(uri))
+    }
+
+    /// Creates a link to the diary entry of a date, such as
+    /// `[[diary:2021-03-04]]`
+    pub fn diary(date: NaiveDate) -> Self {
+        Self::Diary(DiaryLink::from(date))
+    }
+
+    /// Creates a link to an anchor within the current page, such as
+    /// `[[#anchor]]`
+    ///
+    /// NOTE: Named `local_anchor` as `anchor` already returns the anchor of
+    ///       an existing link
+    pub fn local_anchor(anchor: impl Into<Anchor<'a>>) -> Self {
+        Self::Wiki(WikiLink::new(
+            Cow::from(PathBuf::new()),
+            None,
+            Some(anchor.into()),
+        ))
+    }
+
+    /// Sets the description of the link, where raw urls remain urls that
+    /// are written with `[[https://example.com|description]]`
+    pub fn with_description(
+        self,
+        description: impl Into<Description<'a>>,
+    ) -> Self {
+        let description = description.into();
+        match self {
+            Self::Wiki(mut x) => {
+                x.description = Some(description);
+                Self::Wiki(x)
+            }
+            Self::InterWiki(mut x) => {
+                x.link_mut().description = Some(description);
+                Self::InterWiki(x)
+            }
+            Self::Diary(mut x) => {
+                x.description = Some(description);
+                Self::Diary(x)
+            }
+            Self::Raw(mut x) => {
+                x.description = Some(description);
+                Self::Raw(x)
+            }
+            Self::ExternalFile(mut x) => {
+                x.description = Some(description);
+                Self::ExternalFile(x)
+            }
+            Self::Transclusion(mut x) => {
+                x.description = Some(description);
+                Self::Transclusion(x)
+            }
+            Self::Reference(mut x) => {
+                x.description = description;
+                Self::Reference(x)
+            }
+        }
+    }
+
+    /// Classifies the link by its target, where a target with a scheme is a
+    /// url and a wiki link to a local anchor (`[[#anchor]]`) is an anchor
+    pub fn kind(&self) -> LinkKind {
+        match self {
+            Self::Wiki(x) if x.is_local_anchor() => LinkKind::Anchor,
+            Self::Wiki(x) if x.is_url() => LinkKind::Url,
+            Self::Wiki(_) => LinkKind::Wiki,
+            Self::InterWiki(_) => LinkKind::InterWiki,
+            Self::Diary(_) => LinkKind::Diary,
+            Self::Raw(_) | Self::ExternalFile(_) | Self::Transclusion(_) => {
+                LinkKind::Url
+            }
+            Self::Reference(x) if x.target.starts_with('#') => LinkKind::Anchor,
+            Self::Reference(x) if has_scheme(&x.target) => LinkKind::Url,
+            Self::Reference(_) => LinkKind::Wiki,
+        }
+    }
+
+    /// Expands the wiki referenced by an interwiki link into its root using
+    /// the registry, returning none for other links or unregistered wikis
+    pub fn resolve_interwiki(
+        &self,
+        registry: &InterWikiRegistry,
+    ) -> Option<ResolvedLink> {
+        match self {
+            Self::InterWiki(x) => x.resolve(registry),
+            _ => None,
+        }
+    }
+
+    /// Parses the date of a diary link, returning none for other links or
+    /// diary links whose date is malformed
+    pub fn diary_date(&self) -> Option<NaiveDate> {
+        match self {
+            Self::Diary(x) => x.date,
+            _ => None,
+        }
+    }
+
     pub fn description(&self) -> Option<&Description<'a>> {
         match self {
             Self::Wiki(x) => x.description.as_ref(),
             Self::InterWiki(x) => x.link().description.as_ref(),
             Self::Diary(x) => x.description.as_ref(),
-            Self::Raw(_) => None,
+            Self::Raw(x) => x.description.as_ref(),
             Self::ExternalFile(x) => x.description.as_ref(),
             Self::Transclusion(x) => x.description.as_ref(),
+            Self::Reference(x) => Some(&x.description),
+        }
+    }
+
+    pub fn description_mut(&mut self) -> Option<&mut Description<'a>> {
+        match self {
+            Self::Wiki(x) => x.description.as_mut(),
+            Self::InterWiki(x) => x.link_mut().description.as_mut(),
+            Self::Diary(x) => x.description.as_mut(),
+            Self::Raw(x) => x.description.as_mut(),
+            Self::ExternalFile(x) => x.description.as_mut(),
+            Self::Transclusion(x) => x.description.as_mut(),
+            Self::Reference(x) => Some(&mut x.description),
         }
     }
 
@@ -230,6 +390,258 @@ impl<'a> Link<'a> {
             Self::Raw(_) => None,
             Self::ExternalFile(_) => None,
             Self::Transclusion(_) => None,
+            Self::Reference(_) => None,
+        }
+    }
+
+    /// Returns the fragment of the link without its leading `#`, which is
+    /// the anchor of wiki, interwiki, and diary links (nested anchors joined
+    /// by `#`) and the percent-decoded fragment of the url of other links.
+    /// External file links have no fragment
+    pub fn fragment(&self) -> Option<Cow<'_, str>> {
+        let anchor = match self {
+            Self::Raw(x) => {
+                return x.uri.fragment().map(|x| percent_decode(x.as_str()))
+            }
+            Self::Transclusion(x) => {
+                return x.uri.fragment().map(|x| percent_decode(x.as_str()))
+            }
+            Self::Reference(x) => {
+                return x.target.find('#').map(|i| x.target[i + 1..].into())
+            }
+            _ => self.anchor()?,
+        };
+
+        match anchor.elements.as_slice() {
+            [] => None,
+            [x] => Some(Cow::Borrowed(x.as_ref())),
+            xs => Some(Cow::Owned(xs.join("#"))),
+        }
+    }
+
+    /// Sets the fragment of the link, where `#` within the fragment nests
+    /// anchors of wiki, interwiki, and diary links and characters not
+    /// allowed within a url are percent-encoded for other links. Setting
+    /// none removes the fragment. External file links have no fragment, so
+    /// they are left unchanged
+    pub fn set_fragment(&mut self, fragment: Option<String>) {
+        let to_anchor = |x: String| {
+            Anchor::new(
+                x.split('#').map(|x| Cow::from(x.to_string())).collect(),
+            )
+        };
+
+        match self {
+            Self::Wiki(x) => x.anchor = fragment.map(to_anchor),
+            Self::InterWiki(x) => x.link_mut().anchor = fragment.map(to_anchor),
+            Self::Diary(x) => x.anchor = fragment.map(to_anchor),
+            Self::Raw(x) => set_uri_fragment(&mut x.uri, fragment),
+            Self::ExternalFile(_) => {}
+            Self::Transclusion(x) => set_uri_fragment(&mut x.uri, fragment),
+            Self::Reference(x) => {
+                let target = match x.target.find('#') {
+                    Some(i) => &x.target[..i],
+                    None => &x.target,
+                };
+                x.target = Cow::from(match fragment {
+                    Some(fragment) => format!("{}#{}", target, fragment),
+                    None => target.to_string(),
+                });
+            }
+        }
+    }
+
+    /// Returns the target of the link with percent-encoded characters
+    /// decoded and whitespace normalized, suitable for resolving the target
+    pub fn decoded_target(&self) -> Cow<'_, str> {
+        match self {
+            Self::Wiki(x) => x.decoded_target(),
+            Self::InterWiki(x) => x.link().decoded_target(),
+            Self::Diary(x) => Cow::from(x.date_str.as_ref()),
+            Self::Raw(x) => {
+                Cow::from(decode_target(&x.uri.to_string()).into_owned())
+            }
+            Self::ExternalFile(x) => {
+                Cow::from(decode_target(&x.path.to_string_lossy()).into_owned())
+            }
+            Self::Transclusion(x) => {
+                Cow::from(decode_target(&x.uri.to_string()).into_owned())
+            }
+            Self::Reference(x) => decode_target(&x.target),
+        }
+    }
+
+    /// Whether or not both links point at the same page, where wiki links
+    /// within the page at *current* are resolved to paths relative to
+    /// *wiki_root* and other links are compared by their decoded target
+    pub fn same_target(
+        &self,
+        other: &Link,
+        wiki_root: &Path,
+        current: &Path,
+    ) -> bool {
+        match (
+            self.resolve_wiki_path(wiki_root, current),
+            other.resolve_wiki_path(wiki_root, current),
+        ) {
+            (Some(x), Some(y)) => x == y,
+            (None, None) => {
+                self.kind() == other.kind()
+                    && self.decoded_target() == other.decoded_target()
+            }
+            _ => false,
+        }
+    }
+
+    /// Resolves the path of the page targeted by a wiki link within the page
+    /// at `current` without touching the filesystem, returning none for
+    /// other links. Targets starting with a slash are relative to the root
+    /// of the wiki, targets ending with a slash refer to the index of a
+    /// directory, and anchors within the page resolve to the page itself
+    pub fn resolve_wiki_path(
+        &self,
+        root: &Path,
+        current: &Path,
+    ) -> Option<PathBuf> {
+        let target = match self {
+            Self::Wiki(x) if x.is_local_anchor() => {
+                return Some(normalize_path(current));
+            }
+            Self::Wiki(_) if self.kind() == LinkKind::Wiki => {
+                self.decoded_target()
+            }
+            _ => return None,
+        };
+
+        // Targets starting with a slash are relative to the root of the
+        // wiki, otherwise they are relative to the directory of the page
+        let mut path = match target.strip_prefix('/') {
+            Some(target) => root.join(target),
+            None => current.parent().unwrap_or(root).join(&*target),
+        };
+
+        if target.ends_with('/') {
+            path.push("index");
+        }
+
+        if path.extension().is_none() {
+            if let Some(ext) = current.extension() {
+                path.set_extension(ext);
+            }
+        }
+
+        Some(normalize_path(&path))
+    }
+}
+
+/// Replaces the fragment of the uri, percent-encoding any characters that
+/// are not allowed within a fragment
+fn set_uri_fragment(uri: &mut URI<'_>, fragment: Option<String>) {
+    let fragment = fragment.map(|x| {
+        let mut encoded = String::with_capacity(x.len());
+        for c in x.chars() {
+            if c.is_ascii_alphanumeric() || "-._~!$&'()*+,;=:@/?".contains(c) {
+                encoded.push(c);
+            } else {
+                let mut buf = [0; 4];
+                for b in c.encode_utf8(&mut buf).bytes() {
+                    encoded.push_str(&format!("%{:02X}", b));
+                }
+            }
+        }
+
+        Fragment::try_from(encoded.as_str())
+            .expect("Encoded fragment is invalid")
+            .into_owned()
+    });
+
+    uri.map_fragment(|_| fragment);
+}
+
+/// Removes `.` and `..` components from a path without touching the
+/// filesystem
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            x => normalized.push(x.as_os_str()),
+        }
+    }
+    normalized
+}
+
+/// Whether or not the target starts with a scheme such as `https:` or
+/// `mailto:`, ignoring single letters that would be a drive like `C:`
+pub(crate) fn has_scheme(target: &str) -> bool {
+    match target.find(':') {
+        Some(pos) if pos > 1 => {
+            let scheme = &target[..pos];
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme.chars().all(|c| {
+                    c.is_ascii_alphanumeric()
+                        || c == '+'
+                        || c == '-'
+                        || c == '.'
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Percent-decodes the target and collapses runs of whitespace into a
+/// single space, borrowing the original target when nothing changes
+pub(crate) fn decode_target(target: &str) -> Cow<'_, str> {
+    let trimmed = target.trim();
+    let needs_decode = trimmed.contains('%');
+    let needs_normalize = trimmed.len() != target.len()
+        || trimmed.contains("  ")
+        || trimmed.contains(|c: char| c.is_whitespace() && c != ' ');
+
+    if !needs_decode && !needs_normalize {
+        return Cow::Borrowed(target);
+    }
+
+    let decoded = percent_decode(trimmed);
+    Cow::Owned(decoded.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Decodes percent-encoded characters of the text, borrowing the original
+/// text when it has none
+fn percent_decode(text: &str) -> Cow<'_, str> {
+    if !text.contains('%') {
+        return Cow::Borrowed(text);
+    }
+
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    Cow::Owned(String::from_utf8_lossy(&out).into_owned())
+}
+
+impl<'a> RegionsMut for Link<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        if let Some(Description::Inline(x)) = self.description_mut() {
+            x.for_each_region_mut(f);
         }
     }
 }
@@ -244,7 +656,111 @@ impl<'a> StrictEq for Link<'a> {
             (Self::Raw(x), Self::Raw(y)) => x.strict_eq(y),
             (Self::ExternalFile(x), Self::ExternalFile(y)) => x.strict_eq(y),
             (Self::Transclusion(x), Self::Transclusion(y)) => x.strict_eq(y),
+            (Self::Reference(x), Self::Reference(y)) => x.strict_eq(y),
             _ => false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn constructors_should_produce_each_kind_of_link() {
+        let wiki = Link::wiki("Some Page");
+        assert_eq!(wiki.kind(), LinkKind::Wiki);
+        assert_eq!(wiki.to_string(), "Some Page");
+
+        let url = Link::url(URI::try_from("https://example.com/page").unwrap());
+        assert_eq!(url.kind(), LinkKind::Url);
+        assert_eq!(url.to_string(), "https://example.com/page");
+
+        let diary = Link::diary(NaiveDate::from_ymd_opt(2021, 3, 4).unwrap());
+        assert_eq!(diary.kind(), LinkKind::Diary);
+        assert_eq!(diary.to_string(), "2021-03-04");
+
+        let anchor = Link::local_anchor("top");
+        assert_eq!(anchor.kind(), LinkKind::Anchor);
+        assert_eq!(anchor.to_string(), "#top");
+    }
+
+    #[test]
+    fn with_description_should_set_description_of_link() {
+        let wiki = Link::wiki("Some Page").with_description("a page");
+        assert_eq!(wiki.description(), Some(&Description::from("a page")));
+        assert_eq!(wiki.to_string(), "a page");
+
+        let url = Link::url(URI::try_from("https://example.com/page").unwrap())
+            .with_description("example");
+        assert!(matches!(url, Link::Raw(_)), "Unexpected link: {:?}", url);
+        assert_eq!(url.kind(), LinkKind::Url);
+        assert_eq!(url.decoded_target(), "https://example.com/page");
+        assert_eq!(url.description(), Some(&Description::from("example")));
+        assert_eq!(url.to_string(), "example");
+    }
+
+    fn parse_link(input: &str) -> Link<'_> {
+        let link: crate::Located<Link> =
+            crate::Language::from_vimwiki_str(input).parse().unwrap();
+        link.into_inner()
+    }
+
+    #[test]
+    fn fragment_should_return_anchor_of_wiki_link() {
+        let link = parse_link("[[file#sec]]");
+        assert_eq!(link.fragment().as_deref(), Some("sec"));
+
+        let link = parse_link("[[file#sec#sub]]");
+        assert_eq!(link.fragment().as_deref(), Some("sec#sub"));
+
+        let link = parse_link("[[file]]");
+        assert_eq!(link.fragment(), None);
+    }
+
+    #[test]
+    fn set_fragment_should_replace_anchor_of_wiki_link() {
+        let mut link = parse_link("[[file#sec]]");
+        link.set_fragment(Some(String::from("other#sub")));
+        assert_eq!(link.fragment().as_deref(), Some("other#sub"));
+        assert_eq!(
+            link.anchor(),
+            Some(&Anchor::new(vec![Cow::from("other"), Cow::from("sub")]))
+        );
+    }
+
+    #[test]
+    fn set_fragment_should_clear_anchor_if_none() {
+        let mut link = parse_link("[[file#sec]]");
+        link.set_fragment(None);
+        assert_eq!(link.fragment(), None);
+        assert_eq!(link.anchor(), None);
+        assert_eq!(link.decoded_target(), "file");
+    }
+
+    #[test]
+    fn set_fragment_should_replace_fragment_of_url() {
+        let mut link =
+            Link::url(URI::try_from("https://example.com/page#top").unwrap());
+        assert_eq!(link.fragment().as_deref(), Some("top"));
+
+        link.set_fragment(Some(String::from("some section")));
+        assert_eq!(link.fragment().as_deref(), Some("some section"));
+        assert_eq!(link.to_string(), "https://example.com/page#some%20section");
+
+        link.set_fragment(None);
+        assert_eq!(link.fragment(), None);
+        assert_eq!(link.to_string(), "https://example.com/page");
+    }
+
+    #[test]
+    fn fragment_should_round_trip_fragment_set_on_url() {
+        let mut link =
+            Link::url(URI::try_from("https://example.com/page").unwrap());
+        for fragment in &["top", "100% más", "a#b c"] {
+            link.set_fragment(Some(fragment.to_string()));
+            assert_eq!(link.fragment().as_deref(), Some(*fragment));
+        }
+    }
+}
@@ -1,44 +1,52 @@
-use super::uri_to_borrowed;
+use super::{uri_to_borrowed, Description};
 use crate::StrictEq;
-use derive_more::{Constructor, Display};
+use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt};
 use uriparse::URI;
 
-/// Represents a raw link in the form of http[s]://example.com
+/// Represents a raw link in the form of http[s]://example.com, which only
+/// has a description when given one programmatically as vimwiki has no
+/// syntax for describing a bare url
 #[derive(
-    Constructor,
-    Clone,
-    Debug,
-    Display,
-    Eq,
-    PartialEq,
-    Hash,
-    Serialize,
-    Deserialize,
+    Constructor, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
 )]
-#[display(fmt = "{}", uri)]
 pub struct RawLink<'a> {
     pub uri: URI<'a>,
+    pub description: Option<Description<'a>>,
 }
 
 impl RawLink<'_> {
     pub fn to_borrowed(&self) -> RawLink {
         RawLink {
             uri: uri_to_borrowed(&self.uri),
+            description: self
+                .description
+                .as_ref()
+                .map(Description::to_borrowed),
         }
     }
 
     pub fn into_owned(self) -> RawLink<'static> {
         RawLink {
             uri: self.uri.into_owned(),
+            description: self.description.map(Description::into_owned),
+        }
+    }
+}
+
+impl<'a> fmt::Display for RawLink<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.description.as_ref() {
+            Some(desc) => write!(f, "{}", desc),
+            None => write!(f, "{}", self.uri),
         }
     }
 }
 
 impl<'a> From<URI<'a>> for RawLink<'a> {
     fn from(uri: URI<'a>) -> Self {
-        Self::new(uri)
+        Self::new(uri, None)
     }
 }
 
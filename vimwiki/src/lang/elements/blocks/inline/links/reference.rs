@@ -0,0 +1,66 @@
+use super::Description;
+use crate::StrictEq;
+use derive_more::{Constructor, Display};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Represents a reference-style link in the form of `[description][label]`
+/// or `[description][]`, whose target comes from a definition elsewhere in
+/// the page in the form of `[label]: target`
+#[derive(
+    Constructor,
+    Clone,
+    Debug,
+    Display,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[display(fmt = "{}", description)]
+pub struct ReferenceLink<'a> {
+    pub description: Description<'a>,
+
+    /// Label as written within the link, which is empty when the
+    /// description is used as the label such as `[description][]`
+    pub label: Cow<'a, str>,
+
+    /// Target taken from the definition of the label
+    pub target: Cow<'a, str>,
+}
+
+impl ReferenceLink<'_> {
+    pub fn to_borrowed(&self) -> ReferenceLink<'_> {
+        use self::Cow::*;
+
+        ReferenceLink {
+            description: self.description.to_borrowed(),
+            label: Cow::Borrowed(match &self.label {
+                Borrowed(x) => *x,
+                Owned(x) => x.as_str(),
+            }),
+            target: Cow::Borrowed(match &self.target {
+                Borrowed(x) => *x,
+                Owned(x) => x.as_str(),
+            }),
+        }
+    }
+
+    pub fn into_owned(self) -> ReferenceLink<'static> {
+        ReferenceLink {
+            description: self.description.into_owned(),
+            label: Cow::from(self.label.into_owned()),
+            target: Cow::from(self.target.into_owned()),
+        }
+    }
+}
+
+impl<'a> StrictEq for ReferenceLink<'a> {
+    /// Performs strict_eq check on description, otherwise same as PartialEq
+    fn strict_eq(&self, other: &Self) -> bool {
+        self.description.strict_eq(&other.description)
+            && self.label == other.label
+            && self.target == other.target
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Anchor, Description};
+use super::{decode_target, has_scheme, Anchor, Description};
 use crate::StrictEq;
 use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
@@ -59,11 +59,26 @@ impl WikiLink<'_> {
 }
 
 impl<'a> WikiLink<'a> {
+    /// Returns the path of the link with percent-encoded characters decoded
+    /// and whitespace normalized, suitable for resolving the target
+    pub fn decoded_target(&self) -> Cow<'_, str> {
+        match self.path.to_string_lossy() {
+            Cow::Borrowed(x) => decode_target(x),
+            Cow::Owned(x) => Cow::Owned(decode_target(&x).into_owned()),
+        }
+    }
+
     /// Whether or not the link is representing an anchor to the current page
     pub fn is_local_anchor(&self) -> bool {
         self.path.as_os_str().is_empty() && self.anchor.is_some()
     }
 
+    /// Whether or not the link's path starts with a scheme such as `https:`,
+    /// meaning that it points to a URL rather than another wiki page
+    pub fn is_url(&self) -> bool {
+        has_scheme(&self.path.to_string_lossy())
+    }
+
     /// Checks if the link's path is to a directory without actually evaluating
     /// in the filesystem. Only checks if the path appears as that of a
     /// directory
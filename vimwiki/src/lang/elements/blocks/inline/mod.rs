@@ -1,5 +1,5 @@
 use crate::{
-    lang::elements::{IntoChildren, Located},
+    lang::elements::{IntoChildren, Located, Region, RegionsMut},
     StrictEq,
 };
 use derive_more::{
@@ -13,6 +13,10 @@ mod code;
 pub use code::*;
 mod comments;
 pub use comments::*;
+mod footnotes;
+pub use footnotes::*;
+mod hashtags;
+pub use hashtags::*;
 mod links;
 pub use links::*;
 mod math;
@@ -34,6 +38,8 @@ pub enum InlineElement<'a> {
     Tags(Tags<'a>),
     Code(CodeInline<'a>),
     Math(MathInline<'a>),
+    FootnoteRef(FootnoteRef<'a>),
+    Hashtag(Hashtag<'a>),
 
     /// Comments exist as inline elements, but do not show up when displaying
     /// an inline element enum
@@ -41,7 +47,41 @@ pub enum InlineElement<'a> {
     Comment(Comment<'a>),
 }
 
+/// Represents the kind of an inline element without any of its data, for
+/// when only the variant needs to be checked
+#[derive(
+    Copy, Clone, Debug, Display, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
+pub enum InlineKind {
+    Text,
+    DecoratedText,
+    Keyword,
+    Link,
+    Tags,
+    Code,
+    Math,
+    FootnoteRef,
+    Hashtag,
+    Comment,
+}
+
 impl InlineElement<'_> {
+    /// Returns the kind of inline element
+    pub fn kind(&self) -> InlineKind {
+        match self {
+            Self::Text(_) => InlineKind::Text,
+            Self::DecoratedText(_) => InlineKind::DecoratedText,
+            Self::Keyword(_) => InlineKind::Keyword,
+            Self::Link(_) => InlineKind::Link,
+            Self::Tags(_) => InlineKind::Tags,
+            Self::Code(_) => InlineKind::Code,
+            Self::Math(_) => InlineKind::Math,
+            Self::FootnoteRef(_) => InlineKind::FootnoteRef,
+            Self::Hashtag(_) => InlineKind::Hashtag,
+            Self::Comment(_) => InlineKind::Comment,
+        }
+    }
+
     pub fn to_borrowed(&self) -> InlineElement {
         match self {
             Self::Text(x) => InlineElement::from(x.as_borrowed()),
@@ -51,6 +91,8 @@ impl InlineElement<'_> {
             Self::Tags(x) => InlineElement::from(x.to_borrowed()),
             Self::Code(x) => InlineElement::from(x.as_borrowed()),
             Self::Math(x) => InlineElement::from(x.as_borrowed()),
+            Self::FootnoteRef(x) => InlineElement::from(x.as_borrowed()),
+            Self::Hashtag(x) => InlineElement::from(x.as_borrowed()),
             Self::Comment(x) => InlineElement::from(x.to_borrowed()),
         }
     }
@@ -64,6 +106,8 @@ impl InlineElement<'_> {
             Self::Tags(x) => InlineElement::from(x.into_owned()),
             Self::Code(x) => InlineElement::from(x.into_owned()),
             Self::Math(x) => InlineElement::from(x.into_owned()),
+            Self::FootnoteRef(x) => InlineElement::from(x.into_owned()),
+            Self::Hashtag(x) => InlineElement::from(x.into_owned()),
             Self::Comment(x) => InlineElement::from(x.into_owned()),
         }
     }
@@ -80,6 +124,16 @@ impl<'a> IntoChildren for InlineElement<'a> {
     }
 }
 
+impl<'a> RegionsMut for InlineElement<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        match self {
+            Self::DecoratedText(x) => x.for_each_region_mut(f),
+            Self::Link(x) => x.for_each_region_mut(f),
+            _ => {}
+        }
+    }
+}
+
 impl<'a> StrictEq for InlineElement<'a> {
     /// Performs strict_eq check on matching inner variants
     fn strict_eq(&self, other: &Self) -> bool {
@@ -91,6 +145,8 @@ impl<'a> StrictEq for InlineElement<'a> {
             (Self::Tags(x), Self::Tags(y)) => x.strict_eq(y),
             (Self::Code(x), Self::Code(y)) => x.strict_eq(y),
             (Self::Math(x), Self::Math(y)) => x.strict_eq(y),
+            (Self::FootnoteRef(x), Self::FootnoteRef(y)) => x.strict_eq(y),
+            (Self::Hashtag(x), Self::Hashtag(y)) => x.strict_eq(y),
             (Self::Comment(x), Self::Comment(y)) => x.strict_eq(y),
             _ => false,
         }
@@ -119,7 +175,7 @@ pub struct InlineElementContainer<'a> {
     pub elements: Vec<Located<InlineElement<'a>>>,
 }
 
-impl InlineElementContainer<'_> {
+impl<'a> InlineElementContainer<'a> {
     pub fn to_borrowed(&self) -> InlineElementContainer {
         let elements = self
             .elements
@@ -139,6 +195,67 @@ impl InlineElementContainer<'_> {
 
         InlineElementContainer { elements }
     }
+
+    /// Returns the number of elements within the container
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true if the container has no elements
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the element at the given index, or none if out of bounds
+    pub fn get(&self, index: usize) -> Option<&Located<InlineElement<'a>>> {
+        self.elements.get(index)
+    }
+
+    /// Consumes the container, producing its children as owned elements
+    /// that keep their original regions
+    pub fn into_children_owned(self) -> Vec<Located<InlineElement<'static>>> {
+        self.elements
+            .into_iter()
+            .map(|x| x.map(InlineElement::into_owned))
+            .collect()
+    }
+
+    /// Merges consecutive text elements into a single text element whose
+    /// region spans all of them, dropping any empty text elements. All other
+    /// elements are left intact
+    pub fn normalize(self) -> InlineElementContainer<'static> {
+        let mut elements: Vec<Located<InlineElement<'static>>> = Vec::new();
+
+        for element in self.elements {
+            let region = element.region();
+            let element = element.into_inner().into_owned();
+            if matches!(&element, InlineElement::Text(x) if x.0.is_empty()) {
+                continue;
+            }
+
+            let previous = elements.pop().map(|x| (x.region(), x.into_inner()));
+            match (previous, element) {
+                (
+                    Some((prev_region, InlineElement::Text(mut prev))),
+                    InlineElement::Text(text),
+                ) => {
+                    prev.0.to_mut().push_str(&text.0);
+                    elements.push(Located::new(
+                        InlineElement::Text(prev),
+                        prev_region.merge(region),
+                    ));
+                }
+                (previous, x) => {
+                    if let Some((prev_region, prev)) = previous {
+                        elements.push(Located::new(prev, prev_region));
+                    }
+                    elements.push(Located::new(x, region));
+                }
+            }
+        }
+
+        InlineElementContainer { elements }
+    }
 }
 
 impl<'a> IntoChildren for InlineElementContainer<'a> {
@@ -176,6 +293,14 @@ impl<'a> From<Located<&'a str>> for InlineElementContainer<'a> {
     }
 }
 
+impl<'a> RegionsMut for InlineElementContainer<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        for element in self.elements.iter_mut() {
+            element.for_each_region_mut(f);
+        }
+    }
+}
+
 impl<'a> StrictEq for InlineElementContainer<'a> {
     /// Performs strict_eq check on inner elements
     fn strict_eq(&self, other: &Self) -> bool {
@@ -198,5 +323,164 @@ container_mapping!(Located<MathInline<'a>>);
 container_mapping!(Located<Text<'a>>);
 container_mapping!(Located<DecoratedText<'a>>);
 container_mapping!(Located<Keyword>);
+container_mapping!(Located<FootnoteRef<'a>>);
+container_mapping!(Located<Hashtag<'a>>);
 container_mapping!(Located<Link<'a>>);
 container_mapping!(Located<Tags<'a>>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::elements::Position;
+
+    #[test]
+    fn kind_should_match_variant_of_inline_element() {
+        let elements = vec![
+            (InlineElement::from(Text::from("text")), InlineKind::Text),
+            (
+                InlineElement::from(DecoratedText::Bold(vec![])),
+                InlineKind::DecoratedText,
+            ),
+            (InlineElement::from(Keyword::Todo), InlineKind::Keyword),
+            (
+                InlineElement::from(Link::from(WikiLink::from("page"))),
+                InlineKind::Link,
+            ),
+            (InlineElement::from(Tags::from("tag")), InlineKind::Tags),
+            (
+                InlineElement::from(CodeInline::from("code")),
+                InlineKind::Code,
+            ),
+            (
+                InlineElement::from(MathInline::from("math")),
+                InlineKind::Math,
+            ),
+            (
+                InlineElement::from(FootnoteRef::from("1")),
+                InlineKind::FootnoteRef,
+            ),
+            (
+                InlineElement::from(Hashtag::from("tag")),
+                InlineKind::Hashtag,
+            ),
+            (
+                InlineElement::from(Comment::from(LineComment::from("note"))),
+                InlineKind::Comment,
+            ),
+        ];
+
+        for (element, kind) in elements {
+            assert_eq!(element.kind(), kind, "Wrong kind for {:?}", element);
+        }
+    }
+
+    #[test]
+    fn normalize_should_merge_adjacent_text_and_drop_empty_text() {
+        let container = InlineElementContainer::new(vec![
+            Located::new(
+                InlineElement::from(Text::from("one ")),
+                Region::new(0, 4)
+                    .with_positions(Position::new(1, 1), Position::new(1, 5)),
+            ),
+            Located::new(
+                InlineElement::from(Text::from("")),
+                Region::point(4, Position::new(1, 5)),
+            ),
+            Located::new(
+                InlineElement::from(Text::from("two ")),
+                Region::new(4, 4)
+                    .with_positions(Position::new(1, 5), Position::new(1, 9)),
+            ),
+            Located::new(
+                InlineElement::from(Text::from("three")),
+                Region::new(8, 5)
+                    .with_positions(Position::new(1, 9), Position::new(1, 14)),
+            ),
+        ]);
+
+        let container = container.normalize();
+        assert_eq!(container.elements.len(), 1);
+        assert_eq!(
+            container.elements[0].as_inner(),
+            &InlineElement::from(Text::from("one two three"))
+        );
+
+        let region = container.elements[0].region();
+        assert_eq!(region, Region::new(0, 13));
+        assert_eq!(region.start_position(), Position::new(1, 1));
+        assert_eq!(region.end_position(), Position::new(1, 14));
+    }
+
+    #[test]
+    fn normalize_should_keep_non_text_elements_between_text() {
+        let container = InlineElementContainer::new(vec![
+            Located::from(InlineElement::from(Text::from("a"))),
+            Located::from(InlineElement::from(Keyword::Todo)),
+            Located::from(InlineElement::from(Text::from("b"))),
+        ]);
+
+        let container = container.normalize();
+        assert_eq!(
+            container
+                .elements
+                .into_iter()
+                .map(Located::into_inner)
+                .collect::<Vec<InlineElement>>(),
+            vec![
+                InlineElement::from(Text::from("a")),
+                InlineElement::from(Keyword::Todo),
+                InlineElement::from(Text::from("b")),
+            ]
+        );
+    }
+
+    #[test]
+    fn into_children_owned_should_keep_content_and_regions_beyond_source() {
+        let source = String::from("some text");
+        let children = {
+            let container = InlineElementContainer::new(vec![
+                Located::new(
+                    InlineElement::from(Text::from(&source[..4])),
+                    Region::new(0, 4),
+                ),
+                Located::new(
+                    InlineElement::from(Text::from(&source[5..])),
+                    Region::new(5, 4),
+                ),
+            ]);
+            container.into_children_owned()
+        };
+        drop(source);
+
+        assert!(children.strict_eq(&vec![
+            Located::new(
+                InlineElement::from(Text::from("some")),
+                Region::new(0, 4),
+            ),
+            Located::new(
+                InlineElement::from(Text::from("text")),
+                Region::new(5, 4),
+            ),
+        ]));
+    }
+
+    #[test]
+    fn len_is_empty_and_get_should_reflect_elements() {
+        let container = InlineElementContainer::new(vec![]);
+        assert_eq!(container.len(), 0);
+        assert!(container.is_empty());
+        assert_eq!(container.get(0), None);
+
+        let container = InlineElementContainer::new(vec![
+            Located::from(InlineElement::from(Text::from("some"))),
+            Located::from(InlineElement::from(Keyword::Todo)),
+        ]);
+        assert_eq!(container.len(), 2);
+        assert!(!container.is_empty());
+        assert_eq!(
+            container.get(1).map(Located::as_inner),
+            Some(&InlineElement::from(Keyword::Todo))
+        );
+        assert_eq!(container.get(2), None);
+    }
+}
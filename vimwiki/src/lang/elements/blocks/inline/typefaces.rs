@@ -1,13 +1,14 @@
 use crate::{
     lang::elements::{
-        AsChildrenMutSlice, AsChildrenSlice, InlineElement, IntoChildren, Link,
-        Located,
+        AsChildrenMutSlice, AsChildrenSlice, FootnoteRef, InlineElement,
+        IntoChildren, Link, Located, Region, RegionsMut,
     },
     StrictEq,
 };
 use derive_more::{AsMut, AsRef, Constructor, Display, From, Into};
 use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, ops::Range};
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Represents plain text with no decorations or inline elements
 #[derive(
@@ -43,6 +44,104 @@ impl Text<'_> {
 
         Text(inner)
     }
+
+    /// Produces a copy of the text with common named and numeric HTML
+    /// entities (e.g. `&amp;` and `&#65;`) decoded into their characters,
+    /// leaving any unrecognized entities as they are
+    pub fn decode_entities(&self) -> Text<'static> {
+        let mut decoded = String::with_capacity(self.0.len());
+        let mut rest: &str = &self.0;
+
+        while let Some(start) = rest.find('&') {
+            decoded.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let entity = rest
+                .find(';')
+                .and_then(|end| decode_entity(&rest[1..end]).map(|c| (end, c)));
+            match entity {
+                Some((end, c)) => {
+                    decoded.push(c);
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    decoded.push('&');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        decoded.push_str(rest);
+
+        Text::from(decoded)
+    }
+
+    /// Whether or not the text is empty or only contains whitespace
+    pub fn is_whitespace(&self) -> bool {
+        self.0.chars().all(char::is_whitespace)
+    }
+
+    /// Produces a copy of the text where each run of whitespace is replaced
+    /// by a single space, matching how HTML displays the text
+    pub fn collapse_whitespace(&self) -> Text<'static> {
+        let mut collapsed = String::with_capacity(self.0.len());
+        let mut in_whitespace = false;
+
+        for c in self.0.chars() {
+            if c.is_whitespace() {
+                if !in_whitespace {
+                    collapsed.push(' ');
+                }
+                in_whitespace = true;
+            } else {
+                collapsed.push(c);
+                in_whitespace = false;
+            }
+        }
+
+        Text::from(collapsed)
+    }
+
+    /// Returns the byte ranges of each word within the text using Unicode
+    /// word segmentation, skipping whitespace and punctuation between words
+    pub fn word_boundaries(&self) -> Vec<Range<usize>> {
+        self.0
+            .unicode_word_indices()
+            .map(|(start, word)| start..start + word.len())
+            .collect()
+    }
+}
+
+/// Decodes the name of an HTML entity (what is between `&` and `;`) into
+/// its character, returning `None` if the entity is not recognized
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(|c| c == 'x' || c == 'X') {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse::<u32>().ok()?,
+        };
+        return std::char::from_u32(code);
+    }
+
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "deg" => '°',
+        "hellip" => '…',
+        "ndash" => '–',
+        "mdash" => '—',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        _ => return None,
+    })
 }
 
 impl From<String> for Text<'static> {
@@ -74,6 +173,7 @@ pub enum DecoratedTextContent<'a> {
     DecoratedText(DecoratedText<'a>),
     Keyword(Keyword),
     Link(Link<'a>),
+    FootnoteRef(FootnoteRef<'a>),
 }
 
 impl DecoratedTextContent<'_> {
@@ -85,6 +185,7 @@ impl DecoratedTextContent<'_> {
             }
             Self::Keyword(x) => DecoratedTextContent::from(*x),
             Self::Link(x) => DecoratedTextContent::from(x.to_borrowed()),
+            Self::FootnoteRef(x) => DecoratedTextContent::from(x.as_borrowed()),
         }
     }
 
@@ -96,6 +197,7 @@ impl DecoratedTextContent<'_> {
             }
             Self::Keyword(x) => DecoratedTextContent::from(x),
             Self::Link(x) => DecoratedTextContent::from(x.into_owned()),
+            Self::FootnoteRef(x) => DecoratedTextContent::from(x.into_owned()),
         }
     }
 }
@@ -108,6 +210,7 @@ impl<'a> DecoratedTextContent<'a> {
             Self::DecoratedText(ref x) => x.to_borrowed().into(),
             Self::Keyword(x) => (*x).into(),
             Self::Link(ref x) => x.to_borrowed().into(),
+            Self::FootnoteRef(ref x) => x.as_borrowed().into(),
         }
     }
 
@@ -118,6 +221,35 @@ impl<'a> DecoratedTextContent<'a> {
             Self::DecoratedText(x) => x.into(),
             Self::Keyword(x) => x.into(),
             Self::Link(x) => x.into(),
+            Self::FootnoteRef(x) => x.into(),
+        }
+    }
+
+    /// Produces the visible text of the content, recursively concatenating
+    /// text, keywords, and link descriptions while dropping the markers of
+    /// any decorations
+    pub fn flatten_text(&self) -> String {
+        let mut text = String::new();
+        self.push_flattened_text(&mut text);
+        text
+    }
+
+    fn push_flattened_text(&self, text: &mut String) {
+        match self {
+            Self::Text(x) => text.push_str(&x.0),
+            Self::DecoratedText(x) => {
+                for content in x.as_contents() {
+                    content.push_flattened_text(text);
+                }
+            }
+            Self::Keyword(x) => text.push_str(&x.to_string()),
+
+            // NOTE: A link displays its description, falling back to its
+            //       target when it has no description
+            Self::Link(x) => text.push_str(&x.to_string()),
+
+            // NOTE: A footnote reference is a marker rather than text
+            Self::FootnoteRef(_) => {}
         }
     }
 }
@@ -155,6 +287,16 @@ impl<'a> IntoChildren for DecoratedTextContent<'a> {
     }
 }
 
+impl<'a> RegionsMut for DecoratedTextContent<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        match self {
+            Self::DecoratedText(x) => x.for_each_region_mut(f),
+            Self::Link(x) => x.for_each_region_mut(f),
+            _ => {}
+        }
+    }
+}
+
 impl<'a> StrictEq for DecoratedTextContent<'a> {
     /// Performs strict_eq check on matching inner variants
     fn strict_eq(&self, other: &Self) -> bool {
@@ -163,6 +305,7 @@ impl<'a> StrictEq for DecoratedTextContent<'a> {
             (Self::DecoratedText(x), Self::DecoratedText(y)) => x.strict_eq(y),
             (Self::Keyword(x), Self::Keyword(y)) => x.strict_eq(y),
             (Self::Link(x), Self::Link(y)) => x.strict_eq(y),
+            (Self::FootnoteRef(x), Self::FootnoteRef(y)) => x.strict_eq(y),
             _ => false,
         }
     }
@@ -241,6 +384,19 @@ impl<'a> DecoratedText<'a> {
             Self::Subscript(x) => x,
         }
     }
+
+    /// Compares only the kind of decoration and the visible text of both
+    /// decorated texts, ignoring regions as well as how the text is split
+    /// into children or further decorated within. This means that bold
+    /// text made of `he` and `llo` is equal to bold text made of `hello`
+    pub fn content_eq(&self, other: &Self) -> bool {
+        fn flatten_text(x: &DecoratedText) -> String {
+            x.as_contents().iter().map(|x| x.flatten_text()).collect()
+        }
+
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+            && flatten_text(self) == flatten_text(other)
+    }
 }
 
 impl<'a> AsChildrenSlice for DecoratedText<'a> {
@@ -301,6 +457,14 @@ impl<'a> fmt::Display for DecoratedText<'a> {
     }
 }
 
+impl<'a> RegionsMut for DecoratedText<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        for content in self.as_children_mut_slice() {
+            content.for_each_region_mut(f);
+        }
+    }
+}
+
 impl<'a> StrictEq for DecoratedText<'a> {
     /// Performs strict_eq check on matching inner variants
     fn strict_eq(&self, other: &Self) -> bool {
@@ -341,3 +505,92 @@ impl StrictEq for Keyword {
         self == other
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::elements::{Description, WikiLink};
+    use std::path::Path;
+
+    #[test]
+    fn decorated_text_content_flatten_text_should_drop_nested_decorations() {
+        let link = Link::from(WikiLink::new(
+            Cow::from(Path::new("page")),
+            Some(Description::from("a link")),
+            None,
+        ));
+        let content = DecoratedTextContent::from(DecoratedText::Bold(vec![
+            Located::from(DecoratedTextContent::from(Keyword::Todo)),
+            Located::from(DecoratedTextContent::from(Text::from(" bold "))),
+            Located::from(DecoratedTextContent::from(DecoratedText::Italic(
+                vec![
+                    Located::from(DecoratedTextContent::from(Text::from(
+                        "italic with ",
+                    ))),
+                    Located::from(DecoratedTextContent::from(link)),
+                ],
+            ))),
+        ]));
+
+        assert_eq!(content.flatten_text(), "TODO bold italic with a link");
+    }
+
+    #[test]
+    fn text_word_boundaries_should_return_byte_range_of_each_word() {
+        let text = Text::from("hello world");
+        let ranges = text.word_boundaries();
+
+        assert_eq!(ranges, vec![0..5, 6..11]);
+        assert_eq!(&text.0[ranges[0].clone()], "hello");
+        assert_eq!(&text.0[ranges[1].clone()], "world");
+    }
+
+    #[test]
+    fn text_is_whitespace_should_be_true_only_for_whitespace() {
+        assert!(Text::from(" \t ").is_whitespace());
+        assert!(Text::from("").is_whitespace());
+        assert!(!Text::from(" a ").is_whitespace());
+    }
+
+    #[test]
+    fn text_collapse_whitespace_should_replace_runs_with_single_space() {
+        let text = Text::from("a    b\t\t c ");
+        assert_eq!(text.collapse_whitespace(), Text::from("a b c "));
+    }
+
+    #[test]
+    fn text_decode_entities_should_decode_named_entities() {
+        let text = Text::from("fish &amp; chips &lt;3");
+        assert_eq!(text.decode_entities(), Text::from("fish & chips <3"));
+    }
+
+    #[test]
+    fn text_decode_entities_should_decode_numeric_entities() {
+        let text = Text::from("&#65;&#x42;&#X43;");
+        assert_eq!(text.decode_entities(), Text::from("ABC"));
+    }
+
+    #[test]
+    fn text_decode_entities_should_leave_unrecognized_entities_unchanged() {
+        let text = Text::from("&bogus; & &#xZZ; &amp");
+        assert_eq!(text.decode_entities(), Text::from("&bogus; & &#xZZ; &amp"));
+    }
+
+    #[test]
+    fn decorated_text_content_eq_should_ignore_fragmentation_of_text() {
+        let fragmented = DecoratedText::Bold(vec![
+            Located::from(DecoratedTextContent::from(Text::from("he"))),
+            Located::from(DecoratedTextContent::from(Text::from("llo"))),
+        ]);
+        let whole = DecoratedText::Bold(vec![Located::from(
+            DecoratedTextContent::from(Text::from("hello")),
+        )]);
+        assert!(fragmented.content_eq(&whole));
+        assert_ne!(fragmented, whole);
+
+        let italic = DecoratedText::Italic(vec![Located::from(
+            DecoratedTextContent::from(Text::from("hello")),
+        )]);
+        assert!(!whole.content_eq(&italic));
+    }
+}
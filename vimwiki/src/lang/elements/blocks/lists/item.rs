@@ -1,6 +1,7 @@
 use crate::{
     lang::elements::{
         Element, IntoChildren, ListItemContent, ListItemContents, Located,
+        Region, RegionsMut,
     },
     StrictEq,
 };
@@ -11,7 +12,15 @@ use std::borrow::Cow;
 
 /// Represents an item in a list
 #[derive(
-    Constructor, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize,
+    Constructor,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
 )]
 pub struct ListItem<'a> {
     pub item_type: ListItemType<'a>,
@@ -51,6 +60,12 @@ impl<'a> IntoChildren for ListItem<'a> {
     }
 }
 
+impl<'a> RegionsMut for ListItem<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        self.contents.for_each_region_mut(f);
+    }
+}
+
 impl<'a> StrictEq for ListItem<'a> {
     /// Performs a strict_eq check against eqivalent variants
     fn strict_eq(&self, other: &Self) -> bool {
@@ -139,6 +154,7 @@ impl<'a> ListItem<'a> {
     /// Partially Complete 1    == 25%
     /// Partially Complete 2    == 50%
     /// Partially Complete 3    == 75%
+    /// In Progress             == 50%
     /// Completed               == 100%
     #[inline]
     fn to_todo_progress(&self) -> Option<f32> {
@@ -149,6 +165,8 @@ impl<'a> ListItem<'a> {
                 0.5
             } else if self.is_todo_partially_complete_3() {
                 0.75
+            } else if self.is_todo_in_progress() {
+                0.5
             } else if self.is_todo_complete() {
                 1.0
             } else {
@@ -198,6 +216,15 @@ impl<'a> ListItem<'a> {
         )
     }
 
+    /// Indicates whether or not this element is in progress without any
+    /// particular amount of progress
+    pub fn is_todo_in_progress(&self) -> bool {
+        matches!(
+            self.attributes.todo_status,
+            Some(ListItemTodoStatus::InProgress)
+        )
+    }
+
     /// Indicates whether or not this element is complete
     pub fn is_todo_complete(&self) -> bool {
         matches!(
@@ -213,11 +240,33 @@ impl<'a> ListItem<'a> {
             Some(ListItemTodoStatus::Rejected)
         )
     }
+
+    /// Flips the checkbox of a todo item, where a complete item becomes
+    /// incomplete and any other todo status becomes complete. Items that
+    /// are not todo items are left unchanged
+    pub fn toggle_completion(&mut self) {
+        if self.is_todo() {
+            self.set_completion(!self.is_todo_complete());
+        }
+    }
+
+    /// Marks the item as complete or incomplete, turning it into a todo item
+    /// if it was not one already
+    pub fn set_completion(&mut self, complete: bool) {
+        self.attributes.todo_status = Some(if complete {
+            ListItemTodoStatus::Complete
+        } else {
+            ListItemTodoStatus::Incomplete
+        });
+    }
 }
 
 /// Represents a suffix such as . or ) used after beginning of list item
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(
+    Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
 pub enum ListItemSuffix {
+    #[default]
     None,
     Period,
     Paren,
@@ -233,12 +282,6 @@ impl ListItemSuffix {
     }
 }
 
-impl Default for ListItemSuffix {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl StrictEq for ListItemSuffix {
     /// Same as PartialEq
     fn strict_eq(&self, other: &Self) -> bool {
@@ -246,7 +289,7 @@ impl StrictEq for ListItemSuffix {
     }
 }
 
-#[derive(Clone, Debug, From, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, From, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum ListItemType<'a> {
     Ordered(OrderedListItemType),
     Unordered(UnorderedListItemType<'a>),
@@ -303,9 +346,12 @@ impl<'a> StrictEq for ListItemType<'a> {
 }
 
 /// Represents the type associated with an unordered item
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
 pub enum UnorderedListItemType<'a> {
     /// -
+    #[default]
     Hyphen,
     /// *
     Asterisk,
@@ -367,16 +413,13 @@ impl<'a> UnorderedListItemType<'a> {
     }
 }
 
-impl<'a> Default for UnorderedListItemType<'a> {
-    fn default() -> Self {
-        Self::Hyphen
-    }
-}
-
 /// Represents the type associated with an ordered item
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(
+    Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
 pub enum OrderedListItemType {
     /// 1
+    #[default]
     Number,
     /// #
     Pound,
@@ -418,12 +461,6 @@ impl OrderedListItemType {
     }
 }
 
-impl Default for OrderedListItemType {
-    fn default() -> Self {
-        Self::Number
-    }
-}
-
 /// Converts a position in a list (base 0) to an alphabetic representation
 /// where 0 == a, 25 == z, 26 == aa, and so on
 fn pos_to_alphabet(pos: usize, to_lower: bool) -> String {
@@ -475,6 +512,10 @@ pub enum ListItemTodoStatus {
     /// Flags list item as a TODO item that is partially complete (67-99%)
     PartiallyComplete3,
 
+    /// Flags list item as a TODO item that is being worked on, counting as
+    /// halfway complete when computing progress
+    InProgress,
+
     /// Flags list item as a TODO item that is complete
     Complete,
 
@@ -491,7 +532,7 @@ impl StrictEq for ListItemTodoStatus {
 
 /// Represents additional attributes associated with a list item
 #[derive(
-    Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize,
+    Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize,
 )]
 pub struct ListItemAttributes {
     /// The TODO status for a list item, if it has been associated with TODO
@@ -1006,6 +1047,10 @@ mod tests {
             Some(0.75),
             todo_list_item!(PartiallyComplete3).compute_todo_progress()
         );
+        assert_eq!(
+            Some(0.5),
+            todo_list_item!(InProgress).compute_todo_progress()
+        );
         assert_eq!(
             Some(1.0),
             todo_list_item!(Complete).compute_todo_progress()
@@ -1038,6 +1083,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compute_todo_progress_should_include_in_progress_children() {
+        // - [ ] <CALCULATING>
+        //     - [X] 100%
+        //     - [/] 50%
+        //     - [ ] 0%
+        assert_eq!(
+            todo_list_item!(
+                Incomplete,
+                Located::from(todo_list_item!(Complete)),
+                Located::from(todo_list_item!(InProgress)),
+                Located::from(todo_list_item!(Incomplete))
+            )
+            .compute_todo_progress(),
+            Some((1.0 + 0.5 + 0.0) / 3.0)
+        );
+    }
+
     #[test]
     fn compute_todo_progress_should_support_deeper_children() {
         assert_eq!(
@@ -1194,4 +1257,34 @@ mod tests {
         assert!(!todo_list_item!(Complete).is_todo_rejected());
         assert!(!ListItem::default().is_todo_rejected());
     }
+
+    #[test]
+    fn toggle_completion_should_flip_between_incomplete_and_complete() {
+        let mut item = unordered_item!(Hyphen, 0, make_content("task"));
+        item.set_completion(false);
+
+        item.toggle_completion();
+        assert!(item.is_todo_complete());
+        assert_eq!(
+            List::new(vec![Located::from(item.clone())]).to_vimwiki(),
+            "- [X] task"
+        );
+
+        item.toggle_completion();
+        assert!(item.is_todo_incomplete());
+    }
+
+    #[test]
+    fn toggle_completion_should_complete_partially_complete_items() {
+        let mut item = todo_list_item!(PartiallyComplete2);
+        item.toggle_completion();
+        assert!(item.is_todo_complete());
+    }
+
+    #[test]
+    fn toggle_completion_should_not_change_item_that_is_not_todo() {
+        let mut item = ListItem::default();
+        item.toggle_completion();
+        assert!(!item.is_todo());
+    }
 }
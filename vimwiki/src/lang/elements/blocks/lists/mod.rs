@@ -1,7 +1,8 @@
 use crate::{
     lang::elements::{
         AsChildrenMutSlice, AsChildrenSlice, Element, InlineBlockElement,
-        InlineElement, InlineElementContainer, IntoChildren, Located,
+        InlineElement, InlineElementContainer, IntoChildren, Located, Region,
+        RegionsMut,
     },
     StrictEq,
 };
@@ -15,7 +16,7 @@ pub use item::*;
 
 /// Represents a regular list comprised of individual items
 #[derive(
-    Constructor, Clone, Debug, From, Eq, PartialEq, Serialize, Deserialize,
+    Constructor, Clone, Debug, From, Eq, PartialEq, Hash, Serialize, Deserialize,
 )]
 pub struct List<'a> {
     pub items: Vec<Located<ListItem<'a>>>,
@@ -104,6 +105,14 @@ impl<'a> IntoChildren for List<'a> {
     }
 }
 
+impl<'a> RegionsMut for List<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        for item in self.items.iter_mut() {
+            item.for_each_region_mut(f);
+        }
+    }
+}
+
 impl<'a> StrictEq for List<'a> {
     /// Performs a strict_eq check against list items
     fn strict_eq(&self, other: &Self) -> bool {
@@ -113,7 +122,7 @@ impl<'a> StrictEq for List<'a> {
 
 /// Represents some content associated with a list item, either being
 /// an inline element or a new sublist
-#[derive(Clone, Debug, From, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, From, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum ListItemContent<'a> {
     InlineContent(InlineElementContainer<'a>),
     List(List<'a>),
@@ -137,6 +146,15 @@ impl ListItemContent<'_> {
     }
 }
 
+impl<'a> RegionsMut for ListItemContent<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        match self {
+            Self::InlineContent(x) => x.for_each_region_mut(f),
+            Self::List(x) => x.for_each_region_mut(f),
+        }
+    }
+}
+
 impl<'a> StrictEq for ListItemContent<'a> {
     /// Performs a strict_eq check against eqivalent variants
     fn strict_eq(&self, other: &Self) -> bool {
@@ -163,6 +181,7 @@ impl<'a> StrictEq for ListItemContent<'a> {
     IntoIterator,
     Eq,
     PartialEq,
+    Hash,
     Serialize,
     Deserialize,
 )]
@@ -279,6 +298,14 @@ impl<'a> IntoChildren for ListItemContents<'a> {
     }
 }
 
+impl<'a> RegionsMut for ListItemContents<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        for content in self.contents.iter_mut() {
+            content.for_each_region_mut(f);
+        }
+    }
+}
+
 impl<'a> StrictEq for ListItemContents<'a> {
     /// Performs a strict_eq check against inner contents
     fn strict_eq(&self, other: &Self) -> bool {
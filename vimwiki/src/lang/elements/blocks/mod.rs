@@ -1,5 +1,5 @@
 use crate::{
-    lang::elements::{Element, IntoChildren, Located},
+    lang::elements::{Element, IntoChildren, Located, Region, RegionsMut},
     StrictEq,
 };
 use derive_more::From;
@@ -11,6 +11,8 @@ mod definitions;
 pub use definitions::*;
 mod dividers;
 pub use dividers::*;
+mod footnotes;
+pub use footnotes::*;
 mod headers;
 pub use headers::*;
 mod inline;
@@ -25,21 +27,25 @@ mod placeholders;
 pub use placeholders::*;
 mod preformatted;
 pub use preformatted::*;
+mod signatures;
+pub use signatures::*;
 mod tables;
 pub use tables::*;
 
 /// Represents elements that are standalone (metaphorically a block element in CSS)
-#[derive(Clone, Debug, From, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, From, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum BlockElement<'a> {
     Blockquote(Blockquote<'a>),
     DefinitionList(DefinitionList<'a>),
     Divider(Divider),
+    FootnoteDef(FootnoteDef<'a>),
     Header(Header<'a>),
     List(List<'a>),
     Math(MathBlock<'a>),
     Paragraph(Paragraph<'a>),
     Placeholder(Placeholder<'a>),
     PreformattedText(PreformattedText<'a>),
+    Signature(Signature<'a>),
     Table(Table<'a>),
 }
 
@@ -49,12 +55,14 @@ impl BlockElement<'_> {
             Self::Blockquote(x) => BlockElement::from(x.to_borrowed()),
             Self::DefinitionList(x) => BlockElement::from(x.to_borrowed()),
             Self::Divider(x) => BlockElement::from(*x),
+            Self::FootnoteDef(x) => BlockElement::from(x.to_borrowed()),
             Self::Header(x) => BlockElement::from(x.to_borrowed()),
             Self::List(x) => BlockElement::from(x.to_borrowed()),
             Self::Math(x) => BlockElement::from(x.to_borrowed()),
             Self::Paragraph(x) => BlockElement::from(x.to_borrowed()),
             Self::Placeholder(x) => BlockElement::from(x.to_borrowed()),
             Self::PreformattedText(x) => BlockElement::from(x.to_borrowed()),
+            Self::Signature(x) => BlockElement::from(x.to_borrowed()),
             Self::Table(x) => BlockElement::from(x.to_borrowed()),
         }
     }
@@ -66,6 +74,7 @@ impl BlockElement<'_> {
                 BlockElement::DefinitionList(x.into_owned())
             }
             Self::Divider(x) => BlockElement::Divider(x),
+            Self::FootnoteDef(x) => BlockElement::FootnoteDef(x.into_owned()),
             Self::Header(x) => BlockElement::Header(x.into_owned()),
             Self::List(x) => BlockElement::List(x.into_owned()),
             Self::Math(x) => BlockElement::Math(x.into_owned()),
@@ -74,6 +83,7 @@ impl BlockElement<'_> {
             Self::PreformattedText(x) => {
                 BlockElement::PreformattedText(x.into_owned())
             }
+            Self::Signature(x) => BlockElement::Signature(x.into_owned()),
             Self::Table(x) => BlockElement::Table(x.into_owned()),
         }
     }
@@ -84,11 +94,21 @@ impl<'a> IntoChildren for BlockElement<'a> {
 
     fn into_children(self) -> Vec<Self::Child> {
         match self {
+            Self::Blockquote(x) => x
+                .into_children()
+                .into_iter()
+                .map(|x| x.map(Element::from))
+                .collect(),
             Self::DefinitionList(x) => x
                 .into_children()
                 .into_iter()
                 .map(|x| x.map(Element::from))
                 .collect(),
+            Self::FootnoteDef(x) => x
+                .into_children()
+                .into_iter()
+                .map(|x| x.map(Element::from))
+                .collect(),
             Self::Header(x) => x
                 .into_children()
                 .into_iter()
@@ -114,6 +134,21 @@ impl<'a> IntoChildren for BlockElement<'a> {
     }
 }
 
+impl<'a> RegionsMut for BlockElement<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        match self {
+            Self::Blockquote(x) => x.for_each_region_mut(f),
+            Self::DefinitionList(x) => x.for_each_region_mut(f),
+            Self::FootnoteDef(x) => x.for_each_region_mut(f),
+            Self::Header(x) => x.for_each_region_mut(f),
+            Self::List(x) => x.for_each_region_mut(f),
+            Self::Paragraph(x) => x.for_each_region_mut(f),
+            Self::Table(x) => x.for_each_region_mut(f),
+            _ => {}
+        }
+    }
+}
+
 impl<'a> StrictEq for BlockElement<'a> {
     /// Performs strict_eq check on matching inner variants
     fn strict_eq(&self, other: &Self) -> bool {
@@ -123,6 +158,7 @@ impl<'a> StrictEq for BlockElement<'a> {
                 x.strict_eq(y)
             }
             (Self::Divider(x), Self::Divider(y)) => x.strict_eq(y),
+            (Self::FootnoteDef(x), Self::FootnoteDef(y)) => x.strict_eq(y),
             (Self::Header(x), Self::Header(y)) => x.strict_eq(y),
             (Self::List(x), Self::List(y)) => x.strict_eq(y),
             (Self::Math(x), Self::Math(y)) => x.strict_eq(y),
@@ -131,6 +167,7 @@ impl<'a> StrictEq for BlockElement<'a> {
             (Self::PreformattedText(x), Self::PreformattedText(y)) => {
                 x.strict_eq(y)
             }
+            (Self::Signature(x), Self::Signature(y)) => x.strict_eq(y),
             (Self::Table(x), Self::Table(y)) => x.strict_eq(y),
             _ => false,
         }
@@ -157,3 +194,5 @@ le_mapping!(MathBlock<'a>);
 le_mapping!(Blockquote<'a>);
 le_mapping!(Divider);
 le_mapping!(Placeholder<'a>);
+le_mapping!(FootnoteDef<'a>);
+le_mapping!(Signature<'a>);
@@ -1,13 +1,20 @@
 use crate::{
     lang::elements::{
-        InlineElement, InlineElementContainer, IntoChildren, Located,
+        InlineElement, InlineElementContainer, IntoChildren, Located, Region,
+        RegionsMut,
     },
     StrictEq,
 };
 use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
 
-#[derive(Constructor, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+/// Abbreviations whose trailing period does not end a sentence
+const ABBREVIATIONS: &[&str] =
+    &["e.g.", "i.e.", "cf.", "vs.", "Mr.", "Mrs.", "Ms.", "Dr."];
+
+#[derive(
+    Constructor, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize,
+)]
 pub struct Paragraph<'a> {
     pub content: InlineElementContainer<'a>,
 }
@@ -26,6 +33,52 @@ impl Paragraph<'_> {
     }
 }
 
+impl<'a> Paragraph<'a> {
+    /// Splits the visible text of the paragraph into sentences, where a
+    /// sentence ends with `.`, `!`, or `?` followed by whitespace or the end
+    /// of the text. Periods ending common abbreviations such as `e.g.` do
+    /// not end a sentence
+    pub fn sentences(&self) -> Vec<String> {
+        let text = self.content.to_string();
+        let mut sentences = Vec::new();
+        let mut start = 0;
+
+        let mut chars = text.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let at_boundary =
+                chars.peek().is_none_or(|(_, c)| c.is_whitespace());
+            if !matches!(c, '.' | '!' | '?') || !at_boundary {
+                continue;
+            }
+
+            let end = i + c.len_utf8();
+            let sentence = text[start..end].trim();
+            let is_abbreviation = c == '.'
+                && ABBREVIATIONS.iter().any(|abbr| {
+                    sentence.ends_with(abbr)
+                        && sentence[..sentence.len() - abbr.len()]
+                            .chars()
+                            .last()
+                            .is_none_or(char::is_whitespace)
+                });
+
+            if !is_abbreviation {
+                if !sentence.is_empty() {
+                    sentences.push(sentence.to_string());
+                }
+                start = end;
+            }
+        }
+
+        let rest = text[start..].trim();
+        if !rest.is_empty() {
+            sentences.push(rest.to_string());
+        }
+
+        sentences
+    }
+}
+
 impl<'a> IntoChildren for Paragraph<'a> {
     type Child = Located<InlineElement<'a>>;
 
@@ -50,9 +103,78 @@ impl<'a> From<Located<InlineElement<'a>>> for Paragraph<'a> {
     }
 }
 
+impl<'a> RegionsMut for Paragraph<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        self.content.for_each_region_mut(f);
+    }
+}
+
 impl<'a> StrictEq for Paragraph<'a> {
     /// Performs strict_eq on content
     fn strict_eq(&self, other: &Self) -> bool {
         self.content.strict_eq(&other.content)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::elements::{
+        DecoratedText, DecoratedTextContent, InlineElement, Text,
+    };
+
+    fn paragraph(elements: Vec<InlineElement>) -> Paragraph {
+        Paragraph::from(
+            elements
+                .into_iter()
+                .map(Located::from)
+                .collect::<Vec<Located<InlineElement>>>(),
+        )
+    }
+
+    #[test]
+    fn into_children_should_yield_inline_elements_in_order() {
+        let paragraph = paragraph(vec![
+            InlineElement::from(Text::from("some ")),
+            InlineElement::from(DecoratedText::Bold(vec![Located::from(
+                DecoratedTextContent::from(Text::from("bold")),
+            )])),
+            InlineElement::from(Text::from(" text")),
+        ]);
+
+        let children = paragraph.into_children();
+        assert_eq!(children.len(), 3);
+        assert_eq!(
+            children.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["some ", "bold", " text"]
+        );
+    }
+
+    #[test]
+    fn sentences_should_split_flattened_text_on_sentence_boundaries() {
+        let paragraph = paragraph(vec![
+            InlineElement::from(Text::from("This is ")),
+            InlineElement::from(DecoratedText::Bold(vec![Located::from(
+                DecoratedTextContent::from(Text::from("one")),
+            )])),
+            InlineElement::from(Text::from(" sentence. Is this another?")),
+        ]);
+
+        assert_eq!(
+            paragraph.sentences(),
+            vec!["This is one sentence.", "Is this another?"]
+        );
+    }
+
+    #[test]
+    fn sentences_should_not_split_on_abbreviations() {
+        let paragraph = paragraph(vec![InlineElement::from(Text::from(
+            "Some fruit, e.g. apples, are red. Others are not",
+        ))]);
+
+        assert_eq!(
+            paragraph.sentences(),
+            vec!["Some fruit, e.g. apples, are red.", "Others are not"]
+        );
+    }
+}
@@ -9,6 +9,11 @@ pub enum Placeholder<'a> {
     NoHtml,
     Template(Cow<'a, str>),
     Date(NaiveDate),
+    Include(Cow<'a, str>),
+    LinkReference {
+        label: Cow<'a, str>,
+        target: Cow<'a, str>,
+    },
     Other {
         name: Cow<'a, str>,
         value: Cow<'a, str>,
@@ -31,6 +36,25 @@ impl Placeholder<'_> {
                 }))
             }
             Self::Date(x) => Placeholder::Date(*x),
+            Self::Include(ref x) => {
+                Placeholder::Include(Cow::Borrowed(match x {
+                    Borrowed(x) => *x,
+                    Owned(x) => x.as_str(),
+                }))
+            }
+            Self::LinkReference {
+                ref label,
+                ref target,
+            } => Placeholder::LinkReference {
+                label: Cow::Borrowed(match label {
+                    Borrowed(x) => *x,
+                    Owned(x) => x.as_str(),
+                }),
+                target: Cow::Borrowed(match target {
+                    Borrowed(x) => *x,
+                    Owned(x) => x.as_str(),
+                }),
+            },
             Self::Other {
                 ref name,
                 ref value,
@@ -55,6 +79,13 @@ impl Placeholder<'_> {
                 Placeholder::Template(Cow::from(x.into_owned()))
             }
             Self::Date(x) => Placeholder::Date(x),
+            Self::Include(x) => Placeholder::Include(Cow::from(x.into_owned())),
+            Self::LinkReference { label, target } => {
+                Placeholder::LinkReference {
+                    label: Cow::from(label.into_owned()),
+                    target: Cow::from(target.into_owned()),
+                }
+            }
             Self::Other { name, value } => Placeholder::Other {
                 name: Cow::from(name.into_owned()),
                 value: Cow::from(value.into_owned()),
@@ -80,6 +111,28 @@ impl<'a> Placeholder<'a> {
         Self::Template(Cow::from(template))
     }
 
+    pub fn include_from_str(path: &'a str) -> Self {
+        Self::Include(Cow::from(path))
+    }
+
+    pub fn include_from_string(path: String) -> Self {
+        Self::Include(Cow::from(path))
+    }
+
+    pub fn link_reference_from_str(label: &'a str, target: &'a str) -> Self {
+        Self::LinkReference {
+            label: Cow::from(label),
+            target: Cow::from(target),
+        }
+    }
+
+    pub fn link_reference_from_string(label: String, target: String) -> Self {
+        Self::LinkReference {
+            label: Cow::from(label),
+            target: Cow::from(target),
+        }
+    }
+
     pub fn other_from_str(name: &'a str, value: &'a str) -> Self {
         Self::Other {
             name: Cow::from(name),
@@ -1,7 +1,11 @@
 use crate::StrictEq;
 use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    hash::{Hash, Hasher},
+};
 
 #[derive(Constructor, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PreformattedText<'a> {
@@ -70,6 +74,20 @@ impl PreformattedText<'_> {
     }
 }
 
+impl<'a> Hash for PreformattedText<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.lang.hash(state);
+
+        // Grab all metadata and sort it so we get a reproducible iteration
+        // over the keys
+        let mut metadata = self.metadata.iter().collect::<Vec<_>>();
+        metadata.sort_unstable();
+        metadata.hash(state);
+
+        self.lines.hash(state);
+    }
+}
+
 impl<'a> StrictEq for PreformattedText<'a> {
     /// Same as PartialEq
     fn strict_eq(&self, other: &Self) -> bool {
@@ -0,0 +1,56 @@
+use crate::StrictEq;
+use derive_more::{Constructor, Display};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Represents a signature line ending a page, only parsed when enabled
+///
+/// In vimwiki, -- Author, Date would become
+///
+/// Signature("Author, Date")
+///
+#[derive(
+    Constructor,
+    Clone,
+    Debug,
+    Display,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub struct Signature<'a>(pub Cow<'a, str>);
+
+impl Signature<'_> {
+    pub fn to_borrowed(&self) -> Signature<'_> {
+        use self::Cow::*;
+
+        let inner = Cow::Borrowed(match &self.0 {
+            Borrowed(x) => *x,
+            Owned(x) => x.as_str(),
+        });
+
+        Signature(inner)
+    }
+
+    pub fn into_owned(self) -> Signature<'static> {
+        let inner = Cow::from(self.0.into_owned());
+
+        Signature(inner)
+    }
+}
+
+impl<'a> From<&'a str> for Signature<'a> {
+    fn from(s: &'a str) -> Self {
+        Self::new(Cow::from(s))
+    }
+}
+
+impl<'a> StrictEq for Signature<'a> {
+    /// Same as PartialEq
+    #[inline]
+    fn strict_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     lang::elements::{
-        InlineElement, InlineElementContainer, IntoChildren, Located,
+        InlineElement, InlineElementContainer, IntoChildren, Located, Region,
+        RegionsMut,
     },
     StrictEq,
 };
@@ -13,6 +14,10 @@ use serde::{Deserialize, Serialize};
 pub struct Table<'a> {
     pub rows: Vec<Located<Row<'a>>>,
     pub centered: bool,
+
+    /// Caption of the table taken from a `Table: caption` line directly
+    /// before or after the table
+    pub caption: Option<InlineElementContainer<'a>>,
 }
 
 impl Table<'_> {
@@ -24,6 +29,7 @@ impl Table<'_> {
                 .map(|x| x.as_ref().map(Row::to_borrowed))
                 .collect(),
             centered: self.centered,
+            caption: self.caption.as_ref().map(|x| x.to_borrowed()),
         }
     }
 
@@ -35,11 +41,17 @@ impl Table<'_> {
                 .map(|x| x.map(Row::into_owned))
                 .collect(),
             centered: self.centered,
+            caption: self.caption.map(|x| x.into_owned()),
         }
     }
 }
 
 impl<'a> Table<'a> {
+    /// Returns the caption of the table if it has one
+    pub fn caption(&self) -> Option<&InlineElementContainer<'a>> {
+        self.caption.as_ref()
+    }
+
     /// Returns the alignment of the specified column within the table
     ///
     /// NOTE: This will always return an alignment, even if the column
@@ -76,12 +88,24 @@ impl<'a> IntoChildren for Table<'a> {
         self.rows
             .into_iter()
             .flat_map(|x| x.into_inner().into_children())
+            .chain(self.caption.into_iter().flat_map(|x| x.into_children()))
             .collect()
     }
 }
 
+impl<'a> RegionsMut for Table<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        for row in self.rows.iter_mut() {
+            row.for_each_region_mut(f);
+        }
+        if let Some(x) = self.caption.as_mut() {
+            x.for_each_region_mut(f);
+        }
+    }
+}
+
 impl<'a> StrictEq for Table<'a> {
-    /// Performs strict_eq on rows and centered status
+    /// Performs strict_eq on rows, centered status, and caption
     fn strict_eq(&self, other: &Self) -> bool {
         self.centered == other.centered
             && self.rows.len() == other.rows.len()
@@ -90,6 +114,11 @@ impl<'a> StrictEq for Table<'a> {
                 .iter()
                 .zip(other.rows.iter())
                 .all(|(x, y)| x.strict_eq(y))
+            && match (&self.caption, &other.caption) {
+                (Some(x), Some(y)) => x.strict_eq(y),
+                (None, None) => true,
+                _ => false,
+            }
     }
 }
 
@@ -165,6 +194,16 @@ impl<'a> IntoChildren for Row<'a> {
     }
 }
 
+impl<'a> RegionsMut for Row<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        if let Self::Content { cells } = self {
+            for cell in cells.iter_mut() {
+                cell.for_each_region_mut(f);
+            }
+        }
+    }
+}
+
 impl<'a> StrictEq for Row<'a> {
     /// Performs strict_eq check on columns or cells depending on row type
     fn strict_eq(&self, other: &Self) -> bool {
@@ -218,6 +257,14 @@ impl<'a> IntoChildren for Cell<'a> {
     }
 }
 
+impl<'a> RegionsMut for Cell<'a> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        if let Self::Content(x) = self {
+            x.for_each_region_mut(f);
+        }
+    }
+}
+
 impl<'a> StrictEq for Cell<'a> {
     /// Performs strict_eq on cell content
     fn strict_eq(&self, other: &Self) -> bool {
@@ -3,17 +3,31 @@
 use crate::StrictEq;
 use derive_more::{Constructor, From};
 use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    hash::{Hash, Hasher},
+};
 
 mod blocks;
 pub use blocks::*;
 mod utils;
 pub use utils::{
-    AsChildrenMutSlice, AsChildrenSlice, IntoChildren, Located, Region,
+    AsChildrenMutSlice, AsChildrenSlice, IntoChildren, Located, Position,
+    Region, RegionsMut, Retained,
 };
 
 /// Represents a full page containing different elements
 #[derive(
-    Constructor, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize,
+    Constructor,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
 )]
 pub struct Page<'a> {
     /// Comprised of the elements within a page
@@ -30,9 +44,348 @@ impl<'a> Page<'a> {
     pub fn into_elements(self) -> Vec<Located<BlockElement<'a>>> {
         self.elements
     }
+
+    /// Inserts a block at the given index, moving it to where the block
+    /// currently at that index begins (or to just after the last block) and
+    /// shifting the blocks that follow by the bytes and lines of the
+    /// inserted block, including the regions of elements nested within them
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index > len`, like [`Vec::insert`]
+    pub fn insert_block(
+        &mut self,
+        index: usize,
+        mut block: Located<BlockElement<'a>>,
+    ) {
+        let (offset, line) = match self.elements.get(index) {
+            Some(x) => {
+                (x.region().offset(), x.region().start_position().line())
+            }
+            None => self
+                .elements
+                .last()
+                .map_or((0, 1), |x| (x.region().end(), line_after(x.region()))),
+        };
+        block.shift_lines(
+            offset as isize - block.region().offset() as isize,
+            line as isize - block.region().start_position().line() as isize,
+        );
+
+        let len = block.region().len() as isize;
+        let lines = line_count(block.region()) as isize;
+        for element in self.elements[index..].iter_mut() {
+            element.shift_lines(len, lines);
+        }
+
+        self.elements.insert(index, block);
+    }
+
+    /// Removes the block at the given index, shifting the blocks that
+    /// follow back by the bytes and lines of the removed block, or returns
+    /// none if there is no block at the index
+    pub fn remove_block(
+        &mut self,
+        index: usize,
+    ) -> Option<Located<BlockElement<'a>>> {
+        if index >= self.elements.len() {
+            return None;
+        }
+
+        let block = self.elements.remove(index);
+        let len = block.region().len() as isize;
+        let lines = line_count(block.region()) as isize;
+        for element in self.elements[index..].iter_mut() {
+            element.shift_lines(-len, -lines);
+        }
+
+        Some(block)
+    }
+
+    /// Resets the region of every element within the page, including those
+    /// nested within other elements, to the default region so that the page
+    /// no longer depends on the layout of the source it was parsed from,
+    /// such as when producing stable serialized output
+    pub fn strip_regions(&mut self) {
+        for element in self.elements.iter_mut() {
+            element.for_each_region_mut(&mut |region| {
+                *region = Region::default();
+            });
+        }
+    }
+
+    /// Computes a hash of the elements within the page, ignoring the regions
+    /// they occupy so that pages with equal content hash the same.
+    ///
+    /// The hash uses FNV-1a and is the same across platforms and releases of
+    /// Rust, so it can be stored and compared against later
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = ContentHasher::default();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Builds a tree of the headers within the page, where a header becomes
+    /// the child of the nearest preceding header with a smaller level
+    pub fn outline(&self) -> Vec<OutlineNode> {
+        fn pop_node(
+            stack: &mut Vec<OutlineNode>,
+            roots: &mut Vec<OutlineNode>,
+        ) {
+            if let Some(node) = stack.pop() {
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => roots.push(node),
+                }
+            }
+        }
+
+        let mut roots = Vec::new();
+        let mut stack: Vec<OutlineNode> = Vec::new();
+
+        for element in self.elements.iter() {
+            if let BlockElement::Header(header) = element.as_inner() {
+                while stack.last().is_some_and(|x| x.level >= header.level) {
+                    pop_node(&mut stack, &mut roots);
+                }
+
+                stack.push(OutlineNode::new(
+                    header.content.to_string(),
+                    header.level,
+                    element.region(),
+                    Vec::new(),
+                ));
+            }
+        }
+
+        while !stack.is_empty() {
+            pop_node(&mut stack, &mut roots);
+        }
+
+        roots
+    }
+
+    /// Produces the (start, end) lines of foldable regions of the page
+    /// sorted by start line, using the positions of each region to find
+    /// its lines (base index of 1).
+    ///
+    /// Each header folds until the last block before the next header of the
+    /// same or higher level, and every other block except paragraphs folds
+    /// if it spans more than one line
+    pub fn folding_ranges(&self) -> Vec<(usize, usize)> {
+        // NOTE: The end of a region is just past its last character, so a
+        //       region ending with a newline ends at the start of the next
+        //       line rather than on its last line
+        let lines = |region: Region| {
+            let start = region.start_position().line();
+            let end = region.end_position();
+            let last = if end.column() == 1 && end.line() > start {
+                end.line() - 1
+            } else {
+                end.line()
+            };
+            (start, last.max(start))
+        };
+
+        let mut ranges = Vec::new();
+        for (i, element) in self.elements.iter().enumerate() {
+            match element.as_inner() {
+                BlockElement::Header(header) => {
+                    let last = self.elements[i + 1..]
+                        .iter()
+                        .take_while(|x| match x.as_inner() {
+                            BlockElement::Header(x) => x.level > header.level,
+                            _ => true,
+                        })
+                        .last();
+                    if let Some(last) = last {
+                        let (start, _) = lines(element.region());
+                        let (_, end) = lines(last.region());
+                        ranges.push((start, end));
+                    }
+                }
+                BlockElement::Paragraph(_) => {}
+                _ => {
+                    let (start, end) = lines(element.region());
+                    if end > start {
+                        ranges.push((start, end));
+                    }
+                }
+            }
+        }
+
+        ranges.sort_unstable();
+        ranges
+    }
+
+    /// Counts occurrences of each keyword within the page, including those
+    /// nested within decorations, lists, and tables
+    pub fn keyword_counts(&self) -> HashMap<Keyword, usize> {
+        let mut counts = HashMap::new();
+        let mut stack: Vec<Located<Element>> = self
+            .to_borrowed()
+            .into_elements()
+            .into_iter()
+            .map(|x| x.map(Element::from))
+            .collect();
+
+        while let Some(element) = stack.pop() {
+            if let Element::Inline(InlineElement::Keyword(keyword)) =
+                element.as_inner()
+            {
+                *counts.entry(*keyword).or_insert(0) += 1;
+            }
+
+            stack.extend(element.into_inner().into_children());
+        }
+
+        counts
+    }
+
+    /// Iterates over every link within the page in the order they appear,
+    /// including those nested within decorations, lists, and tables, each
+    /// paired with the kind of target it points to
+    ///
+    /// The page is walked by reference as it is iterated, where each link
+    /// is located by the region of the element holding it
+    pub fn links(
+        &self,
+    ) -> impl Iterator<Item = (LinkKind, Located<&'_ Link<'a>>)> + '_ {
+        Links {
+            stack: vec![Box::new(
+                self.elements.iter().map(|x| LinkNode::Block(x.as_inner())),
+            )],
+        }
+        .map(|link| (link.kind(), link))
+    }
+
+    /// Collects every footnote reference within the page in the order they
+    /// appear, each paired with the top-level definition sharing its label
+    /// or none if the reference is dangling
+    pub fn footnotes(
+        &self,
+    ) -> Vec<(Located<FootnoteRef<'_>>, Option<Located<FootnoteDef<'_>>>)> {
+        let mut refs = Vec::new();
+        let mut stack: Vec<Located<Element>> = self
+            .to_borrowed()
+            .into_elements()
+            .into_iter()
+            .rev()
+            .map(|x| x.map(Element::from))
+            .collect();
+
+        while let Some(element) = stack.pop() {
+            let region = element.region();
+            match element.into_inner() {
+                Element::Inline(InlineElement::FootnoteRef(x)) => {
+                    refs.push(Located::new(x, region));
+                }
+                x => stack.extend(x.into_children().into_iter().rev()),
+            }
+        }
+
+        let defs: Vec<Located<FootnoteDef>> = self
+            .elements
+            .iter()
+            .filter_map(|e| match e.as_inner() {
+                BlockElement::FootnoteDef(x) => {
+                    Some(Located::new(x.to_borrowed(), e.region()))
+                }
+                _ => None,
+            })
+            .collect();
+
+        refs.into_iter()
+            .map(|x| {
+                let def = defs.iter().find(|d| d.label == x.label).cloned();
+                (x, def)
+            })
+            .collect()
+    }
+
+    /// Returns the text of the first `%title` placeholder of the page with
+    /// surrounding whitespace trimmed, or none if the page has no title
+    pub fn title(&self) -> Option<String> {
+        self.elements.iter().find_map(|e| match e.as_inner() {
+            BlockElement::Placeholder(Placeholder::Title(x)) => {
+                Some(x.trim().to_string())
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the top-level paragraphs of the page
+    pub fn paragraphs(&self) -> Vec<Located<&Paragraph<'a>>> {
+        self.blocks_of(|x| match x {
+            BlockElement::Paragraph(x) => Some(x),
+            _ => None,
+        })
+    }
+
+    /// Returns the top-level lists of the page
+    pub fn lists(&self) -> Vec<Located<&List<'a>>> {
+        self.blocks_of(|x| match x {
+            BlockElement::List(x) => Some(x),
+            _ => None,
+        })
+    }
+
+    /// Returns the top-level tables of the page
+    pub fn tables(&self) -> Vec<Located<&Table<'a>>> {
+        self.blocks_of(|x| match x {
+            BlockElement::Table(x) => Some(x),
+            _ => None,
+        })
+    }
+
+    /// Returns the top-level dividers of the page
+    pub fn dividers(&self) -> Vec<Located<&Divider>> {
+        self.blocks_of(|x| match x {
+            BlockElement::Divider(x) => Some(x),
+            _ => None,
+        })
+    }
+
+    /// Returns the top-level blocks of the page for which *f* produces an
+    /// element, keeping the region of the block
+    fn blocks_of<'b, T>(
+        &'b self,
+        f: impl Fn(&'b BlockElement<'a>) -> Option<&'b T>,
+    ) -> Vec<Located<&'b T>> {
+        self.elements
+            .iter()
+            .filter_map(|e| {
+                f(e.as_inner()).map(|x| Located::new(x, e.region()))
+            })
+            .collect()
+    }
 }
 
 impl Page<'_> {
+    /// Appends the blocks of another page after those of this page, as if
+    /// the source of the other page followed this one after `line_gap`
+    /// blank lines. The blocks of the other page, including the elements
+    /// nested within them, are shifted down by the number of lines of this
+    /// page plus the gap, and by the bytes up to the end of the last block
+    /// of this page plus a newline for each line of the gap
+    pub fn merge(self, other: Page, line_gap: usize) -> Page<'static> {
+        let (len, lines) = self
+            .elements
+            .last()
+            .map_or((0, 0), |x| (x.region().end(), line_after(x.region()) - 1));
+
+        let mut elements = self.into_owned().elements;
+        for mut element in other.into_owned().elements {
+            element.shift_lines(
+                (len + line_gap) as isize,
+                (lines + line_gap) as isize,
+            );
+            elements.push(element);
+        }
+
+        Page::new(elements)
+    }
+
     pub fn to_borrowed(&self) -> Page {
         let elements = self
             .elements
@@ -54,6 +407,65 @@ impl Page<'_> {
     }
 }
 
+/// Returns the number of lines covered by a region, counting a last line
+/// that does not end with a newline
+fn line_count(region: Region) -> usize {
+    let start = region.start_position();
+    let end = region.end_position();
+    end.line() - start.line() + usize::from(end.column() > 1)
+}
+
+/// Returns the line that follows the last line covered by a region
+fn line_after(region: Region) -> usize {
+    region.start_position().line() + line_count(region)
+}
+
+/// Represents a 64-bit FNV-1a hasher used for content hashes, unlike the
+/// default hasher of the standard library whose algorithm may change
+struct ContentHasher(u64);
+
+impl Default for ContentHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for ContentHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= u64::from(*b);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    // NOTE: Lengths and indexes are hashed as u64 in little-endian order so
+    //       the hash does not depend on the pointer width or endianness
+    fn write_usize(&mut self, i: usize) {
+        self.write(&(i as u64).to_le_bytes());
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl Page<'_> {
+    /// Serializes the page into a compact binary form, which is much smaller
+    /// than the equivalent JSON and suited for caching parsed pages
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // NOTE: Serializing into a vec only fails for types that cannot be
+        //       represented by bincode, which never applies to a page
+        bincode::serialize(self).expect("Page is always serializable")
+    }
+
+    /// Deserializes a page from the binary form produced by
+    /// [`Page::to_bytes`], taking ownership of all text within
+    pub fn from_bytes(bytes: &[u8]) -> bincode::Result<Page<'static>> {
+        bincode::deserialize(bytes)
+    }
+}
+
 impl<'a> StrictEq for Page<'a> {
     /// Performs strict_eq on page elements
     fn strict_eq(&self, other: &Self) -> bool {
@@ -66,6 +478,144 @@ impl<'a> StrictEq for Page<'a> {
     }
 }
 
+/// Represents an element of a page that can lead to links, referenced while
+/// walking the page for its links
+#[derive(Copy, Clone)]
+enum LinkNode<'b, 'a> {
+    Block(&'b BlockElement<'a>),
+    ListItem(&'b ListItem<'a>),
+    Inline(&'b Located<InlineElement<'a>>),
+    Decorated(&'b Located<DecoratedTextContent<'a>>),
+}
+
+type LinkNodes<'b, 'a> = Box<dyn Iterator<Item = LinkNode<'b, 'a>> + 'b>;
+
+impl<'b, 'a: 'b> LinkNode<'b, 'a> {
+    fn inlines(container: &'b InlineElementContainer<'a>) -> LinkNodes<'b, 'a> {
+        Box::new(container.elements.iter().map(Self::Inline))
+    }
+
+    fn list_items(list: &'b List<'a>) -> LinkNodes<'b, 'a> {
+        Box::new(list.items.iter().map(|x| Self::ListItem(x.as_inner())))
+    }
+
+    fn decorated(text: &'b DecoratedText<'a>) -> LinkNodes<'b, 'a> {
+        Box::new(text.as_children_slice().iter().map(Self::Decorated))
+    }
+
+    /// Returns the link of the node along with the region of the element
+    /// holding it, if the node is a link
+    fn link(self) -> Option<Located<&'b Link<'a>>> {
+        match self {
+            Self::Inline(x) => match x.as_inner() {
+                InlineElement::Link(link) => {
+                    Some(Located::new(link, x.region()))
+                }
+                _ => None,
+            },
+            Self::Decorated(x) => match x.as_inner() {
+                DecoratedTextContent::Link(link) => {
+                    Some(Located::new(link, x.region()))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the nodes nested within the node in the order they appear,
+    /// where terms of definition lists are ordered by their position
+    fn children(self) -> LinkNodes<'b, 'a> {
+        match self {
+            Self::Block(BlockElement::Blockquote(x)) => {
+                Box::new(x.attribution.iter().flat_map(Self::inlines))
+            }
+            Self::Block(BlockElement::DefinitionList(x)) => {
+                let mut terms: Vec<_> = x.iter().collect();
+                terms.sort_by_key(|(term, _)| term.region().offset());
+                Box::new(terms.into_iter().flat_map(|(term, defs)| {
+                    Self::inlines(term.as_inner().as_inner()).chain(
+                        defs.iter().flat_map(|def| match def.as_inner() {
+                            Definition::Inline(value) => {
+                                Self::inlines(value.as_inner())
+                            }
+                            Definition::WithList { value, list } => Box::new(
+                                Self::inlines(value.as_inner())
+                                    .chain(Self::list_items(list)),
+                            ),
+                        }),
+                    )
+                }))
+            }
+            Self::Block(BlockElement::FootnoteDef(x)) => {
+                Self::inlines(&x.content)
+            }
+            Self::Block(BlockElement::Header(x)) => Self::inlines(&x.content),
+            Self::Block(BlockElement::List(x)) => Self::list_items(x),
+            Self::Block(BlockElement::Paragraph(x)) => {
+                Self::inlines(&x.content)
+            }
+            Self::Block(BlockElement::Table(x)) => Box::new(
+                x.rows
+                    .iter()
+                    .flat_map(|row| match row.as_inner() {
+                        Row::Content { cells } => cells.as_slice(),
+                        Row::Divider { .. } => &[],
+                    })
+                    .filter_map(|cell| match cell.as_inner() {
+                        Cell::Content(x) => Some(x),
+                        _ => None,
+                    })
+                    .chain(x.caption.iter())
+                    .flat_map(Self::inlines),
+            ),
+            Self::Block(_) => Box::new(std::iter::empty()),
+            Self::ListItem(x) => {
+                Box::new(x.contents.contents.iter().flat_map(|content| {
+                    match content.as_inner() {
+                        ListItemContent::InlineContent(x) => Self::inlines(x),
+                        ListItemContent::List(x) => Self::list_items(x),
+                    }
+                }))
+            }
+            Self::Inline(x) => match x.as_inner() {
+                InlineElement::DecoratedText(x) => Self::decorated(x),
+                _ => Box::new(std::iter::empty()),
+            },
+            Self::Decorated(x) => match x.as_inner() {
+                DecoratedTextContent::DecoratedText(x) => Self::decorated(x),
+                _ => Box::new(std::iter::empty()),
+            },
+        }
+    }
+}
+
+/// Iterator over the links of a page that walks the page depth-first by
+/// reference, descending into an element only once it is reached
+struct Links<'b, 'a> {
+    stack: Vec<LinkNodes<'b, 'a>>,
+}
+
+impl<'b, 'a: 'b> Iterator for Links<'b, 'a> {
+    type Item = Located<&'b Link<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(nodes) = self.stack.last_mut() {
+            match nodes.next() {
+                Some(node) => match node.link() {
+                    Some(link) => return Some(link),
+                    None => self.stack.push(node.children()),
+                },
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+
+        None
+    }
+}
+
 /// Represents a `BlockElement`, an `InlineElement`, or one of a handful of
 /// special inbetween types like `ListItem`
 #[derive(Clone, Debug, From, PartialEq, Eq, Serialize, Deserialize)]
@@ -212,11 +762,7 @@ impl<'a> IntoChildren for InlineBlockElement<'a> {
                 .into_iter()
                 .map(|x| x.map(Element::from))
                 .collect(),
-            Self::Definition(x) => x
-                .into_children()
-                .into_iter()
-                .map(|x| x.map(Element::from))
-                .collect(),
+            Self::Definition(x) => x.into_children(),
         }
     }
 }
@@ -232,6 +778,74 @@ impl<'a> StrictEq for InlineBlockElement<'a> {
     }
 }
 
+impl<'a> From<Located<ListItem<'a>>> for Located<InlineBlockElement<'a>> {
+    fn from(element: Located<ListItem<'a>>) -> Self {
+        element.map(InlineBlockElement::from)
+    }
+}
+
+impl<'a> TryFrom<Located<BlockElement<'a>>>
+    for Located<InlineBlockElement<'a>>
+{
+    type Error = Located<BlockElement<'a>>;
+
+    /// Demotes a list containing a single item into that item and a
+    /// definition list containing a single term without definitions into
+    /// that term, keeping the region of the item or term. Any other block
+    /// element is returned as the error.
+    fn try_from(
+        element: Located<BlockElement<'a>>,
+    ) -> Result<Self, Self::Error> {
+        let region = element.region();
+        match element.into_inner() {
+            BlockElement::List(mut list) if list.items.len() == 1 => {
+                Ok(list.items.remove(0).map(InlineBlockElement::ListItem))
+            }
+            BlockElement::DefinitionList(list)
+                if list.terms().len() == 1
+                    && list.definitions().next().is_none() =>
+            {
+                Ok(list
+                    .into_iter()
+                    .map(|(term, _)| term.map(InlineBlockElement::Term))
+                    .next()
+                    .expect("Definition list missing its only term"))
+            }
+            x => Err(Located::new(x, region)),
+        }
+    }
+}
+
+impl<'a> TryFrom<Located<InlineBlockElement<'a>>>
+    for Located<BlockElement<'a>>
+{
+    type Error = Located<InlineBlockElement<'a>>;
+
+    /// Promotes a list item into a list containing only that item and a term
+    /// into a definition list containing only that term, where both the new
+    /// block element and its child share the region of the original. A
+    /// definition is returned as the error as it cannot exist without a term.
+    fn try_from(
+        element: Located<InlineBlockElement<'a>>,
+    ) -> Result<Self, Self::Error> {
+        let region = element.region();
+        match element.into_inner() {
+            InlineBlockElement::ListItem(x) => Ok(Located::new(
+                BlockElement::from(List::new(vec![Located::new(x, region)])),
+                region,
+            )),
+            InlineBlockElement::Term(x) => Ok(Located::new(
+                BlockElement::from(DefinitionList::from(vec![(
+                    Located::new(x, region),
+                    Vec::new(),
+                )])),
+                region,
+            )),
+            x => Err(Located::new(x, region)),
+        }
+    }
+}
+
 macro_rules! element_impl_from {
     ($type:ty, $class:ident) => {
         impl<'a> From<$type> for Element<'a> {
@@ -245,12 +859,14 @@ macro_rules! element_impl_from {
 element_impl_from!(Blockquote<'a>, BlockElement);
 element_impl_from!(DefinitionList<'a>, BlockElement);
 element_impl_from!(Divider, BlockElement);
+element_impl_from!(FootnoteDef<'a>, BlockElement);
 element_impl_from!(Header<'a>, BlockElement);
 element_impl_from!(List<'a>, BlockElement);
 element_impl_from!(MathBlock<'a>, BlockElement);
 element_impl_from!(Paragraph<'a>, BlockElement);
 element_impl_from!(Placeholder<'a>, BlockElement);
 element_impl_from!(PreformattedText<'a>, BlockElement);
+element_impl_from!(Signature<'a>, BlockElement);
 element_impl_from!(Table<'a>, BlockElement);
 
 element_impl_from!(Text<'a>, InlineElement);
@@ -260,5 +876,394 @@ element_impl_from!(Link<'a>, InlineElement);
 element_impl_from!(Tags<'a>, InlineElement);
 element_impl_from!(CodeInline<'a>, InlineElement);
 element_impl_from!(MathInline<'a>, InlineElement);
+element_impl_from!(FootnoteRef<'a>, InlineElement);
+element_impl_from!(Hashtag<'a>, InlineElement);
 
 element_impl_from!(ListItem<'a>, InlineBlockElement);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::output::parse_page;
+
+    #[test]
+    fn inline_block_element_term_should_convert_to_and_from_block_element() {
+        let region = Region::new(3, 4);
+        let term =
+            Located::new(InlineBlockElement::Term(Term::from("term")), region);
+
+        let block = Located::<BlockElement>::try_from(term.clone()).unwrap();
+        assert_eq!(block.region(), region);
+        match block.as_inner() {
+            BlockElement::DefinitionList(x) => {
+                let terms: Vec<_> = x.terms().collect();
+                assert_eq!(terms.len(), 1);
+                assert_eq!(terms[0].region(), region);
+                assert_eq!(x.definitions().count(), 0);
+            }
+            x => panic!("Unexpected block element: {:?}", x),
+        }
+
+        let element = Located::<InlineBlockElement>::try_from(block).unwrap();
+        assert!(element.strict_eq(&term), "Term changed: {:?}", element);
+    }
+
+    #[test]
+    fn list_item_should_convert_to_and_from_block_element() {
+        let page = parse_page("- some item\n");
+        let list = page.elements[0].clone();
+        let item_region = match list.as_inner() {
+            BlockElement::List(x) => x.items[0].region(),
+            x => panic!("Unexpected block element: {:?}", x),
+        };
+
+        let item = Located::<InlineBlockElement>::try_from(list).unwrap();
+        assert_eq!(item.region(), item_region);
+        assert!(matches!(item.as_inner(), InlineBlockElement::ListItem(_)));
+
+        let block = Located::<BlockElement>::try_from(item).unwrap();
+        assert_eq!(block.region(), item_region);
+        assert!(matches!(block.as_inner(), BlockElement::List(_)));
+    }
+
+    #[test]
+    fn inline_block_element_definition_should_not_convert_to_block_element() {
+        let definition = Located::new(
+            InlineBlockElement::Definition(Definition::from("definition")),
+            Region::new(1, 10),
+        );
+
+        let result = Located::<BlockElement>::try_from(definition.clone());
+        assert!(result.unwrap_err().strict_eq(&definition));
+    }
+
+    #[test]
+    fn content_hash_should_only_depend_on_content_of_page() {
+        let page = parse_page("= Header =\n\nsome *bold* text\n");
+        let spaced_page = parse_page("\n= Header =\n\n\n\nsome *bold* text");
+        let other_page = parse_page("= Header =\n\nsome *bold* texts\n");
+
+        assert_ne!(page.elements[1].region(), spaced_page.elements[1].region());
+        assert_eq!(page.content_hash(), spaced_page.content_hash());
+        assert_ne!(page.content_hash(), other_page.content_hash());
+    }
+
+    #[test]
+    fn content_hasher_should_produce_fnv_1a_hashes() {
+        let hash = |bytes: &[u8]| {
+            let mut hasher = ContentHasher::default();
+            hasher.write(bytes);
+            hasher.finish()
+        };
+
+        assert_eq!(hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(hash(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn links_should_classify_each_link_by_its_target() {
+        let page = parse_page(indoc::indoc! {"
+            [[Some Page]] and [[wiki1:Other Page]]
+
+            - [[diary:2021-03-04]]
+            - *bold [[#some anchor]]*
+
+            https://example.com or [[mailto:me@example.com|email]]
+        "});
+        let links: Vec<(LinkKind, Located<&Link>)> = page.links().collect();
+
+        assert_eq!(
+            links.iter().map(|(kind, _)| *kind).collect::<Vec<_>>(),
+            vec![
+                LinkKind::Wiki,
+                LinkKind::InterWiki,
+                LinkKind::Diary,
+                LinkKind::Anchor,
+                LinkKind::Url,
+                LinkKind::Url,
+            ]
+        );
+        assert!(
+            links
+                .windows(2)
+                .all(|x| x[0].1.region().offset() < x[1].1.region().offset()),
+            "Links not in order of appearance"
+        );
+    }
+
+    #[test]
+    fn links_should_include_those_of_tables_definitions_and_sublists() {
+        let page = parse_page(indoc::indoc! {"
+            |[[A]]|*[[B]]*|
+
+            Term [[C]]:: Definition [[D]]
+
+            - item
+              - sub [[E]]
+        "});
+
+        assert_eq!(
+            page.links()
+                .map(|(_, link)| link.to_string())
+                .collect::<Vec<_>>(),
+            vec!["A", "B", "C", "D", "E"]
+        );
+    }
+
+    #[test]
+    fn footnotes_should_pair_references_with_their_definitions() {
+        let page = parse_page(indoc::indoc! {"
+            First[^1] and *second[^2]*
+
+            [^1]: The first note
+        "});
+        let footnotes = page.footnotes();
+
+        assert_eq!(footnotes.len(), 2);
+        assert_eq!(footnotes[0].0.label, "1");
+        assert_eq!(
+            footnotes[0].1.as_ref().map(|x| x.content.to_string()),
+            Some("The first note".to_string())
+        );
+        assert_eq!(
+            footnotes[0].1.as_ref().unwrap().region(),
+            page.elements[1].region()
+        );
+
+        // Dangling references have no definition
+        assert_eq!(footnotes[1].0.label, "2");
+        assert!(footnotes[1].1.is_none());
+    }
+
+    #[test]
+    fn merge_should_shift_blocks_of_other_page_past_first_page() {
+        let first = parse_page("one\ntwo\nthree\n");
+        let second = parse_page("= *Four* =\nfive\n");
+
+        // Second page starts after the three lines of the first page and
+        // two blank lines, including the elements nested within its blocks
+        let page = first.merge(second, 2);
+        let expected = parse_page("one\ntwo\nthree\n\n\n= *Four* =\nfive\n");
+        assert!(page.strict_eq(&expected), "Unexpected page: {:?}", page);
+        assert_eq!(page.elements[1].region().start_position().line(), 6);
+        assert_eq!(page.elements[2].region().start_position().line(), 7);
+    }
+
+    #[test]
+    fn keyword_counts_should_tally_keywords_including_decorations() {
+        let page = parse_page("TODO first\n\n*TODO bold* then DONE\n");
+        let counts = page.keyword_counts();
+
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts.get(&Keyword::Todo), Some(&2));
+        assert_eq!(counts.get(&Keyword::Done), Some(&1));
+    }
+
+    #[test]
+    fn outline_should_nest_headers_by_level() {
+        let page = parse_page(
+            "= One =\n== Two ==\n=== Three ===\n== Four ==\n==== Five ====\n",
+        );
+        let outline = page.outline();
+
+        assert_eq!(outline.len(), 1);
+        let one = &outline[0];
+        assert_eq!(one.text, "One");
+        assert_eq!(one.level, 1);
+        assert_eq!(one.region, page.elements[0].region());
+        assert_eq!(one.children.len(), 2);
+
+        let two = &one.children[0];
+        assert_eq!(two.text, "Two");
+        assert_eq!(two.children.len(), 1);
+        assert_eq!(two.children[0].text, "Three");
+        assert!(two.children[0].children.is_empty());
+
+        // Skipped levels attach to the nearest shallower header
+        let four = &one.children[1];
+        assert_eq!(four.text, "Four");
+        assert_eq!(four.children.len(), 1);
+        assert_eq!(four.children[0].text, "Five");
+        assert_eq!(four.children[0].level, 4);
+    }
+
+    #[test]
+    fn paragraphs_and_lists_should_only_include_top_level_blocks_of_kind() {
+        let page = parse_page(indoc::indoc! {"
+            = Header =
+            first paragraph
+
+            - list item
+            - another item
+
+            second paragraph
+
+            ----
+
+            * other list
+
+            | table |
+
+            third paragraph
+        "});
+
+        assert_eq!(page.paragraphs().len(), 3);
+        assert_eq!(page.lists().len(), 2);
+        assert_eq!(page.tables().len(), 1);
+        assert_eq!(page.dividers().len(), 1);
+
+        let paragraph = &page.paragraphs()[0];
+        assert_eq!(paragraph.content.to_string(), "first paragraph");
+        assert_eq!(paragraph.region(), page.elements[1].region());
+    }
+
+    #[test]
+    fn block_element_into_children_should_dispatch_to_each_variant() {
+        let page = parse_page("Some *bold* text\n\n- one\n- two\n");
+        let mut blocks = page.into_elements().into_iter();
+
+        let paragraph = blocks.next().unwrap().into_inner();
+        assert!(matches!(paragraph, BlockElement::Paragraph(_)));
+        let children = paragraph.into_children();
+        assert_eq!(children.len(), 3);
+        assert!(children.iter().all(|x| x.as_inline_element().is_some()));
+        assert!(matches!(
+            children[1].as_inline_element(),
+            Some(InlineElement::DecoratedText(_))
+        ));
+
+        let list = blocks.next().unwrap().into_inner();
+        assert!(matches!(list, BlockElement::List(_)));
+        let children = list.into_children();
+        assert_eq!(children.len(), 2);
+        assert!(children.iter().all(|x| matches!(
+            x.as_inline_block_element(),
+            Some(InlineBlockElement::ListItem(_))
+        )));
+    }
+
+    #[test]
+    fn folding_ranges_should_fold_header_sections_and_multiline_blocks() {
+        let source = indoc::indoc! {"
+            = Header =
+            first paragraph
+
+            second paragraph
+
+            - one
+            - two
+
+            = Other =
+        "};
+        let page = parse_page(source);
+
+        assert_eq!(page.folding_ranges(), vec![(1, 7), (6, 7)]);
+    }
+
+    #[test]
+    fn folding_ranges_should_include_last_line_without_trailing_newline() {
+        let page = parse_page(
+            "= Header =
+- one
+- two",
+        );
+        assert_eq!(page.folding_ranges(), vec![(1, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn title_should_return_trimmed_text_of_title_placeholder() {
+        let page = parse_page("some text\n%title   Foo  \n");
+        assert_eq!(page.title(), Some("Foo".to_string()));
+    }
+
+    #[test]
+    fn title_should_return_none_if_page_has_no_title_placeholder() {
+        let page = parse_page("= Foo =\nsome text\n");
+        assert_eq!(page.title(), None);
+    }
+
+    fn regions(page: &Page) -> Vec<Region> {
+        page.elements().iter().map(|x| x.region()).collect()
+    }
+
+    #[test]
+    fn insert_block_should_shift_following_blocks_by_its_lines() {
+        let mut page = parse_page("= A =\n= *B* =\n= C =\n");
+        let block = parse_page("one\ntwo\n").into_elements().remove(0);
+        page.insert_block(1, block);
+
+        // Nested elements move along with their blocks
+        let expected = parse_page("= A =\none\ntwo\n= *B* =\n= C =\n");
+        assert!(page.strict_eq(&expected), "Unexpected page: {:?}", page);
+
+        let lines: Vec<usize> = regions(&page)
+            .iter()
+            .map(|x| x.start_position().line())
+            .collect();
+        assert_eq!(lines, vec![1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn remove_block_should_shift_following_blocks_back() {
+        let mut page = parse_page("= A =\none\ntwo\n= *B* =\n= C =\n");
+        let block = page.remove_block(1).expect("Missing block");
+        assert!(matches!(block.as_inner(), BlockElement::Paragraph(_)));
+
+        let expected = parse_page("= A =\n= *B* =\n= C =\n");
+        assert!(page.strict_eq(&expected), "Unexpected page: {:?}", page);
+        assert!(page.remove_block(3).is_none());
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn from_bytes_should_restore_page_produced_by_to_bytes() {
+        let page = parse_page(indoc::indoc! {"
+            = Title =
+            Some *bold* text with [[link|a description]] and `code`.
+            - item one
+            - item two
+            |a|b|
+        "});
+
+        let bytes = page.to_bytes();
+        let restored = Page::from_bytes(&bytes).expect("Failed to decode page");
+        assert!(restored.strict_eq(&page), "Pages differ: {:?}", restored);
+
+        let json = serde_json::to_vec(&page).expect("Failed to encode json");
+        assert!(
+            bytes.len() < json.len(),
+            "Binary form ({} bytes) not smaller than json ({} bytes)",
+            bytes.len(),
+            json.len()
+        );
+    }
+
+    #[test]
+    fn strip_regions_should_make_pages_independent_of_source_layout() {
+        let source = indoc::indoc! {"
+            = Title =
+            - *bold [[link|*desc*]]*
+            |a|b|
+            term:: def
+        "};
+        let shifted_source = format!("\n\n{}", source);
+        let mut page = parse_page(source);
+        let mut shifted = parse_page(&shifted_source);
+        assert!(!page.strict_eq(&shifted));
+
+        page.strip_regions();
+        shifted.strip_regions();
+        assert!(page.strict_eq(&shifted), "Regions differ: {:?}", page);
+
+        // Every located element within the page ends with the default region
+        let serialized = format!("{:?}", page);
+        assert!(serialized.contains("Located("));
+        assert_eq!(
+            serialized.matches("Located(").count(),
+            serialized.matches(", 1:1-1:1 [0..0] @ 0)").count(),
+            "Unstripped region: {}",
+            serialized
+        );
+    }
+}
@@ -1,7 +1,14 @@
 use crate::StrictEq;
 use derive_more::{Constructor, Deref, DerefMut, Display};
 use serde::{Deserialize, Serialize};
-use std::hash::{Hash, Hasher};
+use std::{
+    borrow::Cow,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+mod position;
+pub use position::Position;
 
 mod region;
 pub use region::Region;
@@ -36,13 +43,20 @@ pub trait IntoChildren {
     fn into_children(self) -> Vec<Self::Child>;
 }
 
+/// Represents a trait that provides the ability to edit the region of an
+/// element and of every element nested within it, such as to reset or shift
+/// all of them at once
+pub trait RegionsMut {
+    /// Applies *f* to every region contained within
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region));
+}
+
 /// Represents an encapsulation of a language element and its location
 /// within some string/file
 #[derive(
     Constructor,
     Copy,
     Clone,
-    Debug,
     Display,
     Deref,
     DerefMut,
@@ -115,6 +129,56 @@ impl<T> Located<T> {
     pub fn region(&self) -> Region {
         self.region
     }
+
+    /// Returns a mutable reference to the region associated with the inner
+    /// value so it can be edited in place
+    pub fn region_mut(&mut self) -> &mut Region {
+        &mut self.region
+    }
+
+    /// Moves the region of the element and of every element nested within
+    /// it by the given number of lines and columns, such as after inserting
+    /// or removing text before the element.
+    ///
+    /// Every line moves by `line_delta`, while `col_delta` only applies to
+    /// positions on the line where this element starts as text inserted
+    /// before the element on that line does not move any later lines.
+    /// Positions are clamped to the first line and column, and byte offsets
+    /// are left unchanged
+    pub fn shift(&mut self, line_delta: isize, col_delta: isize)
+    where
+        T: RegionsMut,
+    {
+        let line = self.region.start_position().line();
+        self.for_each_region_mut(&mut |region| {
+            *region = region.translate_on_line(line, line_delta, col_delta);
+        });
+    }
+
+    /// Moves the region of the element and of every element nested within
+    /// it by the given number of bytes and lines, keeping their columns, such
+    /// as when whole lines are placed before the element
+    pub(crate) fn shift_lines(&mut self, bytes: isize, lines: isize)
+    where
+        T: RegionsMut,
+    {
+        self.for_each_region_mut(&mut |region| {
+            *region = region.shifted(bytes).translate(lines, 0);
+        });
+    }
+
+    /// Combines a `Located<T>` and `Located<U>` into a `Located<(T, U)>`
+    /// whose region covers the regions of both
+    pub fn zip<U>(self, other: Located<U>) -> Located<(T, U)> {
+        Located::new((self.inner, other.inner), self.region.merge(other.region))
+    }
+}
+
+impl<T: RegionsMut> RegionsMut for Located<T> {
+    fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+        f(&mut self.region);
+        self.inner.for_each_region_mut(f);
+    }
 }
 
 impl<T> Located<Option<T>> {
@@ -165,6 +229,30 @@ impl<T: StrictEq> StrictEq<T> for Located<T> {
     }
 }
 
+impl<T: fmt::Debug> fmt::Debug for Located<T> {
+    /// Formats the element followed by its region as the positions
+    /// `line:column-line:column`, the byte range, and the depth of the
+    /// region, such as `Located(Text("abc"), 1:4-1:7 [3..6] @ 2)`, which
+    /// keeps nested elements readable compared to the fields of each region
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = self.region.start_position();
+        let end = self.region.end_position();
+        f.debug_tuple("Located")
+            .field(&self.inner)
+            .field(&format_args!(
+                "{}:{}-{}:{} [{}..{}] @ {}",
+                start.line(),
+                start.column(),
+                end.line(),
+                end.column(),
+                self.region.offset(),
+                self.region.end(),
+                self.region.depth()
+            ))
+            .finish()
+    }
+}
+
 impl<T: Hash> Hash for Located<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.inner.hash(state);
@@ -178,6 +266,76 @@ impl<T> From<T> for Located<T> {
     }
 }
 
+impl<T> From<(T, Position, Position)> for Located<T> {
+    /// Creates around `T`, using a region spanning from the first position
+    /// up to the second position
+    fn from((t, start, end): (T, Position, Position)) -> Self {
+        Self::new(t, Region::from((start, end)))
+    }
+}
+
+/// Represents an encapsulation of a language element and, optionally, the
+/// original text it was parsed from so that an untouched element can be
+/// written back out verbatim
+///
+/// The original text is ignored when comparing and hashing, meaning two
+/// elements are equal regardless of whether either retained its text
+#[derive(Clone, Debug, Deref, Serialize, Deserialize)]
+pub struct Retained<'a, T> {
+    #[deref]
+    inner: T,
+    raw: Option<Cow<'a, str>>,
+}
+
+impl<'a, T> Retained<'a, T> {
+    pub fn new(inner: T, raw: Option<Cow<'a, str>>) -> Self {
+        Self { inner, raw }
+    }
+
+    /// Returns the original text of the element, if it was retained
+    pub fn raw(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+
+    /// Converts from `&Retained<T>` to `&T`
+    pub fn as_inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Converts from `&mut Retained<T>` to `&mut T`, discarding the original
+    /// text as the element may no longer match it
+    pub fn as_mut_inner(&mut self) -> &mut T {
+        self.raw = None;
+        &mut self.inner
+    }
+
+    /// Converts from `Retained<T>` to `T`
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq for Retained<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<'a, T: Eq> Eq for Retained<'a, T> {}
+
+impl<'a, T: Hash> Hash for Retained<'a, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<'a, T> From<T> for Retained<'a, T> {
+    /// Creates around `T` without any original text
+    fn from(t: T) -> Self {
+        Self::new(t, None)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -191,6 +349,15 @@ mod tests {
         assert_eq!(mapped_le.region(), Region::new(1, 4));
     }
 
+    #[test]
+    fn zip_should_pair_inner_values_and_merge_regions() {
+        let le1 = Located::new(3, Region::new(1, 4));
+        let le2 = Located::new(7, Region::new(8, 2));
+        let zipped = le1.zip(le2);
+        assert_eq!(*zipped.as_inner(), (3, 7));
+        assert_eq!(zipped.region(), Region::new(1, 9));
+    }
+
     #[test]
     fn equality_with_other_should_only_use_inner_value() {
         let le1 = Located::new(3, Region::new(1, 4));
@@ -231,6 +398,90 @@ mod tests {
         assert_eq!(le.region(), Region::new(1, 4));
     }
 
+    #[test]
+    fn region_mut_should_allow_editing_region_in_place() {
+        let mut le = Located::new(3, Region::new(10, 4));
+
+        *le.region_mut() = Region::new(2, 8);
+        assert_eq!(le.region(), Region::new(2, 8));
+
+        let region = le.region_mut();
+        *region = region.shifted(5);
+        assert_eq!(le.region(), Region::new(7, 8));
+    }
+
+    #[derive(Debug)]
+    struct Leaf;
+
+    impl RegionsMut for Leaf {
+        fn for_each_region_mut(&mut self, _f: &mut dyn FnMut(&mut Region)) {}
+    }
+
+    #[derive(Debug)]
+    struct Parent(Vec<Located<Leaf>>);
+
+    impl RegionsMut for Parent {
+        fn for_each_region_mut(&mut self, f: &mut dyn FnMut(&mut Region)) {
+            for child in self.0.iter_mut() {
+                child.for_each_region_mut(f);
+            }
+        }
+    }
+
+    fn at(start: (usize, usize), end: (usize, usize)) -> Region {
+        Region::from((Position::from(start), Position::from(end)))
+    }
+
+    #[test]
+    fn shift_should_move_both_start_and_end_of_region_and_nested_regions() {
+        let mut le = Located::new(
+            Parent(vec![
+                Located::new(Leaf, at((1, 4), (1, 6))),
+                Located::new(Leaf, at((2, 1), (3, 5))),
+            ]),
+            at((1, 3), (3, 5)),
+        );
+
+        // Columns only move positions on the line where the element starts
+        le.shift(2, 4);
+        assert_eq!(le.region(), at((3, 7), (5, 5)));
+        assert_eq!(le.0[0].region(), at((3, 8), (3, 10)));
+        assert_eq!(le.0[1].region(), at((4, 1), (5, 5)));
+
+        // Positions are clamped to the first line and column
+        le.shift(-10, -10);
+        assert_eq!(le.region(), at((1, 1), (1, 5)));
+        assert_eq!(le.0[0].region(), at((1, 1), (1, 1)));
+        assert_eq!(le.0[1].region(), at((1, 1), (1, 5)));
+    }
+
+    #[test]
+    fn from_should_support_located_between_two_positions() {
+        let le: Located<usize> =
+            Located::from((3, Position::new(1, 2), Position::new(4, 5)));
+        assert_eq!(*le.as_inner(), 3);
+        assert_eq!(le.region().start_position(), Position::new(1, 2));
+        assert_eq!(le.region().end_position(), Position::new(4, 5));
+    }
+
+    #[test]
+    fn debug_should_include_element_and_region_as_positions_offsets_and_depth()
+    {
+        let region = Region::new_at_depth(3, 5, 2)
+            .with_positions(Position::new(1, 4), Position::new(1, 9));
+        let le = Located::new(Some("abc"), region);
+        assert_eq!(
+            format!("{:?}", le),
+            r#"Located(Some("abc"), 1:4-1:9 [3..8] @ 2)"#
+        );
+
+        let nested = Located::new(vec![le], at((1, 1), (2, 1)));
+        assert_eq!(
+            format!("{:?}", nested),
+            r#"Located([Located(Some("abc"), 1:4-1:9 [3..8] @ 2)], 1:1-2:1 [0..0] @ 0)"#
+        );
+    }
+
     #[test]
     fn as_ref_should_return_new_element_with_ref_and_same_region() {
         #[derive(Debug, PartialEq, Eq)]
@@ -288,4 +539,24 @@ mod tests {
 
         assert_eq!(inner, Test(5));
     }
+
+    #[test]
+    fn retained_equality_and_hashing_should_ignore_raw_text() {
+        let r1 = Retained::new(3, Some(Cow::from(" 3 ")));
+        let r2 = Retained::from(3);
+        assert_eq!(r1, r2);
+
+        let mut m = HashSet::new();
+        m.insert(r1);
+        let r = m.get(&r2).expect("Failed to retrieve Retained");
+        assert_eq!(r.raw(), Some(" 3 "));
+    }
+
+    #[test]
+    fn retained_as_mut_inner_should_discard_raw_text() {
+        let mut r = Retained::new(3, Some(Cow::from("3")));
+        *r.as_mut_inner() += 1;
+        assert_eq!(*r.as_inner(), 4);
+        assert_eq!(r.raw(), None);
+    }
 }
@@ -0,0 +1,190 @@
+use crate::lang::parsers::Span;
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Sub};
+
+/// Represents a position in a string or file as a line and column, both of
+/// which start at 1 to match how positions are reported by editors such as
+/// vim, meaning that the first character of the input is at `1:1`
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Hash,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+pub struct Position {
+    /// Line of the position, starting at 1
+    line: usize,
+
+    /// Column of the position within its line, starting at 1
+    column: usize,
+}
+
+impl Position {
+    /// Position of the first character of some input
+    pub const ORIGIN: Position = Position { line: 1, column: 1 };
+
+    /// Constructs a new position at the given line and column, which are
+    /// expected to start at 1; use [`Position::try_new`] to reject a line or
+    /// column of zero
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Constructs a new position at the given line and column, returning
+    /// none if either is zero as both start at 1
+    pub fn try_new(line: usize, column: usize) -> Option<Self> {
+        if line > 0 && column > 0 {
+            Some(Self::new(line, column))
+        } else {
+            None
+        }
+    }
+
+    /// The line of the position
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the position
+    #[inline]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Constructs a copy of the position moved forward (positive) or
+    /// backward (negative) by the given number of lines and columns
+    pub(crate) fn translated(self, lines: isize, columns: isize) -> Self {
+        let position = if lines < 0 {
+            self - (lines.unsigned_abs(), 0)
+        } else {
+            self + (lines as usize, 0)
+        };
+
+        if columns < 0 {
+            position - (0, columns.unsigned_abs())
+        } else {
+            position + (0, columns as usize)
+        }
+    }
+}
+
+impl Default for Position {
+    /// Returns the origin at `1:1`
+    fn default() -> Self {
+        Self::ORIGIN
+    }
+}
+
+impl Add<(usize, usize)> for Position {
+    type Output = Self;
+
+    /// Moves the position forward by `(lines, columns)`
+    fn add(self, (lines, columns): (usize, usize)) -> Self::Output {
+        Self::new(
+            self.line.saturating_add(lines),
+            self.column.saturating_add(columns),
+        )
+    }
+}
+
+impl Sub<(usize, usize)> for Position {
+    type Output = Self;
+
+    /// Moves the position backward by `(lines, columns)`, where a line or
+    /// column that would go before the first line or column is clamped to 1
+    fn sub(self, (lines, columns): (usize, usize)) -> Self::Output {
+        Self::new(
+            self.line.saturating_sub(lines).max(Self::ORIGIN.line),
+            self.column.saturating_sub(columns).max(Self::ORIGIN.column),
+        )
+    }
+}
+
+impl<'a> From<Span<'a>> for Position {
+    /// Converts a `Span` to the position where it starts, with a base index
+    /// of 1 for both the line and column
+    fn from(span: Span<'a>) -> Self {
+        let (line, column) = span.line_and_column();
+        Self::new(line, column)
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    /// Converts from `(line, column)` to `Position { line, column }`
+    fn from((line, column): (usize, usize)) -> Self {
+        Self::new(line, column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_should_move_position_forward_by_lines_and_columns() {
+        let position = Position::new(3, 5);
+        assert_eq!(position + (0, 0), Position::new(3, 5));
+        assert_eq!(position + (2, 0), Position::new(5, 5));
+        assert_eq!(position + (0, 4), Position::new(3, 9));
+        assert_eq!(position + (1, 1), Position::new(4, 6));
+    }
+
+    #[test]
+    fn add_should_saturate_at_max() {
+        let position = Position::new(usize::MAX - 1, 1);
+        assert_eq!(position + (5, 0), Position::new(usize::MAX, 1));
+    }
+
+    #[test]
+    fn sub_should_move_position_backward_by_lines_and_columns() {
+        let position = Position::new(3, 5);
+        assert_eq!(position - (0, 0), Position::new(3, 5));
+        assert_eq!(position - (2, 0), Position::new(1, 5));
+        assert_eq!(position - (0, 4), Position::new(3, 1));
+        assert_eq!(position - (1, 1), Position::new(2, 4));
+    }
+
+    #[test]
+    fn sub_should_saturate_at_origin() {
+        let position = Position::new(3, 5);
+        assert_eq!(position - (4, 0), Position::new(1, 5));
+        assert_eq!(position - (0, 6), Position::new(3, 1));
+        assert_eq!(position - (10, 10), Position::ORIGIN);
+    }
+
+    #[test]
+    fn default_should_be_origin() {
+        assert_eq!(Position::default(), Position::ORIGIN);
+        assert_eq!(Position::ORIGIN, Position::new(1, 1));
+        assert_eq!(Position::from(Span::from("abc")), Position::ORIGIN);
+    }
+
+    #[test]
+    fn try_new_should_reject_line_or_column_of_zero() {
+        assert_eq!(Position::try_new(2, 3), Some(Position::new(2, 3)));
+        assert_eq!(Position::try_new(0, 3), None);
+        assert_eq!(Position::try_new(2, 0), None);
+    }
+
+    #[test]
+    fn translated_should_move_position_by_signed_lines_and_columns() {
+        let position = Position::new(3, 5);
+        assert_eq!(position.translated(2, -1), Position::new(5, 4));
+        assert_eq!(position.translated(-2, 3), Position::new(1, 8));
+        assert_eq!(position.translated(-5, -5), Position::ORIGIN);
+    }
+
+    #[test]
+    fn from_should_properly_convert_span_to_position() {
+        let span = Span::from("abc\ndef");
+        let position = Position::from(span.starting_at(5));
+        assert_eq!(position, Position::new(2, 2));
+    }
+}
@@ -1,11 +1,20 @@
+use super::Position;
 use crate::lang::parsers::Span;
 use serde::{Deserialize, Serialize};
-use std::ops::{Range, RangeInclusive, RangeTo, RangeToInclusive};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::{Range, RangeInclusive, RangeTo, RangeToInclusive},
+};
 
 /// Represents a region in a string or file, comprised of a start and end
-#[derive(
-    Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize,
-)]
+/// both as byte offsets and as line and column positions
+///
+/// Positions follow from the byte offsets within the same input, so only
+/// the offset, length, and depth take part in equality, hashing, and
+/// ordering. Regions constructed from byte offsets alone, such as with
+/// [`Region::new`], are positioned at the origin until given positions
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Region {
     /// Position within some byte array this region begins
     offset: usize,
@@ -17,27 +26,52 @@ pub struct Region {
     /// the current location is. This has no bearing on the actual location
     /// in a byte stream
     depth: u16,
+
+    /// Line and column where this region begins
+    start_position: Position,
+
+    /// Line and column just past the end of this region
+    end_position: Position,
 }
 
 impl Region {
     /// Constructs a new region with the given offset and length, containing
-    /// no extra information
+    /// no extra information and positioned at the origin
     pub fn new(offset: usize, len: usize) -> Self {
+        Self::new_at_depth(offset, len, 0)
+    }
+
+    /// Constructs a zero-width region at the given offset, where the region
+    /// starts and ends at the given position
+    pub fn point(offset: usize, position: Position) -> Self {
+        Self::new(offset, 0).with_positions(position, position)
+    }
+
+    /// Constructs a new region with the given offset and length and depth,
+    /// positioned at the origin
+    pub fn new_at_depth(offset: usize, len: usize, depth: u16) -> Self {
         Self {
             offset,
             len,
-            depth: 0,
+            depth,
+            start_position: Position::ORIGIN,
+            end_position: Position::ORIGIN,
         }
     }
 
-    /// Constructs a new region with the given offset and length and depth
-    pub fn new_at_depth(offset: usize, len: usize, depth: u16) -> Self {
-        Self { offset, len, depth }
-    }
-
     /// Constructs a copy of a region with set to specified depth
     pub fn with_depth(&self, depth: u16) -> Self {
-        Self::new_at_depth(self.offset, self.len, depth)
+        Self { depth, ..*self }
+    }
+
+    /// Constructs a copy of a region with set to specified start and end
+    /// positions, where the end is just past the last character
+    pub fn with_positions(&self, start: Position, end: Position) -> Self {
+        Self {
+            start_position: start,
+            end_position: end,
+            ..*self
+        }
     }
 
     /// Checks if a position is contained within this region
@@ -69,16 +103,133 @@ impl Region {
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    /// The offset just past the end of the region
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.offset + self.len
+    }
+
+    /// The line and column where the region begins
+    #[inline]
+    pub fn start_position(&self) -> Position {
+        self.start_position
+    }
+
+    /// The line and column just past the end of the region
+    #[inline]
+    pub fn end_position(&self) -> Position {
+        self.end_position
+    }
+
+    /// Constructs a copy of the region moved by the given number of bytes,
+    /// keeping its length, depth, and positions. An offset that would move
+    /// before the start of the input is clamped to zero
+    pub fn shifted(&self, delta: isize) -> Self {
+        let offset = if delta < 0 {
+            self.offset.saturating_sub(delta.unsigned_abs())
+        } else {
+            self.offset.saturating_add(delta as usize)
+        };
+        Self { offset, ..*self }
+    }
+
+    /// Constructs a copy of the region whose positions are moved by the
+    /// given number of lines and columns, keeping its byte offset and length.
+    /// Columns only move the start and an end on the same line as the start,
+    /// as text inserted before the region on its first line does not move
+    /// any later lines. Positions are clamped to the first line and column
+    pub fn translate(&self, lines: isize, columns: isize) -> Self {
+        self.translate_on_line(self.start_position.line(), lines, columns)
+    }
+
+    /// Like [`Region::translate`], but columns only move positions on the
+    /// given line, such as the line of an element containing this region
+    pub(crate) fn translate_on_line(
+        &self,
+        line: usize,
+        lines: isize,
+        columns: isize,
+    ) -> Self {
+        let columns_on = |position: Position| {
+            if position.line() == line {
+                columns
+            } else {
+                0
+            }
+        };
+
+        self.with_positions(
+            self.start_position
+                .translated(lines, columns_on(self.start_position)),
+            self.end_position
+                .translated(lines, columns_on(self.end_position)),
+        )
+    }
+
+    /// Constructs the smallest region covering both this region and the
+    /// other, including anything between them, at the shallower of their
+    /// depths
+    pub fn merge(&self, other: Region) -> Self {
+        let offset = self.offset.min(other.offset);
+        let end = self.end().max(other.end());
+        Self::new_at_depth(offset, end - offset, self.depth.min(other.depth))
+            .with_positions(
+                self.start_position.min(other.start_position),
+                self.end_position.max(other.end_position),
+            )
+    }
+}
+
+impl PartialEq for Region {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.len == other.len
+            && self.depth == other.depth
+    }
+}
+
+impl Eq for Region {}
+
+impl Hash for Region {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+        self.len.hash(state);
+        self.depth.hash(state);
+    }
+}
+
+impl PartialOrd for Region {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Region {
+    /// Orders regions by where they start and then by where they end,
+    /// placing them in document order. Depth is only compared to break ties
+    /// so that ordering stays consistent with equality
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.offset
+            .cmp(&other.offset)
+            .then_with(|| self.end().cmp(&other.end()))
+            .then_with(|| self.depth.cmp(&other.depth))
+    }
 }
 
 impl<'a> From<Span<'a>> for Region {
-    /// Converts a `Span` to a region
+    /// Converts a `Span` to a region, including the line and column where
+    /// the span starts and just past where it ends
     fn from(span: Span<'a>) -> Self {
         Self::new_at_depth(
             span.start_offset(),
             span.remaining_len(),
             span.depth(),
         )
+        .with_positions(
+            Position::from(span),
+            Position::from(span.starting_at(span.remaining_len())),
+        )
     }
 }
 
@@ -123,6 +274,15 @@ impl From<RangeToInclusive<usize>> for Region {
     }
 }
 
+impl From<(Position, Position)> for Region {
+    /// Converts from `(start, end)` positions to a region at those positions
+    /// with no byte offset or length, as positions alone do not say where
+    /// the region falls within some byte array
+    fn from((start, end): (Position, Position)) -> Self {
+        Self::default().with_positions(start, end)
+    }
+}
+
 impl From<(usize, usize)> for Region {
     /// Converts from `(start, end)` to `Region { offset: start, len: end, depth: 0 }`
     fn from(coords: (usize, usize)) -> Self {
@@ -148,6 +308,35 @@ mod tests {
         assert!(!region.contains(7));
     }
 
+    #[test]
+    fn point_should_start_and_end_at_the_same_offset_and_position() {
+        let region = Region::point(5, Position::new(2, 3));
+        assert_eq!(region.offset(), 5);
+        assert_eq!(region.end(), region.offset());
+        assert!(region.is_empty());
+        assert_eq!(region.start_position(), Position::new(2, 3));
+        assert_eq!(region.end_position(), region.start_position());
+    }
+
+    #[test]
+    fn eq_should_ignore_positions() {
+        let region = Region::new_at_depth(3, 2, 1);
+        let positioned =
+            region.with_positions(Position::new(2, 1), Position::new(2, 3));
+        assert_eq!(region, positioned);
+        assert_eq!(region.cmp(&positioned), Ordering::Equal);
+
+        let hash = |region: Region| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            region.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(region), hash(positioned));
+
+        assert_ne!(region, Region::new_at_depth(3, 2, 2));
+        assert_ne!(region, Region::new_at_depth(3, 3, 1));
+    }
+
     #[test]
     fn from_should_properly_convert_range_to_region() {
         let region = Region::from(0..3);
@@ -200,8 +389,82 @@ mod tests {
 
     #[test]
     fn from_should_properly_convert_span_to_region() {
-        let span = Span::new(&[], 3, 8, 2);
+        let span = Span::new(b"ab\ncdef\ngh", 3, 8, 2);
         let region = Region::from(span);
         assert_eq!(region, Region::new_at_depth(3, 5, 2));
+        assert_eq!(region.start_position(), Position::new(2, 1));
+        assert_eq!(region.end_position(), Position::new(3, 1));
+    }
+
+    #[test]
+    fn from_should_properly_convert_positions_to_region() {
+        let region = Region::from((Position::new(2, 3), Position::new(4, 1)));
+        assert_eq!(region.start_position(), Position::new(2, 3));
+        assert_eq!(region.end_position(), Position::new(4, 1));
+        assert_eq!(region.offset(), 0);
+        assert!(region.is_empty());
+    }
+
+    #[test]
+    fn translate_should_move_positions_by_lines_and_columns() {
+        let region = Region::new(4, 10)
+            .with_positions(Position::new(2, 3), Position::new(2, 8));
+        let translated = region.translate(2, 4);
+        assert_eq!(translated.start_position(), Position::new(4, 7));
+        assert_eq!(translated.end_position(), Position::new(4, 12));
+        assert_eq!(translated.offset(), 4);
+        assert_eq!(translated.len(), 10);
+
+        // Columns only move an end on the same line as the start
+        let region = Region::from((Position::new(2, 3), Position::new(3, 2)));
+        let translated = region.translate(-1, 2);
+        assert_eq!(translated.start_position(), Position::new(1, 5));
+        assert_eq!(translated.end_position(), Position::new(2, 2));
+
+        // Positions are clamped to the first line and column
+        let translated = region.translate(-5, -5);
+        assert_eq!(translated.start_position(), Position::ORIGIN);
+        assert_eq!(translated.end_position(), Position::new(1, 2));
+    }
+
+    #[test]
+    fn merge_should_cover_both_regions() {
+        let region = Region::new(2, 3).merge(Region::new(8, 2));
+        assert_eq!(region, Region::new(2, 8));
+
+        let region = Region::new(8, 2).merge(Region::new(2, 3));
+        assert_eq!(region, Region::new(2, 8));
+
+        let region = Region::new(2, 10).merge(Region::new(4, 2));
+        assert_eq!(region, Region::new(2, 10));
+
+        let region =
+            Region::new_at_depth(0, 1, 3).merge(Region::new_at_depth(1, 1, 1));
+        assert_eq!(region, Region::new_at_depth(0, 2, 1));
+    }
+
+    #[test]
+    fn sort_should_order_regions_by_start_then_end() {
+        let mut regions = vec![
+            Region::new(8, 2),
+            Region::new(0, 5),
+            Region::new(3, 1),
+            Region::new(0, 2),
+            Region::new_at_depth(3, 1, 1),
+            Region::new(12, 0),
+        ];
+        regions.sort();
+
+        assert_eq!(
+            regions,
+            vec![
+                Region::new(0, 2),
+                Region::new(0, 5),
+                Region::new(3, 1),
+                Region::new_at_depth(3, 1, 1),
+                Region::new(8, 2),
+                Region::new(12, 0),
+            ]
+        );
     }
 }
@@ -1,9 +1,17 @@
 pub mod elements;
+pub mod output;
 pub mod parsers;
 
 use derive_more::Display;
 use elements::*;
-use parsers::{vimwiki, IResult, Span};
+use parsers::{
+    vimwiki, IResult, ParseConfig, ParseDiagnostic, ParseDiagnosticKind,
+    ParseState, Span,
+};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
 /// Parse a value from a `Language`
 pub trait FromLanguage<'a>: Sized {
@@ -11,6 +19,15 @@ pub trait FromLanguage<'a>: Sized {
 
     /// Parses a `Language` to return a value of this type
     fn from_language(language: Language<'a>) -> Result<Self, Self::Error>;
+
+    /// Parses a `Language` to return a value of this type, using the given
+    /// state to configure the parser and collect diagnostics
+    fn from_language_with_state(
+        language: Language<'a>,
+        _state: &'a ParseState,
+    ) -> Result<Self, Self::Error> {
+        Self::from_language(language)
+    }
 }
 
 /// Represents a raw, unparsed representation of some language
@@ -43,17 +60,65 @@ pub enum Language<'a> {
 impl<'a> Language<'a> {
     /// Wraps provided `&str` as a `Language` for *vimwiki*
     pub fn from_vimwiki_str(inner: &'a str) -> Self {
-        Self::Vimwiki(inner)
+        Self::Vimwiki(strip_bom(inner))
     }
 
     /// Wraps provided `&str` as a `Language` for *markdown*
     pub fn from_markdown_str(inner: &'a str) -> Self {
-        Self::Markdown(inner)
+        Self::Markdown(strip_bom(inner))
     }
 
     /// Wraps provided `&str` as a `Language` for *mediawiki*
     pub fn from_mediawiki_str(inner: &'a str) -> Self {
-        Self::Mediawiki(inner)
+        Self::Mediawiki(strip_bom(inner))
+    }
+
+    /// Guesses the format of the source by tallying syntax that is specific
+    /// to vimwiki (`[[links]]`, `= headers =`) or to markdown
+    /// (`[links](url)`, `# headers`, code fences), wrapping the source as
+    /// whichever scores higher and defaulting to vimwiki on a tie
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use vimwiki::Language;
+    ///
+    /// assert!(Language::detect("= Header =\n[[link]]").is_vimwiki());
+    /// assert!(Language::detect("# Header\n[link](url)").is_markdown());
+    /// ```
+    pub fn detect(source: &'a str) -> Self {
+        let mut vimwiki = 0;
+        let mut markdown = 0;
+
+        for line in source.lines() {
+            let line = line.trim();
+
+            vimwiki += line.matches("[[").count();
+            markdown += line.matches("](").count();
+
+            let level = line.chars().take_while(|c| *c == '=').count();
+            if level > 0
+                && line.len() > level * 2
+                && line.ends_with(&line[..level])
+            {
+                vimwiki += 1;
+            }
+
+            let level = line.chars().take_while(|c| *c == '#').count();
+            if level > 0 && line[level..].starts_with(' ') {
+                markdown += 1;
+            }
+
+            if line.starts_with("```") {
+                markdown += 1;
+            }
+        }
+
+        if markdown > vimwiki {
+            Self::from_markdown_str(source)
+        } else {
+            Self::from_vimwiki_str(source)
+        }
     }
 
     /// Whether or not this represents a vimwiki format
@@ -83,6 +148,297 @@ impl<'a> Language<'a> {
     pub fn parse<F: FromLanguage<'a>>(&self) -> Result<F, F::Error> {
         FromLanguage::from_language(*self)
     }
+
+    /// Borrows this language and parses it into another type, using the
+    /// given state to configure the parser and collect diagnostics
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use vimwiki::{Language, Page, ParseConfig, ParseState};
+    ///
+    /// let state = ParseState::new(ParseConfig {
+    ///     max_nesting_depth: 8,
+    ///     ..Default::default()
+    /// });
+    /// let language = Language::from_vimwiki_str("*bold _italic_*");
+    /// let page: Page = language.parse_with_state(&state).unwrap();
+    /// assert!(state.diagnostics().is_empty());
+    /// ```
+    pub fn parse_with_state<'b, F: FromLanguage<'b>>(
+        &self,
+        state: &'b ParseState,
+    ) -> Result<F, F::Error>
+    where
+        'a: 'b,
+    {
+        FromLanguage::from_language_with_state(*self, state)
+    }
+
+    /// Borrows this language and parses it into a page, replacing each
+    /// paragraph that contains nothing but a transclusion link with the
+    /// blocks of the page that the resolver returns for the link's uri.
+    ///
+    /// The given state configures the parser of this page and of every
+    /// transcluded page, and collects the diagnostics of this page. Each
+    /// transcluded page is parsed with a state of its own sharing the same
+    /// configuration, so that its diagnostics and reference definitions do
+    /// not mix with those of this page.
+    ///
+    /// Transcluded pages are resolved recursively. A transclusion that would
+    /// form a cycle, that the resolver does not provide, or whose content
+    /// fails to parse is left in place. Blocks taken from a transcluded
+    /// page keep their regions relative to that page's content
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use vimwiki::{BlockElement, Language, Page, ParseState};
+    ///
+    /// let language = Language::from_vimwiki_str("{{file:other.wiki}}");
+    /// let page: Page = language
+    ///     .parse_with_resolver(&ParseState::default(), |uri| match uri {
+    ///         "file:other.wiki" => Some(String::from("= Other =")),
+    ///         _ => None,
+    ///     })
+    ///     .unwrap();
+    /// assert!(matches!(page.elements[0].as_inner(), BlockElement::Header(_)));
+    /// ```
+    pub fn parse_with_resolver<'b>(
+        &self,
+        state: &'b ParseState,
+        resolver: impl Fn(&str) -> Option<String>,
+    ) -> Result<Page<'static>, parsers::Error<'b>>
+    where
+        'a: 'b,
+    {
+        let page: Page<'b> = self.parse_with_state(state)?;
+        Ok(resolve_transclusions(
+            page,
+            self.constructor(),
+            *state.config(),
+            &resolver,
+            &mut Vec::new(),
+        ))
+    }
+
+    /// Returns a function that creates a language of the same kind as this
+    /// one from other content, such as that of a transcluded or included
+    /// page
+    fn constructor(&self) -> fn(&str) -> Language {
+        match self {
+            Self::Vimwiki(_) => |s| Language::from_vimwiki_str(s),
+            Self::Markdown(_) => |s| Language::from_markdown_str(s),
+            Self::Mediawiki(_) => |s| Language::from_mediawiki_str(s),
+        }
+    }
+
+    /// Borrows this language as the content of the file at `root` and
+    /// parses it into a page, replacing each `#include path` directive with
+    /// the blocks of the file that the resolver returns for the path. Paths
+    /// are relative to the directory of the file containing the directive.
+    ///
+    /// Included files are resolved recursively, keeping a set of the files
+    /// being included so that a directive that would include one of them
+    /// again is left in place and reported as a cycle. A directive whose
+    /// file the resolver does not provide or that fails to parse is also
+    /// left in place and reported. Blocks taken from an included file have
+    /// their regions shifted to start at the directive they replace and are
+    /// tagged with the path of the file they came from
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use vimwiki::{BlockElement, Language};
+    ///
+    /// let language = Language::from_vimwiki_str("#include other.wiki");
+    /// let included = language
+    ///     .parse_with_includes("wiki/index.wiki", |path| {
+    ///         if path == Path::new("wiki/other.wiki") {
+    ///             Some(String::from("= Other ="))
+    ///         } else {
+    ///             None
+    ///         }
+    ///     })
+    ///     .unwrap();
+    /// assert!(matches!(
+    ///     included.page.elements[0].as_inner(),
+    ///     BlockElement::Header(_),
+    /// ));
+    /// assert_eq!(included.sources[0], Path::new("wiki/other.wiki"));
+    /// ```
+    pub fn parse_with_includes(
+        &self,
+        root: impl AsRef<Path>,
+        resolver: impl Fn(&Path) -> Option<String>,
+    ) -> Result<IncludedPage, parsers::Error<'a>> {
+        let root = root.as_ref();
+        let mut visited = HashSet::new();
+        visited.insert(root.to_path_buf());
+
+        let page: Page<'a> = self.parse()?;
+        Ok(resolve_includes(
+            page,
+            root,
+            self.constructor(),
+            &resolver,
+            &mut visited,
+        ))
+    }
+}
+
+/// Represents a page whose includes have been resolved
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IncludedPage {
+    /// Page containing the blocks of the root file and of every file that
+    /// was included
+    pub page: Page<'static>,
+
+    /// Path of the file that each block of the page came from, in the same
+    /// order as the elements of the page
+    pub sources: Vec<PathBuf>,
+
+    /// Issues encountered while resolving includes
+    pub diagnostics: Vec<ParseDiagnostic>,
+}
+
+/// Replaces includes within a page with the blocks of the files they
+/// reference, skipping any path already being resolved to avoid cycles
+fn resolve_includes(
+    page: Page<'_>,
+    path: &Path,
+    language: fn(&str) -> Language,
+    resolver: &dyn Fn(&Path) -> Option<String>,
+    visited: &mut HashSet<PathBuf>,
+) -> IncludedPage {
+    let mut elements = Vec::new();
+    let mut sources = Vec::new();
+    let mut diagnostics = Vec::new();
+
+    for element in page.into_elements() {
+        let target = match element.as_inner() {
+            BlockElement::Placeholder(Placeholder::Include(x)) => path
+                .parent()
+                .unwrap_or_else(|| Path::new(""))
+                .join(x.as_ref()),
+            _ => {
+                elements.push(element.map(BlockElement::into_owned));
+                sources.push(path.to_path_buf());
+                continue;
+            }
+        };
+
+        let included = if visited.contains(&target) {
+            Err(ParseDiagnosticKind::IncludeCycle)
+        } else {
+            resolver(&target)
+                .and_then(|text| {
+                    let page: Page = language(&text).parse().ok()?;
+
+                    visited.insert(target.clone());
+                    let included = resolve_includes(
+                        page, &target, language, resolver, visited,
+                    );
+                    visited.remove(&target);
+
+                    Some(included)
+                })
+                .ok_or(ParseDiagnosticKind::IncludeUnresolved)
+        };
+
+        match included {
+            Ok(included) => {
+                let delta = element.region().offset() as isize;
+                let lines =
+                    element.region().start_position().line() as isize - 1;
+                for mut element in included.page.into_elements() {
+                    element.shift_lines(delta, lines);
+                    elements.push(element);
+                }
+                sources.extend(included.sources);
+                diagnostics.extend(included.diagnostics.into_iter().map(|x| {
+                    let region = x.region().shifted(delta).translate(lines, 0);
+                    ParseDiagnostic::new(x.kind(), region)
+                }));
+            }
+            Err(kind) => {
+                diagnostics.push(ParseDiagnostic::new(kind, element.region()));
+                elements.push(element.map(BlockElement::into_owned));
+                sources.push(path.to_path_buf());
+            }
+        }
+    }
+
+    IncludedPage {
+        page: Page::new(elements),
+        sources,
+        diagnostics,
+    }
+}
+
+/// Replaces transclusions within a page with the blocks of the pages they
+/// reference, skipping any uri already being resolved to avoid cycles
+fn resolve_transclusions(
+    page: Page<'_>,
+    language: fn(&str) -> Language,
+    config: ParseConfig,
+    resolver: &dyn Fn(&str) -> Option<String>,
+    stack: &mut Vec<String>,
+) -> Page<'static> {
+    let mut elements = Vec::new();
+
+    for element in page.into_elements() {
+        let sub_page = transclusion_uri(element.as_inner())
+            .filter(|uri| !stack.contains(uri))
+            .and_then(|uri| {
+                let text = resolver(&uri)?;
+                let state = ParseState::new(config);
+                let page: Page =
+                    language(&text).parse_with_state(&state).ok()?;
+
+                stack.push(uri);
+                let page = resolve_transclusions(
+                    page, language, config, resolver, stack,
+                );
+                stack.pop();
+
+                Some(page)
+            });
+
+        match sub_page {
+            Some(page) => elements.extend(page.into_elements()),
+            None => elements.push(element.map(BlockElement::into_owned)),
+        }
+    }
+
+    Page::new(elements)
+}
+
+/// Returns the uri of the transclusion link if the element is a paragraph
+/// containing only that link and whitespace
+fn transclusion_uri(element: &BlockElement) -> Option<String> {
+    let paragraph = match element {
+        BlockElement::Paragraph(x) => x,
+        _ => return None,
+    };
+
+    let mut elements = paragraph.content.elements.iter().filter(|x| {
+        !matches!(x.as_inner(), InlineElement::Text(t) if t.0.trim().is_empty())
+    });
+
+    match (elements.next().map(|x| x.as_inner()), elements.next()) {
+        (Some(InlineElement::Link(Link::Transclusion(x))), None) => {
+            Some(x.uri.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Removes a leading UTF-8 byte order mark so positions are relative to the
+/// content that follows it
+fn strip_bom(input: &str) -> &str {
+    input.strip_prefix('\u{feff}').unwrap_or(input)
 }
 
 macro_rules! impl_from_language {
@@ -96,6 +452,18 @@ macro_rules! impl_from_language {
                     _ => Err(parsers::Error::unsupported()),
                 }
             }
+
+            fn from_language_with_state(
+                l: Language<'a>,
+                state: &'a ParseState,
+            ) -> Result<Self, Self::Error> {
+                match l {
+                    Language::Vimwiki(x) => {
+                        Ok($f(Span::from(x).with_state(state))?.1)
+                    }
+                    _ => Err(parsers::Error::unsupported()),
+                }
+            }
         }
     };
 }
@@ -103,6 +471,10 @@ macro_rules! impl_from_language {
 // Top-level types
 impl_from_language!(Page<'a>, vimwiki::page);
 impl_from_language!(Located<BlockElement<'a>>, vimwiki::blocks::block_element);
+impl_from_language!(
+    Retained<'a, Located<BlockElement<'a>>>,
+    vimwiki::blocks::retained_block_element
+);
 impl_from_language!(
     Located<InlineElementContainer<'a>>,
     vimwiki::blocks::inline::inline_element_container
@@ -149,6 +521,16 @@ impl_from_language!(
 // Dividers
 impl_from_language!(Located<Divider>, vimwiki::blocks::dividers::divider);
 
+// Footnotes
+impl_from_language!(
+    Located<FootnoteDef<'a>>,
+    vimwiki::blocks::footnotes::footnote_def
+);
+impl_from_language!(
+    Located<FootnoteRef<'a>>,
+    vimwiki::blocks::inline::footnotes::footnote_ref
+);
+
 // Headers
 impl_from_language!(Located<Header<'a>>, vimwiki::blocks::headers::header);
 
@@ -166,6 +548,10 @@ impl_from_language!(
     Located<RawLink<'a>>,
     vimwiki::blocks::inline::links::raw::raw_link
 );
+impl_from_language!(
+    Located<ReferenceLink<'a>>,
+    vimwiki::blocks::inline::links::reference::reference_link
+);
 impl_from_language!(
     Located<TransclusionLink<'a>>,
     vimwiki::blocks::inline::links::transclusion::transclusion_link
@@ -214,6 +600,12 @@ impl_from_language!(
     vimwiki::blocks::preformatted::preformatted_text
 );
 
+// Signatures
+impl_from_language!(
+    Located<Signature<'a>>,
+    vimwiki::blocks::signatures::signature
+);
+
 // Tables
 impl_from_language!(Located<Table<'a>>, vimwiki::blocks::tables::table);
 
@@ -238,6 +630,24 @@ impl_from_language!(
 mod tests {
     use super::*;
 
+    #[test]
+    fn detect_should_classify_vimwiki_source() {
+        let source = "= Header =\n\nSee [[other page]] and [[#anchor]]\n";
+        assert_eq!(Language::detect(source), Language::Vimwiki(source));
+    }
+
+    #[test]
+    fn detect_should_classify_markdown_source() {
+        let source = "# Header\n\nSee [other](page.md)\n\n```\ncode\n```\n";
+        assert_eq!(Language::detect(source), Language::Markdown(source));
+    }
+
+    #[test]
+    fn detect_should_default_to_vimwiki_if_ambiguous() {
+        let source = "some plain text";
+        assert_eq!(Language::detect(source), Language::Vimwiki(source));
+    }
+
     /// Contains tests for the vimwiki language parsers
     mod vimwiki {
         use super::*;
@@ -248,6 +658,395 @@ mod tests {
             let _result: Page = input.parse().expect("Failed to parse");
         }
 
+        #[test]
+        fn parse_should_ignore_leading_byte_order_mark() {
+            let input = Language::from_vimwiki_str("\u{feff}= Header =\n");
+            let page: Page = input.parse().expect("Failed to parse");
+
+            let element = &page.elements[0];
+            assert!(
+                matches!(element.as_inner(), BlockElement::Header(_)),
+                "Unexpected element: {:?}",
+                element
+            );
+            assert_eq!(element.region().offset(), 0);
+
+            // Positions count from the content after the byte order mark
+            let start = element.region().start_position();
+            assert_eq!((start.line(), start.column()), (1, 1));
+            let content = match element.as_inner() {
+                BlockElement::Header(x) => &x.content,
+                _ => unreachable!(),
+            };
+            let start = content.elements[0].region().start_position();
+            assert_eq!((start.line(), start.column()), (1, 3));
+        }
+
+        #[test]
+        fn parse_with_state_should_report_exceeding_max_nesting_depth() {
+            let state = ParseState::new(parsers::ParseConfig {
+                max_nesting_depth: 3,
+                ..Default::default()
+            });
+            let input = Language::from_vimwiki_str("*_~~^,,text,,^~~_*");
+            let page: Page =
+                input.parse_with_state(&state).expect("Failed to parse");
+            assert_eq!(page.elements.len(), 1);
+
+            let diagnostics = state.into_diagnostics();
+            assert!(!diagnostics.is_empty(), "Missing depth diagnostic");
+            assert!(diagnostics.iter().all(|d| d.kind()
+                == parsers::ParseDiagnosticKind::MaxNestingDepthExceeded));
+        }
+
+        #[test]
+        fn parse_with_state_should_report_deeply_nested_lists_without_overflowing(
+        ) {
+            let text: String = (0..2000)
+                .map(|i| format!("{}- item {}\n", " ".repeat(i), i))
+                .collect();
+            let state = ParseState::default();
+            let page: Page = Language::from_vimwiki_str(&text)
+                .parse_with_state(&state)
+                .expect("Failed to parse");
+            assert_eq!(page.elements.len(), 1);
+
+            let diagnostics = state.into_diagnostics();
+            assert!(!diagnostics.is_empty(), "Missing depth diagnostic");
+            assert!(diagnostics.iter().all(|d| d.kind()
+                == parsers::ParseDiagnosticKind::MaxNestingDepthExceeded));
+        }
+
+        #[test]
+        fn parse_with_state_should_parse_signature_ending_page_if_configured() {
+            let state = ParseState::new(parsers::ParseConfig {
+                parse_signatures: true,
+                ..Default::default()
+            });
+            let input = Language::from_vimwiki_str(
+                "some text\n-- Author, 2021-03-04\n",
+            );
+            let page: Page =
+                input.parse_with_state(&state).expect("Failed to parse");
+
+            assert_eq!(page.elements.len(), 2);
+            assert_eq!(
+                page.elements[1].as_inner(),
+                &BlockElement::from(Signature::from("Author, 2021-03-04"))
+            );
+
+            // Without the option, the line continues the paragraph
+            let page: Page = input.parse().expect("Failed to parse");
+            assert_eq!(page.elements.len(), 1);
+        }
+
+        #[test]
+        fn parse_with_state_should_parse_hashtags_within_text_if_configured() {
+            let state = ParseState::new(parsers::ParseConfig {
+                parse_hashtags: true,
+                ..Default::default()
+            });
+            let input = Language::from_vimwiki_str(
+                "see #project/sub here\n\n# Heading\n\n#Heading\n",
+            );
+            let page: Page =
+                input.parse_with_state(&state).expect("Failed to parse");
+
+            let html = page.to_html(&crate::HtmlConfig::default());
+            assert_eq!(
+                html.matches("class=\"hashtag\"").count(),
+                1,
+                "{}",
+                html
+            );
+            assert!(
+                html.contains("<span class=\"hashtag\">#project/sub</span>"),
+                "Missing hashtag: {}",
+                html
+            );
+
+            // Without the option, hashtags are left as text
+            let page: Page = input.parse().expect("Failed to parse");
+            let html = page.to_html(&crate::HtmlConfig::default());
+            assert!(!html.contains("hashtag"), "Unexpected hashtag: {}", html);
+        }
+
+        #[test]
+        fn parse_with_state_should_autolink_bare_urls_if_configured() {
+            let state = ParseState::new(parsers::ParseConfig {
+                autolink_urls: true,
+                ..Default::default()
+            });
+            let input = Language::from_vimwiki_str(
+                "see https://example.com/page. or xhttp://example.com\n",
+            );
+            let page: Page =
+                input.parse_with_state(&state).expect("Failed to parse");
+
+            let html = page.to_html(&crate::HtmlConfig::default());
+            assert_eq!(html.matches("<a ").count(), 1, "{}", html);
+            assert!(
+                html.contains(
+                    "see <a href=\"https://example.com/page\">\
+                     https://example.com/page</a>. or xhttp://example.com"
+                ),
+                "Missing link: {}",
+                html
+            );
+
+            // Without the option, bare urls are left as text
+            let page: Page = input.parse().expect("Failed to parse");
+            let html = page.to_html(&crate::HtmlConfig::default());
+            assert!(!html.contains("<a "), "Unexpected link: {}", html);
+        }
+
+        #[test]
+        fn parse_with_state_should_resolve_reference_links_if_configured() {
+            let state = ParseState::new(parsers::ParseConfig {
+                reference_links: true,
+                ..Default::default()
+            });
+            let input = Language::from_vimwiki_str(
+                "see [docs][1] and [other][missing]\n\n[1]: https://example.com\n",
+            );
+            let page: Page =
+                input.parse_with_state(&state).expect("Failed to parse");
+
+            let html = page.to_html(&crate::HtmlConfig::default());
+            assert!(
+                html.contains(
+                    "see <a href=\"https://example.com\">docs</a> \
+                     and [other][missing]"
+                ),
+                "Missing link: {}",
+                html
+            );
+            assert_eq!(
+                page.elements[1].as_inner(),
+                &BlockElement::from(Placeholder::link_reference_from_str(
+                    "1",
+                    "https://example.com"
+                ))
+            );
+
+            // Undefined references are kept as text and reported
+            let diagnostics: Vec<_> = state
+                .into_diagnostics()
+                .into_iter()
+                .map(|d| (d.kind(), d.region().offset(), d.region().len()))
+                .collect();
+            assert_eq!(
+                diagnostics,
+                vec![(ParseDiagnosticKind::UndefinedReference, 18, 16)]
+            );
+
+            // Without the option, reference links are left as text
+            let page: Page = input.parse().expect("Failed to parse");
+            let html = page.to_html(&crate::HtmlConfig::default());
+            assert!(
+                html.contains("see [docs][1]"),
+                "Unexpected link: {}",
+                html
+            );
+        }
+
+        #[test]
+        fn parse_with_state_should_parse_indented_code_blocks_if_configured() {
+            let state = ParseState::new(parsers::ParseConfig {
+                indented_code_blocks: true,
+                ..Default::default()
+            });
+            let input = Language::from_vimwiki_str(
+                "some text\n    continued\n\n    let x = 1;\n\n        x + 1\n",
+            );
+            let page: Page =
+                input.parse_with_state(&state).expect("Failed to parse");
+
+            assert_eq!(page.elements.len(), 2, "{:?}", page.elements);
+            assert!(matches!(
+                page.elements[0].as_inner(),
+                BlockElement::Paragraph(_)
+            ));
+            assert_eq!(
+                page.elements[1].as_inner(),
+                &BlockElement::from(PreformattedText::new(
+                    None,
+                    Default::default(),
+                    vec!["let x = 1;".into(), "".into(), "    x + 1".into()],
+                ))
+            );
+
+            // Without the option, indented lines are blockquotes
+            let page: Page = input.parse().expect("Failed to parse");
+            assert!(matches!(
+                page.elements[1].as_inner(),
+                BlockElement::Blockquote(_)
+            ));
+        }
+
+        #[test]
+        fn parse_with_state_should_not_mistake_divider_for_signature() {
+            let state = ParseState::new(parsers::ParseConfig {
+                parse_signatures: true,
+                ..Default::default()
+            });
+            let input = Language::from_vimwiki_str("some text\n----\n");
+            let page: Page =
+                input.parse_with_state(&state).expect("Failed to parse");
+
+            assert_eq!(page.elements.len(), 2);
+            assert_eq!(
+                page.elements[1].as_inner(),
+                &BlockElement::Divider(Divider)
+            );
+        }
+
+        #[test]
+        fn parse_with_state_should_retain_raw_text_of_block_if_configured() {
+            let text = "  =  Some   Header  =  ";
+            let state = ParseState::new(parsers::ParseConfig {
+                retain_raw: true,
+                ..Default::default()
+            });
+            let input = Language::from_vimwiki_str(text);
+            let block: Retained<Located<BlockElement>> =
+                input.parse_with_state(&state).expect("Failed to parse");
+            assert!(
+                matches!(block.as_inner().as_inner(), BlockElement::Header(_)),
+                "Unexpected element: {:?}",
+                block
+            );
+            assert_eq!(block.raw(), Some(text));
+
+            // Retaining raw text should not impact equality
+            let block_without_raw: Retained<Located<BlockElement>> =
+                input.parse().expect("Failed to parse");
+            assert_eq!(block_without_raw.raw(), None);
+            assert_eq!(block, block_without_raw);
+        }
+
+        #[test]
+        fn parse_with_resolver_should_splice_blocks_of_transcluded_page() {
+            let input = Language::from_vimwiki_str("= A =\n{{file:b.wiki}}\n");
+            let page = input
+                .parse_with_resolver(&ParseState::default(), |uri| match uri {
+                    "file:b.wiki" => Some(String::from("= B =\nfrom b\n")),
+                    _ => None,
+                })
+                .expect("Failed to parse");
+
+            let expected: Page =
+                Language::from_vimwiki_str("= A =\n= B =\nfrom b\n")
+                    .parse()
+                    .expect("Failed to parse");
+            assert_eq!(page, expected);
+        }
+
+        #[test]
+        fn parse_with_resolver_should_parse_transcluded_pages_with_config() {
+            let state = ParseState::new(parsers::ParseConfig {
+                autolink_urls: true,
+                ..Default::default()
+            });
+            let input = Language::from_vimwiki_str(
+                "see https://a.com\n\n{{file:b.wiki}}\n",
+            );
+            let page = input
+                .parse_with_resolver(&state, |uri| match uri {
+                    "file:b.wiki" => Some(String::from("see https://b.com\n")),
+                    _ => None,
+                })
+                .expect("Failed to parse");
+
+            let expected: Page = Language::from_vimwiki_str(
+                "see https://a.com\n\nsee https://b.com\n",
+            )
+            .parse_with_state(&state)
+            .expect("Failed to parse");
+            assert_eq!(page.elements.len(), 2);
+            assert_eq!(page.links().count(), 2);
+            assert_eq!(page, expected);
+        }
+
+        #[test]
+        fn parse_with_resolver_should_stop_at_cycles() {
+            let input = Language::from_vimwiki_str("{{file:b.wiki}}");
+            let page = input
+                .parse_with_resolver(&ParseState::default(), |uri| match uri {
+                    "file:a.wiki" => Some(String::from("{{file:b.wiki}}")),
+                    "file:b.wiki" => Some(String::from("{{file:a.wiki}}")),
+                    _ => None,
+                })
+                .expect("Failed to parse");
+
+            // Resolving b leads to a, which leads back to b and is kept as is
+            let expected: Page = Language::from_vimwiki_str("{{file:b.wiki}}")
+                .parse()
+                .expect("Failed to parse");
+            assert_eq!(page, expected);
+        }
+
+        #[test]
+        fn parse_with_includes_should_splice_blocks_of_included_file() {
+            let input =
+                Language::from_vimwiki_str("= A =\n#include b.wiki\n\nafter\n");
+            let included = input
+                .parse_with_includes("wiki/a.wiki", |path| {
+                    if path == Path::new("wiki/b.wiki") {
+                        Some(String::from("= B =\nfrom b\n"))
+                    } else {
+                        None
+                    }
+                })
+                .expect("Failed to parse");
+
+            let expected: Page =
+                Language::from_vimwiki_str("= A =\n= B =\nfrom b\n\nafter\n")
+                    .parse()
+                    .expect("Failed to parse");
+            assert_eq!(included.page, expected);
+            assert_eq!(
+                included.sources,
+                vec![
+                    PathBuf::from("wiki/a.wiki"),
+                    PathBuf::from("wiki/b.wiki"),
+                    PathBuf::from("wiki/b.wiki"),
+                    PathBuf::from("wiki/a.wiki"),
+                ]
+            );
+            assert!(included.diagnostics.is_empty());
+
+            // Included blocks are shifted to start at the directive
+            assert_eq!(included.page.elements[1].region().offset(), 6);
+            assert_eq!(included.page.elements[2].region().offset(), 12);
+        }
+
+        #[test]
+        fn parse_with_includes_should_report_cycles() {
+            let text = "#include a.wiki\nsome text\n";
+            let input = Language::from_vimwiki_str(text);
+            let included = input
+                .parse_with_includes("a.wiki", |path| {
+                    if path == Path::new("a.wiki") {
+                        Some(String::from(text))
+                    } else {
+                        None
+                    }
+                })
+                .expect("Failed to parse");
+
+            // Including a from a leads back to a and is kept as is
+            let expected: Page = input.parse().expect("Failed to parse");
+            assert_eq!(included.page, expected);
+            assert_eq!(
+                included.diagnostics,
+                vec![ParseDiagnostic::new(
+                    ParseDiagnosticKind::IncludeCycle,
+                    included.page.elements[0].region(),
+                )]
+            );
+        }
+
         #[test]
         fn parse_to_located_block_element() {
             let input = Language::from_vimwiki_str("some text");
@@ -0,0 +1,1170 @@
+use super::{push_str_with_newline, Newline};
+use crate::lang::elements::*;
+use std::fmt::{self, Write};
+
+/// Represents configuration options when rendering HTML
+#[derive(Clone, Debug, Default)]
+pub struct HtmlConfig {
+    /// Line ending written between lines of output
+    pub newline: Newline,
+
+    /// Algorithm used to turn header text into the ids of headers and the
+    /// anchors of links pointing at them
+    pub slugger: SlugStyle,
+
+    /// If true, headers end with a `¶` link to their own id so readers can
+    /// copy a link to the section
+    pub header_permalinks: bool,
+
+    /// If true, straight quotes within text are rendered as curly quotes and
+    /// `--`/`---` as en/em dashes, leaving code and links untouched
+    pub smart_typography: bool,
+
+    /// If true, runs of whitespace within text are rendered as a single
+    /// space, leaving code and preformatted text untouched
+    pub collapse_whitespace: bool,
+
+    /// If provided, keywords are rendered with this class along with the
+    /// class made from it and the lowercased keyword, such as `kw kw-todo`
+    /// for a prefix of `kw`, instead of the `todo` class
+    pub keyword_class_prefix: Option<String>,
+}
+
+/// Represents the algorithm used to produce the slug of a header
+#[derive(Copy, Clone, Debug, Default)]
+pub enum SlugStyle {
+    /// Uses the header text as-is with surrounding whitespace removed
+    #[default]
+    Vimwiki,
+
+    /// Lowercases the header text, strips punctuation, and collapses
+    /// whitespace into single dashes
+    GitHub,
+
+    /// Uses the provided function to produce the slug
+    Custom(fn(&str) -> String),
+}
+
+impl SlugStyle {
+    /// Produces the slug of the given header text
+    pub fn slugify(&self, text: &str) -> String {
+        match self {
+            Self::Vimwiki => text.trim().to_string(),
+            Self::GitHub => text
+                .split_whitespace()
+                .map(|word| {
+                    word.chars()
+                        .filter(|c| {
+                            c.is_alphanumeric() || *c == '-' || *c == '_'
+                        })
+                        .flat_map(char::to_lowercase)
+                        .collect::<String>()
+                })
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join("-"),
+            Self::Custom(f) => f(text),
+        }
+    }
+}
+
+/// Represents the destination of HTML output, tracking the configuration
+/// used while rendering
+pub struct HtmlFormatter<'a> {
+    config: &'a HtmlConfig,
+    content: String,
+    within_link: bool,
+
+    /// Whether a straight quote written next with smart typography opens
+    /// rather than closes, carried across the inline elements of a block
+    quote_opens: bool,
+}
+
+impl<'a> HtmlFormatter<'a> {
+    pub fn new(config: &'a HtmlConfig) -> Self {
+        Self {
+            config,
+            content: String::new(),
+            within_link: false,
+            quote_opens: true,
+        }
+    }
+
+    /// Returns the configuration used by this formatter
+    pub fn config(&self) -> &HtmlConfig {
+        self.config
+    }
+
+    /// Returns the HTML written so far
+    pub fn as_content(&self) -> &str {
+        &self.content
+    }
+
+    /// Consumes the formatter, returning the HTML that was written
+    pub fn into_content(self) -> String {
+        self.content
+    }
+
+    /// Returns the line (base index of 1) where the next write will begin
+    pub fn line(&self) -> usize {
+        self.content.matches('\n').count() + 1
+    }
+
+    /// Writes text, escaping characters that have meaning within HTML
+    pub fn write_escaped(&mut self, text: &str) -> fmt::Result {
+        for c in text.chars() {
+            match c {
+                '&' => self.content.push_str("&amp;"),
+                '<' => self.content.push_str("&lt;"),
+                '>' => self.content.push_str("&gt;"),
+                '"' => self.content.push_str("&quot;"),
+                '\'' => self.content.push_str("&#39;"),
+                '\n' => self.write_str("\n")?,
+                _ => self.content.push(c),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Write for HtmlFormatter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        push_str_with_newline(&mut self.content, s, self.config.newline);
+        Ok(())
+    }
+}
+
+/// Represents an element that can be rendered as HTML
+pub trait Html {
+    /// Writes the HTML representation of this element to the formatter
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result;
+}
+
+/// Convenience trait to render an element into a standalone string of HTML
+pub trait ToHtmlString {
+    fn to_html_string(&self, config: &HtmlConfig) -> String;
+}
+
+impl<T: Html> ToHtmlString for T {
+    fn to_html_string(&self, config: &HtmlConfig) -> String {
+        let mut f = HtmlFormatter::new(config);
+
+        // NOTE: Writing to a string buffer never fails
+        let _ = self.fmt_html(&mut f);
+        f.into_content()
+    }
+}
+
+impl<T: Html> Html for Located<T> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        self.as_inner().fmt_html(f)
+    }
+}
+
+impl Page<'_> {
+    /// Renders the page as HTML
+    pub fn to_html(&self, config: &HtmlConfig) -> String {
+        self.to_html_string(config)
+    }
+
+    /// Renders the page as HTML alongside a sourcemap that associates the
+    /// output line (base index of 1) where each rendered block starts with
+    /// the region of the block in the original source
+    pub fn to_html_with_sourcemap(
+        &self,
+        config: &HtmlConfig,
+    ) -> (String, Vec<(usize, Region)>) {
+        let mut f = HtmlFormatter::new(config);
+        let mut sourcemap = Vec::new();
+
+        // NOTE: Writing to a string buffer never fails
+        let _ = fmt_blocks(self.elements(), &mut f, |line, region| {
+            sourcemap.push((line, region))
+        });
+
+        (f.into_content(), sourcemap)
+    }
+}
+
+impl Html for Page<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        fmt_blocks(self.elements(), f, |_, _| {})
+    }
+}
+
+/// Writes each block on its own line(s), skipping blocks that produce no
+/// output and reporting the starting line and region of those that do
+fn fmt_blocks(
+    blocks: &[Located<BlockElement>],
+    f: &mut HtmlFormatter,
+    mut on_block: impl FnMut(usize, Region),
+) -> fmt::Result {
+    for block in blocks {
+        let html = {
+            let mut inner = HtmlFormatter::new(f.config());
+            block.fmt_html(&mut inner)?;
+            inner.into_content()
+        };
+
+        if html.is_empty() {
+            continue;
+        }
+
+        if !f.as_content().is_empty() {
+            writeln!(f)?;
+        }
+
+        on_block(f.line(), block.region());
+        f.write_str(&html)?;
+    }
+
+    Ok(())
+}
+
+impl Html for BlockElement<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        match self {
+            Self::Blockquote(x) => x.fmt_html(f),
+            Self::DefinitionList(x) => x.fmt_html(f),
+            Self::Divider(x) => x.fmt_html(f),
+            Self::FootnoteDef(x) => x.fmt_html(f),
+            Self::Header(x) => x.fmt_html(f),
+            Self::List(x) => x.fmt_html(f),
+            Self::Math(x) => x.fmt_html(f),
+            Self::Paragraph(x) => x.fmt_html(f),
+            Self::Placeholder(x) => x.fmt_html(f),
+            Self::PreformattedText(x) => x.fmt_html(f),
+            Self::Signature(x) => x.fmt_html(f),
+            Self::Table(x) => x.fmt_html(f),
+        }
+    }
+}
+
+impl Html for Blockquote<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        writeln!(f, "<blockquote>")?;
+
+        // Blank lines within a blockquote separate its paragraphs
+        for paragraph in self.lines.split(|line| line.trim().is_empty()) {
+            if paragraph.is_empty() {
+                continue;
+            }
+
+            f.write_str("<p>")?;
+            for (i, line) in paragraph.iter().enumerate() {
+                if i > 0 {
+                    writeln!(f)?;
+                }
+                f.write_escaped(line.trim())?;
+            }
+            writeln!(f, "</p>")?;
+        }
+
+        if let Some(attribution) = self.attribution.as_ref() {
+            f.write_str("<footer>&mdash; ")?;
+            attribution.fmt_html(f)?;
+            writeln!(f, "</footer>")?;
+        }
+
+        f.write_str("</blockquote>")
+    }
+}
+
+impl Html for DefinitionList<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        // NOTE: Terms are stored without order, so we sort them by their
+        //       position in the source to produce stable output
+        let mut terms: Vec<_> = self.iter().collect();
+        terms.sort_by_key(|(term, _)| term.region().offset());
+
+        writeln!(f, "<dl>")?;
+        for (term, definitions) in terms {
+            f.write_str("<dt>")?;
+            term.as_inner().as_inner().fmt_html(f)?;
+            writeln!(f, "</dt>")?;
+
+            for definition in definitions {
+                f.write_str("<dd>")?;
+                definition.as_inner().as_inner().fmt_html(f)?;
+                if let Some(list) = definition.list() {
+                    writeln!(f)?;
+                    list.fmt_html(f)?;
+                    writeln!(f)?;
+                }
+                writeln!(f, "</dd>")?;
+            }
+        }
+        f.write_str("</dl>")
+    }
+}
+
+impl Html for Divider {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<hr />")
+    }
+}
+
+impl Html for FootnoteDef<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<div class=\"footnote\" id=\"fn-")?;
+        f.write_escaped(&self.label)?;
+        f.write_str("\"><sup>")?;
+        f.write_escaped(&self.label)?;
+        f.write_str("</sup> ")?;
+        self.content.fmt_html(f)?;
+        f.write_str("</div>")
+    }
+}
+
+impl Html for Header<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        // NOTE: An explicit id of the header takes the place of its slug
+        let id = match self.custom_id.as_deref() {
+            Some(id) => id.to_string(),
+            None => f.config().slugger.slugify(&self.content.to_string()),
+        };
+        write!(f, "<h{} id=\"", self.level)?;
+        f.write_escaped(&id)?;
+        f.write_str("\"")?;
+        if self.centered {
+            f.write_str(" class=\"justcenter\"")?;
+        }
+        f.write_str(">")?;
+        self.content.fmt_html(f)?;
+        if f.config().header_permalinks {
+            f.write_str("<a class=\"permalink\" href=\"#")?;
+            f.write_escaped(&id)?;
+            f.write_str("\">¶</a>")?;
+        }
+        write!(f, "</h{}>", self.level)
+    }
+}
+
+impl Html for List<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        let tag = match self.items.first().map(|x| &x.item_type) {
+            Some(ListItemType::Ordered(_)) => "ol",
+            _ => "ul",
+        };
+
+        f.write_str("<")?;
+        f.write_str(tag)?;
+        if let Some(ListItemType::Ordered(ty)) =
+            self.items.first().map(|x| &x.item_type)
+        {
+            match ty {
+                OrderedListItemType::LowercaseAlphabet => {
+                    f.write_str(" type=\"a\"")?
+                }
+                OrderedListItemType::UppercaseAlphabet => {
+                    f.write_str(" type=\"A\"")?
+                }
+                OrderedListItemType::LowercaseRoman => {
+                    f.write_str(" type=\"i\"")?
+                }
+                OrderedListItemType::UppercaseRoman => {
+                    f.write_str(" type=\"I\"")?
+                }
+                _ => {}
+            }
+        }
+        writeln!(f, ">")?;
+
+        for item in self.items.iter() {
+            item.fmt_html(f)?;
+            writeln!(f)?;
+        }
+
+        write!(f, "</{}>", tag)
+    }
+}
+
+impl Html for ListItem<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<li")?;
+        if let Some(status) = self.attributes.todo_status {
+            let class = match status {
+                ListItemTodoStatus::Incomplete => "done0",
+                ListItemTodoStatus::PartiallyComplete1 => "done1",
+                ListItemTodoStatus::PartiallyComplete2 => "done2",
+                ListItemTodoStatus::PartiallyComplete3 => "done3",
+                ListItemTodoStatus::InProgress => "in-progress",
+                ListItemTodoStatus::Complete => "done4",
+                ListItemTodoStatus::Rejected => "rejected",
+            };
+            write!(f, " class=\"{}\"", class)?;
+        }
+        f.write_str(">")?;
+
+        for content in self.contents.contents.iter() {
+            match content.as_inner() {
+                ListItemContent::InlineContent(x) => x.fmt_html(f)?,
+                ListItemContent::List(x) => {
+                    writeln!(f)?;
+                    x.fmt_html(f)?;
+                    writeln!(f)?;
+                }
+            }
+        }
+
+        f.write_str("</li>")
+    }
+}
+
+impl Html for MathBlock<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<div class=\"math\">")?;
+        match self.environment.as_deref() {
+            Some(env) => writeln!(f, "\\begin{{{}}}", env)?,
+            None => writeln!(f, "\\[")?,
+        }
+        for line in self.lines.iter() {
+            f.write_escaped(line)?;
+            writeln!(f)?;
+        }
+        match self.environment.as_deref() {
+            Some(env) => write!(f, "\\end{{{}}}", env)?,
+            None => f.write_str("\\]")?,
+        }
+        f.write_str("</div>")
+    }
+}
+
+impl Html for Paragraph<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<p>")?;
+        self.content.fmt_html(f)?;
+        f.write_str("</p>")
+    }
+}
+
+impl Html for Placeholder<'_> {
+    /// Placeholders only influence the surrounding document, so they do not
+    /// produce any output themselves
+    fn fmt_html(&self, _f: &mut HtmlFormatter) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl Html for PreformattedText<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<pre")?;
+        if let Some(lang) = self.lang.as_deref() {
+            f.write_str(" class=\"")?;
+            f.write_escaped(lang)?;
+            f.write_str("\"")?;
+        }
+        writeln!(f, ">")?;
+        for line in self.lines.iter() {
+            f.write_escaped(line)?;
+            writeln!(f)?;
+        }
+        f.write_str("</pre>")
+    }
+}
+
+impl Html for Signature<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<p class=\"signature\">&mdash; ")?;
+        f.write_escaped(&self.0)?;
+        f.write_str("</p>")
+    }
+}
+
+impl Html for Table<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<table")?;
+        if self.centered {
+            f.write_str(" class=\"center\"")?;
+        }
+        writeln!(f, ">")?;
+
+        if let Some(caption) = self.caption.as_ref() {
+            f.write_str("<caption>")?;
+            caption.fmt_html(f)?;
+            writeln!(f, "</caption>")?;
+        }
+
+        // NOTE: Rows above the divider are header rows, so tables without a
+        //       divider are made up entirely of body rows
+        let (head, body) = match self
+            .rows
+            .iter()
+            .position(|row| matches!(row.as_inner(), Row::Divider { .. }))
+        {
+            Some(idx) => (&self.rows[..idx], &self.rows[idx + 1..]),
+            None => (&self.rows[..0], &self.rows[..]),
+        };
+
+        if !head.is_empty() {
+            writeln!(f, "<thead>")?;
+            for row in head.iter() {
+                fmt_table_row(self, row.as_inner(), "th", f)?;
+            }
+            writeln!(f, "</thead>")?;
+        }
+
+        writeln!(f, "<tbody>")?;
+        for row in body.iter() {
+            fmt_table_row(self, row.as_inner(), "td", f)?;
+        }
+        writeln!(f, "</tbody>")?;
+
+        f.write_str("</table>")
+    }
+}
+
+/// Writes a content row of the table using the given tag for its cells,
+/// skipping dividers
+fn fmt_table_row(
+    table: &Table,
+    row: &Row,
+    tag: &str,
+    f: &mut HtmlFormatter,
+) -> fmt::Result {
+    if let Row::Content { cells } = row {
+        f.write_str("<tr>")?;
+        for (col, cell) in cells.iter().enumerate() {
+            // NOTE: Spanned cells are merged into a neighboring cell, so
+            //       they are not rendered on their own
+            if let Cell::Content(x) = cell.as_inner() {
+                // NOTE: Left is the default alignment of a cell, so only
+                //       other alignments are styled
+                match table.get_column_alignment(col) {
+                    ColumnAlign::Left => write!(f, "<{}>", tag)?,
+                    ColumnAlign::Center => {
+                        write!(f, "<{} style=\"text-align:center;\">", tag)?
+                    }
+                    ColumnAlign::Right => {
+                        write!(f, "<{} style=\"text-align:right;\">", tag)?
+                    }
+                }
+                x.fmt_html(f)?;
+                write!(f, "</{}>", tag)?;
+            }
+        }
+        writeln!(f, "</tr>")?;
+    }
+
+    Ok(())
+}
+
+impl Html for InlineElementContainer<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.quote_opens = true;
+        for element in self.elements.iter() {
+            element.fmt_html(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Html for InlineElement<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        match self {
+            Self::Text(x) => return x.fmt_html(f),
+            Self::DecoratedText(x) => return x.fmt_html(f),
+            Self::Keyword(x) => x.fmt_html(f)?,
+            Self::Link(x) => x.fmt_html(f)?,
+            Self::Tags(x) => x.fmt_html(f)?,
+            Self::Code(x) => x.fmt_html(f)?,
+            Self::Math(x) => x.fmt_html(f)?,
+            Self::FootnoteRef(x) => x.fmt_html(f)?,
+            Self::Hashtag(x) => x.fmt_html(f)?,
+            Self::Comment(_) => return Ok(()),
+        }
+
+        // NOTE: A quote right after any other element, such as the `'` of
+        //       `[[link]]'s`, closes
+        f.quote_opens = false;
+        Ok(())
+    }
+}
+
+impl Html for Text<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        let text = if f.config().collapse_whitespace {
+            self.collapse_whitespace()
+        } else {
+            self.as_borrowed()
+        };
+
+        if f.config().smart_typography && !f.within_link {
+            let (text, quote_opens) = smarten(&text.0, f.quote_opens);
+            f.quote_opens = quote_opens;
+            f.write_escaped(&text)
+        } else {
+            f.write_escaped(&text.0)
+        }
+    }
+}
+
+/// Replaces straight quotes with curly quotes, where a quote opens if it
+/// follows whitespace or an opening bracket (or starts the text and
+/// *opens* is true), and replaces `---` and `--` with em and en dashes.
+/// Returns the text along with whether a quote following it would open
+fn smarten(text: &str, opens: bool) -> (String, bool) {
+    let opens_after = |x: char| {
+        x.is_whitespace() || matches!(x, '(' | '[' | '{' | '\u{2014}')
+    };
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut prev: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        let opens = prev.map_or(opens, opens_after);
+
+        let c = match c {
+            '"' if opens => '\u{201C}',
+            '"' => '\u{201D}',
+            '\'' if opens => '\u{2018}',
+            '\'' => '\u{2019}',
+            '-' if chars.peek() == Some(&'-') => {
+                chars.next();
+                if chars.peek() == Some(&'-') {
+                    chars.next();
+                    '\u{2014}'
+                } else {
+                    '\u{2013}'
+                }
+            }
+            c => c,
+        };
+
+        out.push(c);
+        prev = Some(c);
+    }
+
+    let opens = prev.map_or(opens, opens_after);
+    (out, opens)
+}
+
+impl Html for DecoratedTextContent<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        match self {
+            Self::Text(x) => return x.fmt_html(f),
+            Self::DecoratedText(x) => return x.fmt_html(f),
+            Self::Keyword(x) => x.fmt_html(f)?,
+            Self::Link(x) => x.fmt_html(f)?,
+            Self::FootnoteRef(x) => x.fmt_html(f)?,
+        }
+
+        f.quote_opens = false;
+        Ok(())
+    }
+}
+
+impl Html for DecoratedText<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        let (tag, contents) = match self {
+            Self::Bold(x) => ("strong", x),
+            Self::Italic(x) => ("em", x),
+            Self::Strikeout(x) => ("del", x),
+            Self::Superscript(x) => ("sup", x),
+            Self::Subscript(x) => ("sub", x),
+        };
+
+        write!(f, "<{}>", tag)?;
+        for content in contents.iter() {
+            content.fmt_html(f)?;
+        }
+        write!(f, "</{}>", tag)
+    }
+}
+
+impl Html for Keyword {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        let class = match f.config().keyword_class_prefix.as_deref() {
+            Some(prefix) => {
+                format!("{0} {0}-{1}", prefix, self.to_string().to_lowercase())
+            }
+            None => String::from("todo"),
+        };
+
+        f.write_str("<span class=\"")?;
+        f.write_escaped(&class)?;
+        write!(f, "\">{}</span>", self)
+    }
+}
+
+impl Html for Link<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        match self {
+            Self::Wiki(x) => {
+                let href = wiki_link_href(x, f.config().slugger);
+                fmt_link(f, &href, x.description.as_ref())
+            }
+            Self::InterWiki(x) => {
+                let href = wiki_link_href(x.link(), f.config().slugger);
+                fmt_link(f, &href, x.description())
+            }
+            Self::Diary(x) => {
+                let mut href = format!("diary/{}.html", x.date_str);
+                if let Some(anchor) = x.anchor.as_ref() {
+                    href.push('#');
+                    href.push_str(&anchor_slug(anchor, f.config().slugger));
+                }
+                fmt_link(f, &href, x.description.as_ref())
+            }
+            Self::Raw(x) => {
+                fmt_link(f, &x.uri.to_string(), x.description.as_ref())
+            }
+            Self::ExternalFile(x) => {
+                fmt_link(f, &x.path.to_string_lossy(), x.description.as_ref())
+            }
+            Self::Transclusion(x) => {
+                f.write_str("<img src=\"")?;
+                f.write_escaped(&x.uri.to_string())?;
+                f.write_str("\"")?;
+                if let Some(description) = x.description.as_ref() {
+                    f.write_str(" alt=\"")?;
+                    f.write_escaped(&description.to_string())?;
+                    f.write_str("\"")?;
+                }
+
+                // NOTE: Properties are stored without order, so we sort them
+                //       to produce stable output
+                let mut properties: Vec<_> = x.properties.iter().collect();
+                properties.sort();
+                for (key, value) in properties {
+                    f.write_str(" ")?;
+                    f.write_escaped(key)?;
+                    f.write_str("=\"")?;
+                    f.write_escaped(value)?;
+                    f.write_str("\"")?;
+                }
+                f.write_str(" />")
+            }
+            Self::Reference(x) => fmt_link(f, &x.target, Some(&x.description)),
+        }
+    }
+}
+
+/// Produces the href of a link to another wiki page, where directories
+/// resolve to their index page and URLs are kept as they are
+fn wiki_link_href(link: &WikiLink, slugger: SlugStyle) -> String {
+    let mut href = String::new();
+
+    if link.is_url() {
+        href.push_str(&link.path.to_string_lossy());
+    } else if !link.is_local_anchor() {
+        href.push_str(&link.path.to_string_lossy());
+        if link.is_path_dir() {
+            href.push_str("index");
+        }
+        href.push_str(".html");
+    }
+
+    if let Some(anchor) = link.anchor.as_ref() {
+        href.push('#');
+        href.push_str(&anchor_slug(anchor, slugger));
+    }
+
+    href
+}
+
+/// Produces the fragment of an anchor, slugging each of its elements the
+/// same way as the id of the header it points to
+fn anchor_slug(anchor: &Anchor, slugger: SlugStyle) -> String {
+    anchor
+        .elements
+        .iter()
+        .map(|x| slugger.slugify(x))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Writes an anchor tag, falling back to the href as the link text when no
+/// description is available
+fn fmt_link(
+    f: &mut HtmlFormatter,
+    href: &str,
+    description: Option<&Description>,
+) -> fmt::Result {
+    f.write_str("<a href=\"")?;
+    f.write_escaped(href)?;
+    f.write_str("\">")?;
+    match description {
+        Some(Description::Text(x)) => f.write_escaped(x)?,
+        Some(Description::Uri(x)) => {
+            f.write_str("<img src=\"")?;
+            f.write_escaped(&x.to_string())?;
+            f.write_str("\" />")?;
+        }
+        Some(Description::Inline(x)) => {
+            f.within_link = true;
+            let result = x.fmt_html(f);
+            f.within_link = false;
+            result?
+        }
+        None => f.write_escaped(href)?,
+    }
+    f.write_str("</a>")
+}
+
+impl Html for Tags<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        for tag in self.0.iter() {
+            f.write_str("<span class=\"tag\">")?;
+            f.write_escaped(&tag.0)?;
+            f.write_str("</span>")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Html for CodeInline<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<code>")?;
+        f.write_escaped(&self.code)?;
+        f.write_str("</code>")
+    }
+}
+
+impl Html for MathInline<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("\\(")?;
+        f.write_escaped(&self.formula)?;
+        f.write_str("\\)")
+    }
+}
+
+impl Html for FootnoteRef<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<sup class=\"footnote-ref\"><a href=\"#fn-")?;
+        f.write_escaped(&self.label)?;
+        f.write_str("\">")?;
+        f.write_escaped(&self.label)?;
+        f.write_str("</a></sup>")
+    }
+}
+
+impl Html for Hashtag<'_> {
+    fn fmt_html(&self, f: &mut HtmlFormatter) -> fmt::Result {
+        f.write_str("<span class=\"hashtag\">#")?;
+        f.write_escaped(&self.name)?;
+        f.write_str("</span>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::output::parse_page;
+
+    #[test]
+    fn to_html_should_render_each_block() {
+        let page = parse_page("= Header =\n\nsome *bold* text\n----\n");
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<h1 id=\"Header\">Header</h1>\n\
+             <p>some <strong>bold</strong> text</p>\n\
+             <hr />"
+        );
+    }
+
+    #[test]
+    fn to_html_should_use_configured_newline_between_blocks() {
+        let page = parse_page("= Header =\n\nsome text\n");
+        let config = HtmlConfig {
+            newline: Newline::Crlf,
+            ..Default::default()
+        };
+        assert_eq!(
+            page.to_html(&config),
+            "<h1 id=\"Header\">Header</h1>\r\n<p>some text</p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_slug_headers_using_configured_style() {
+        let page = parse_page("= Hello, World! =\n");
+        let render = |slugger| {
+            page.to_html(&HtmlConfig {
+                slugger,
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(
+            render(SlugStyle::Vimwiki),
+            "<h1 id=\"Hello, World!\">Hello, World!</h1>"
+        );
+        assert_eq!(
+            render(SlugStyle::GitHub),
+            "<h1 id=\"hello-world\">Hello, World!</h1>"
+        );
+        assert_eq!(
+            render(SlugStyle::Custom(|x| x.len().to_string())),
+            "<h1 id=\"13\">Hello, World!</h1>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_include_permalink_to_header_id_if_enabled() {
+        let page = parse_page("= Hello, World! =\n");
+        let config = HtmlConfig {
+            slugger: SlugStyle::GitHub,
+            header_permalinks: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            page.to_html(&config),
+            "<h1 id=\"hello-world\">Hello, World!\
+             <a class=\"permalink\" href=\"#hello-world\">¶</a></h1>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_use_custom_id_of_header_in_place_of_slug() {
+        let page = parse_page("= Hello, World! =#greeting\n== Other ==\n");
+        let config = HtmlConfig {
+            slugger: SlugStyle::GitHub,
+            header_permalinks: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            page.to_html(&config),
+            "<h1 id=\"greeting\">Hello, World!\
+             <a class=\"permalink\" href=\"#greeting\">¶</a></h1>\n\
+             <h2 id=\"other\">Other\
+             <a class=\"permalink\" href=\"#other\">¶</a></h2>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_slug_link_anchors_like_header_ids() {
+        let page = parse_page("[[Page#Hello, World!|greeting]]\n");
+        let config = HtmlConfig {
+            slugger: SlugStyle::GitHub,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            page.to_html(&config),
+            "<p><a href=\"Page.html#hello-world\">greeting</a></p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_keep_url_targets_of_wiki_links_as_they_are() {
+        let page = parse_page("[[https://example.com|desc]] [[Page|page]]\n");
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<p><a href=\"https://example.com\">desc</a> \
+             <a href=\"Page.html\">page</a></p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_align_table_cells_using_divider_row() {
+        let page = parse_page("|a|b|c|\n|:--|:-:|--:|\n|1|2|3|\n");
+        let table = match page.elements()[0].as_inner() {
+            BlockElement::Table(x) => x,
+            x => panic!("Unexpected element: {:?}", x),
+        };
+        assert_eq!(table.get_column_alignment(0), ColumnAlign::Left);
+        assert_eq!(table.get_column_alignment(1), ColumnAlign::Center);
+        assert_eq!(table.get_column_alignment(2), ColumnAlign::Right);
+
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<table>\n\
+             <thead>\n\
+             <tr><th>a</th>\
+             <th style=\"text-align:center;\">b</th>\
+             <th style=\"text-align:right;\">c</th></tr>\n\
+             </thead>\n\
+             <tbody>\n\
+             <tr><td>1</td>\
+             <td style=\"text-align:center;\">2</td>\
+             <td style=\"text-align:right;\">3</td></tr>\n\
+             </tbody>\n\
+             </table>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_split_table_into_head_and_body_at_divider_row() {
+        let page = parse_page("|a|b|\n|c|d|\n|---|---|\n|1|2|\n|3|4|\n");
+
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<table>\n\
+             <thead>\n\
+             <tr><th>a</th><th>b</th></tr>\n\
+             <tr><th>c</th><th>d</th></tr>\n\
+             </thead>\n\
+             <tbody>\n\
+             <tr><td>1</td><td>2</td></tr>\n\
+             <tr><td>3</td><td>4</td></tr>\n\
+             </tbody>\n\
+             </table>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_render_in_progress_list_item_with_class() {
+        let page = parse_page("- [/] working\n- [X] done\n");
+
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<ul>\n\
+             <li class=\"in-progress\">working</li>\n\
+             <li class=\"done4\">done</li>\n\
+             </ul>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_render_superscript_as_sup() {
+        let page = parse_page("x^2^ and x^*2*^\n");
+
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<p>x<sup>2</sup> and x<sup><strong>2</strong></sup></p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_render_subscript_as_sub() {
+        let page = parse_page("a,,x,, and a,,_x_,,\n");
+
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<p>a<sub>x</sub> and a<sub><em>x</em></sub></p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_render_table_caption() {
+        let page = parse_page("|a|b|\nTable: Some *values*\n");
+
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<table>\n\
+             <caption>Some <strong>values</strong></caption>\n\
+             <tbody>\n\
+             <tr><td>a</td><td>b</td></tr>\n\
+             </tbody>\n\
+             </table>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_render_smart_typography_if_enabled() {
+        let page = parse_page(
+            "\"Quoted\" -- it's 'here' --- not `\"code\" -- 'here'`\n",
+        );
+        let config = HtmlConfig {
+            smart_typography: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            page.to_html(&config),
+            "<p>\u{201C}Quoted\u{201D} \u{2013} it\u{2019}s \
+             \u{2018}here\u{2019} \u{2014} not \
+             <code>&quot;code&quot; -- &#39;here&#39;</code></p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_close_quotes_following_other_inline_elements() {
+        let page = parse_page("*hi*'s `x`'s and '*quoted*'\n");
+        let config = HtmlConfig {
+            smart_typography: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            page.to_html(&config),
+            "<p><strong>hi</strong>\u{2019}s <code>x</code>\u{2019}s and \
+             \u{2018}<strong>quoted</strong>\u{2019}</p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_leave_link_descriptions_without_smart_typography() {
+        let page = parse_page("[[Page|*it's* -- here]]\n");
+        let config = HtmlConfig {
+            smart_typography: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            page.to_html(&config),
+            "<p><a href=\"Page.html\"><strong>it&#39;s</strong> -- here\
+             </a></p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_collapse_whitespace_in_text_if_enabled() {
+        let page = parse_page("a    b `c    d`\n");
+        let config = HtmlConfig {
+            collapse_whitespace: true,
+            ..Default::default()
+        };
+
+        assert_eq!(page.to_html(&config), "<p>a b <code>c    d</code></p>");
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<p>a    b <code>c    d</code></p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_render_keywords_with_class_prefix_if_provided() {
+        let page = parse_page("TODO item\n");
+        let config = HtmlConfig {
+            keyword_class_prefix: Some(String::from("kw")),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            page.to_html(&config),
+            "<p><span class=\"kw kw-todo\">TODO</span> item</p>"
+        );
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<p><span class=\"todo\">TODO</span> item</p>"
+        );
+    }
+
+    #[test]
+    fn to_html_should_escape_text() {
+        let page = parse_page("a < b & c\n");
+        assert_eq!(
+            page.to_html(&HtmlConfig::default()),
+            "<p>a &lt; b &amp; c</p>"
+        );
+    }
+
+    #[test]
+    fn to_html_with_sourcemap_should_map_output_lines_to_block_regions() {
+        let page =
+            parse_page("= Header =\n\nsome paragraph\n\n- item 1\n- item 2\n");
+        let (html, sourcemap) =
+            page.to_html_with_sourcemap(&HtmlConfig::default());
+
+        assert_eq!(sourcemap.len(), 3, "Unexpected sourcemap: {:?}", sourcemap);
+        assert!(
+            sourcemap.windows(2).all(|w| w[0].0 < w[1].0),
+            "Output lines not increasing: {:?}",
+            sourcemap
+        );
+
+        let lines: Vec<&str> = html.lines().collect();
+        for ((line, region), element) in sourcemap.iter().zip(page.elements()) {
+            assert_eq!(*region, element.region());
+            assert!(!lines[line - 1].is_empty());
+        }
+        assert!(lines[sourcemap[2].0 - 1].starts_with("<ul>"));
+    }
+}
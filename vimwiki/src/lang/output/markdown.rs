@@ -0,0 +1,270 @@
+use super::plain::wiki_link_target;
+use crate::lang::elements::*;
+use std::fmt::{self, Write};
+
+impl DefinitionList<'_> {
+    /// Renders the definition list in the PHP Markdown Extra style, where
+    /// each term is a line of its own followed by a `: definition` line for
+    /// each of its definitions. Decorations, links, and code within terms
+    /// and definitions are written as Markdown, and a list beneath a
+    /// definition is indented by four spaces
+    pub fn to_markdown(&self) -> String {
+        // NOTE: Terms are stored without order, so we sort them by their
+        //       position in the source to keep the order they were written
+        let mut terms: Vec<_> = self.iter().collect();
+        terms.sort_by_key(|(term, _)| term.region().offset());
+
+        // NOTE: Writing to a string buffer never fails
+        let mut out = String::new();
+        for (term, definitions) in terms {
+            let _ = term.as_inner().as_inner().fmt_markdown(&mut out);
+            out.push('\n');
+
+            for definition in definitions.iter() {
+                out.push_str(": ");
+                let _ = definition.as_inner().as_inner().fmt_markdown(&mut out);
+                out.push('\n');
+
+                if let Some(list) = definition.list() {
+                    let _ = fmt_list(&mut out, list.as_inner(), 4);
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// Represents an element that can be written as Markdown
+trait Markdown {
+    fn fmt_markdown(&self, f: &mut String) -> fmt::Result;
+}
+
+/// Writes each item of the list on its own line at the given indentation,
+/// using `-` for unordered items and `1.` style numbers for ordered items
+fn fmt_list(f: &mut String, list: &List, indent: usize) -> fmt::Result {
+    for item in list.items.iter() {
+        let prefix = if item.is_ordered() {
+            format!("{}.", item.pos + 1)
+        } else {
+            String::from("-")
+        };
+        write!(f, "{:indent$}{} ", "", prefix, indent = indent)?;
+
+        for (i, content) in item.contents.contents.iter().enumerate() {
+            match content.as_inner() {
+                ListItemContent::InlineContent(x) => {
+                    if i > 0 {
+                        write!(f, "{:indent$}", "", indent = indent + 4)?;
+                    }
+                    x.fmt_markdown(f)?;
+                    f.push('\n');
+                }
+                ListItemContent::List(x) => fmt_list(f, x, indent + 4)?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl Markdown for InlineElementContainer<'_> {
+    fn fmt_markdown(&self, f: &mut String) -> fmt::Result {
+        for element in self.elements.iter() {
+            element.as_inner().fmt_markdown(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Markdown for InlineElement<'_> {
+    fn fmt_markdown(&self, f: &mut String) -> fmt::Result {
+        match self {
+            Self::Text(x) => x.fmt_markdown(f),
+            Self::DecoratedText(x) => x.fmt_markdown(f),
+            Self::Keyword(x) => write!(f, "{}", x),
+            Self::Link(x) => x.fmt_markdown(f),
+            Self::Tags(x) => write!(f, "{}", x),
+            Self::Code(x) => fmt_code(f, &x.code),
+            Self::Math(x) => write!(f, "${}$", x.formula),
+            Self::FootnoteRef(x) => write!(f, "[^{}]", x.label),
+            Self::Hashtag(x) => write!(f, "#{}", x.name),
+            Self::Comment(_) => Ok(()),
+        }
+    }
+}
+
+impl Markdown for Text<'_> {
+    /// Writes the text, escaping characters that Markdown would otherwise
+    /// read as decorations, code, or links
+    fn fmt_markdown(&self, f: &mut String) -> fmt::Result {
+        for c in self.0.chars() {
+            if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+                f.push('\\');
+            }
+            f.push(c);
+        }
+
+        Ok(())
+    }
+}
+
+impl Markdown for DecoratedTextContent<'_> {
+    fn fmt_markdown(&self, f: &mut String) -> fmt::Result {
+        match self {
+            Self::Text(x) => x.fmt_markdown(f),
+            Self::DecoratedText(x) => x.fmt_markdown(f),
+            Self::Keyword(x) => write!(f, "{}", x),
+            Self::Link(x) => x.fmt_markdown(f),
+            Self::FootnoteRef(x) => write!(f, "[^{}]", x.label),
+        }
+    }
+}
+
+impl Markdown for DecoratedText<'_> {
+    /// Writes the contents between Markdown markers, falling back to html
+    /// tags for superscript and subscript as Markdown has no markers for them
+    fn fmt_markdown(&self, f: &mut String) -> fmt::Result {
+        let (contents, start, end) = match self {
+            Self::Bold(x) => (x, "**", "**"),
+            Self::Italic(x) => (x, "*", "*"),
+            Self::Strikeout(x) => (x, "~~", "~~"),
+            Self::Superscript(x) => (x, "<sup>", "</sup>"),
+            Self::Subscript(x) => (x, "<sub>", "</sub>"),
+        };
+
+        f.push_str(start);
+        for content in contents.iter() {
+            content.as_inner().fmt_markdown(f)?;
+        }
+        f.push_str(end);
+
+        Ok(())
+    }
+}
+
+impl Markdown for Link<'_> {
+    /// Writes the link as `[description](target)`, where a url without a
+    /// description is written as an autolink and a transclusion as an image
+    fn fmt_markdown(&self, f: &mut String) -> fmt::Result {
+        match self {
+            Self::Wiki(x) => {
+                fmt_link(f, &wiki_link_target(x), x.description.as_ref())
+            }
+            Self::InterWiki(x) => {
+                fmt_link(f, &wiki_link_target(x.link()), x.description())
+            }
+            Self::Diary(x) => {
+                let mut target = format!("diary:{}", x.date_str);
+                if let Some(anchor) = x.anchor.as_ref() {
+                    target.push_str(&anchor.to_string());
+                }
+                fmt_link(f, &target, x.description.as_ref())
+            }
+            Self::Raw(x) => match x.description.as_ref() {
+                Some(description) => {
+                    fmt_link(f, &x.uri.to_string(), Some(description))
+                }
+                None => write!(f, "<{}>", x.uri),
+            },
+            Self::ExternalFile(x) => {
+                fmt_link(f, &x.path.to_string_lossy(), x.description.as_ref())
+            }
+            Self::Transclusion(x) => {
+                f.push('!');
+                fmt_link(f, &x.uri.to_string(), x.description.as_ref())
+            }
+            Self::Reference(x) => fmt_link(f, &x.target, Some(&x.description)),
+        }
+    }
+}
+
+/// Writes a link to the target, using the target as the text of the link
+/// when there is no description
+fn fmt_link(
+    f: &mut String,
+    target: &str,
+    description: Option<&Description>,
+) -> fmt::Result {
+    f.push('[');
+    match description {
+        Some(Description::Text(x)) => Text::from(x.as_ref()).fmt_markdown(f)?,
+        Some(Description::Uri(x)) => write!(f, "{}", x)?,
+        Some(Description::Inline(x)) => x.fmt_markdown(f)?,
+        None => Text::from(target).fmt_markdown(f)?,
+    }
+    write!(f, "]({})", target.replace(' ', "%20"))
+}
+
+/// Writes code between enough backticks that none within the code end it,
+/// padding it with spaces when it starts or ends with a backtick
+fn fmt_code(f: &mut String, code: &str) -> fmt::Result {
+    let longest = code
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest + 1);
+    let pad = if code.starts_with('`') || code.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+
+    write!(f, "{}{}{}{}{}", fence, pad, code, pad, fence)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lang::output::parse_page, BlockElement};
+    use indoc::indoc;
+
+    #[test]
+    fn definition_list_to_markdown_should_write_term_and_definition_lines() {
+        let page = parse_page(indoc! {"
+            Term 1:: First *definition*
+            :: Second definition
+            Term 2::
+            :: Third definition
+        "});
+
+        let list = match page.elements[0].as_inner() {
+            BlockElement::DefinitionList(x) => x,
+            x => panic!("Unexpected element: {:?}", x),
+        };
+
+        assert_eq!(
+            list.to_markdown(),
+            indoc! {"
+                Term 1
+                : First **definition**
+                : Second definition
+                Term 2
+                : Third definition
+            "}
+        );
+    }
+
+    #[test]
+    fn definition_list_to_markdown_should_write_inline_content_as_markdown() {
+        let page = parse_page(indoc! {"
+            *Bold* term:: see [[other page|the _other_ page]] or `x * y`
+            :: visit https://example.com or [[index]] for 2*3
+        "});
+
+        let list = match page.elements[0].as_inner() {
+            BlockElement::DefinitionList(x) => x,
+            x => panic!("Unexpected element: {:?}", x),
+        };
+
+        assert_eq!(
+            list.to_markdown(),
+            indoc! {"
+                **Bold** term
+                : see [the *other* page](other%20page) or `x * y`
+                : visit https://example.com or [index](index) for 2\\*3
+            "}
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+mod html;
+pub use html::*;
+mod markdown;
+mod opml;
+mod plain;
+pub use plain::*;
+mod vimwiki;
+pub use self::vimwiki::*;
+
+/// Represents the line ending written by renderers
+#[derive(
+    Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize,
+)]
+pub enum Newline {
+    /// Line feed (`\n`)
+    #[default]
+    Lf,
+
+    /// Carriage return followed by line feed (`\r\n`)
+    Crlf,
+}
+
+impl Newline {
+    /// Returns the characters that make up the line ending
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::Crlf => "\r\n",
+        }
+    }
+}
+
+/// Appends text to the content, writing each line feed using the given line
+/// ending unless it already follows a carriage return
+fn push_str_with_newline(content: &mut String, s: &str, newline: Newline) {
+    if newline == Newline::Lf {
+        content.push_str(s);
+        return;
+    }
+
+    for c in s.chars() {
+        if c == '\n' && !content.ends_with('\r') {
+            content.push_str(newline.as_str());
+        } else {
+            content.push(c);
+        }
+    }
+}
+
+/// Parses vimwiki into a page, panicking if it fails, for use within tests
+#[cfg(test)]
+pub(crate) fn parse_page(input: &str) -> crate::Page<'_> {
+    crate::Language::from_vimwiki_str(input).parse().unwrap()
+}
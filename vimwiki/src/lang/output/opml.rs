@@ -0,0 +1,157 @@
+use super::{PlainConfig, ToPlainString};
+use crate::lang::elements::*;
+
+/// Represents an outline within an OPML document, built from a header and
+/// the paragraphs beneath it
+struct OpmlOutline {
+    text: String,
+    level: usize,
+    notes: Vec<String>,
+    children: Vec<OpmlOutline>,
+}
+
+impl OpmlOutline {
+    fn write(&self, indent: usize, out: &mut String) {
+        out.push_str(&"  ".repeat(indent));
+        out.push_str("<outline text=\"");
+        push_escaped(out, &self.text);
+        out.push('"');
+
+        if !self.notes.is_empty() {
+            out.push_str(" _note=\"");
+            push_escaped(out, &self.notes.join("\n\n"));
+            out.push('"');
+        }
+
+        if self.children.is_empty() {
+            out.push_str("/>\n");
+        } else {
+            out.push_str(">\n");
+            for child in self.children.iter() {
+                child.write(indent + 1, out);
+            }
+            out.push_str(&"  ".repeat(indent));
+            out.push_str("</outline>\n");
+        }
+    }
+}
+
+impl Page<'_> {
+    /// Renders the page as an OPML document, where each header becomes an
+    /// outline nested beneath the nearest preceding header with a smaller
+    /// level and paragraphs become the note of the header they follow.
+    ///
+    /// Paragraphs before the first header become outlines of their own
+    pub fn to_opml(&self) -> String {
+        fn pop_outline(
+            stack: &mut Vec<OpmlOutline>,
+            roots: &mut Vec<OpmlOutline>,
+        ) {
+            if let Some(outline) = stack.pop() {
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(outline),
+                    None => roots.push(outline),
+                }
+            }
+        }
+
+        let config = PlainConfig::default();
+        let mut roots = Vec::new();
+        let mut stack: Vec<OpmlOutline> = Vec::new();
+
+        for element in self.elements.iter() {
+            match element.as_inner() {
+                BlockElement::Header(header) => {
+                    while stack.last().is_some_and(|x| x.level >= header.level)
+                    {
+                        pop_outline(&mut stack, &mut roots);
+                    }
+
+                    stack.push(OpmlOutline {
+                        text: header.content.to_plain_string(&config),
+                        level: header.level,
+                        notes: Vec::new(),
+                        children: Vec::new(),
+                    });
+                }
+                BlockElement::Paragraph(paragraph) => {
+                    let text = paragraph.to_plain_string(&config);
+                    match stack.last_mut() {
+                        Some(outline) => outline.notes.push(text),
+                        None => roots.push(OpmlOutline {
+                            text,
+                            level: 0,
+                            notes: Vec::new(),
+                            children: Vec::new(),
+                        }),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        while !stack.is_empty() {
+            pop_outline(&mut stack, &mut roots);
+        }
+
+        let mut out = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <opml version=\"2.0\">\n<head></head>\n<body>\n",
+        );
+        for outline in roots.iter() {
+            outline.write(1, &mut out);
+        }
+        out.push_str("</body>\n</opml>\n");
+        out
+    }
+}
+
+/// Escapes text so it can be placed within an attribute of the document,
+/// encoding line breaks so they survive attribute normalization
+fn push_escaped(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\n' => out.push_str("&#10;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lang::output::parse_page;
+    use indoc::indoc;
+
+    #[test]
+    fn page_to_opml_should_nest_headers_and_attach_paragraphs_as_notes() {
+        let page = parse_page(indoc! {"
+            = One =
+            first *note*
+
+            second note
+            == Two & Three ==
+            nested note
+            = Four =
+        "});
+
+        assert_eq!(
+            page.to_opml(),
+            indoc! {r#"
+                <?xml version="1.0" encoding="UTF-8"?>
+                <opml version="2.0">
+                <head></head>
+                <body>
+                  <outline text="One" _note="first note&#10;&#10;second note">
+                    <outline text="Two &amp; Three" _note="nested note"/>
+                  </outline>
+                  <outline text="Four"/>
+                </body>
+                </opml>
+            "#}
+        );
+    }
+}
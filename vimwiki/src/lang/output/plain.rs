@@ -0,0 +1,452 @@
+use super::{push_str_with_newline, Newline};
+use crate::lang::elements::*;
+use std::fmt::{self, Write};
+
+/// Represents configuration options when rendering plain text
+#[derive(Clone, Debug, Default)]
+pub struct PlainConfig {
+    /// If true, links are rendered as their text followed by their target
+    /// in parentheses; otherwise, only the text of the link is rendered
+    pub inline_urls: bool,
+
+    /// Line ending written between lines of output
+    pub newline: Newline,
+}
+
+/// Represents the destination of plain text output, tracking the
+/// configuration and current indentation used while rendering
+pub struct PlainFormatter<'a> {
+    config: &'a PlainConfig,
+    content: String,
+    indent: usize,
+}
+
+impl<'a> PlainFormatter<'a> {
+    pub fn new(config: &'a PlainConfig) -> Self {
+        Self {
+            config,
+            content: String::new(),
+            indent: 0,
+        }
+    }
+
+    /// Returns the configuration used by this formatter
+    pub fn config(&self) -> &PlainConfig {
+        self.config
+    }
+
+    /// Consumes the formatter, returning the plain text that was written
+    pub fn into_content(self) -> String {
+        self.content
+    }
+
+    /// Writes the current indentation as spaces
+    pub fn write_indent(&mut self) -> fmt::Result {
+        for _ in 0..self.indent {
+            self.content.push(' ');
+        }
+
+        Ok(())
+    }
+
+    /// Invokes the function with indentation increased by the given amount,
+    /// restoring the previous indentation afterwards
+    pub fn with_indent<F>(&mut self, amount: usize, f: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Self) -> fmt::Result,
+    {
+        self.indent += amount;
+        let result = f(self);
+        self.indent -= amount;
+        result
+    }
+}
+
+impl Write for PlainFormatter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        push_str_with_newline(&mut self.content, s, self.config.newline);
+        Ok(())
+    }
+}
+
+/// Represents an element that can be rendered as plain text
+pub trait Plain {
+    /// Writes the plain text representation of this element to the formatter
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result;
+}
+
+/// Convenience trait to render an element into a standalone string of
+/// plain text
+pub trait ToPlainString {
+    fn to_plain_string(&self, config: &PlainConfig) -> String;
+}
+
+impl<T: Plain> ToPlainString for T {
+    fn to_plain_string(&self, config: &PlainConfig) -> String {
+        let mut f = PlainFormatter::new(config);
+
+        // NOTE: Writing to a string buffer never fails
+        let _ = self.fmt_plain(&mut f);
+        f.into_content()
+    }
+}
+
+impl<T: Plain> Plain for Located<T> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        self.as_inner().fmt_plain(f)
+    }
+}
+
+impl Plain for Page<'_> {
+    /// Writes each block on its own line, skipping placeholders as they
+    /// carry no readable content
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        let blocks = self
+            .elements
+            .iter()
+            .filter(|e| !matches!(e.as_inner(), BlockElement::Placeholder(_)));
+
+        for (i, element) in blocks.enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            element.fmt_plain(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Plain for BlockElement<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        match self {
+            Self::Blockquote(x) => x.fmt_plain(f),
+            Self::DefinitionList(x) => x.fmt_plain(f),
+            Self::Divider(_) => Ok(()),
+            Self::FootnoteDef(x) => x.fmt_plain(f),
+            Self::Header(x) => x.fmt_plain(f),
+            Self::List(x) => x.fmt_plain(f),
+            Self::Math(x) => x.fmt_plain(f),
+            Self::Paragraph(x) => x.fmt_plain(f),
+            Self::Placeholder(_) => Ok(()),
+            Self::PreformattedText(x) => x.fmt_plain(f),
+            Self::Signature(x) => f.write_str(&x.0),
+            Self::Table(x) => x.fmt_plain(f),
+        }
+    }
+}
+
+impl Plain for Blockquote<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        f.write_str(&self.lines.join("\n"))?;
+
+        if let Some(attribution) = self.attribution.as_ref() {
+            f.write_str("\n-- ")?;
+            attribution.fmt_plain(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Plain for DefinitionList<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        // NOTE: Terms are stored without order, so we sort them by their
+        //       position in the source to produce stable output
+        let mut terms: Vec<_> = self.iter().collect();
+        terms.sort_by_key(|(term, _)| term.region().offset());
+
+        for (i, (term, definitions)) in terms.into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            term.as_inner().as_inner().fmt_plain(f)?;
+            for definition in definitions.iter() {
+                writeln!(f)?;
+                f.with_indent(4, |f| f.write_indent())?;
+                definition.as_inner().as_inner().fmt_plain(f)?;
+                if let Some(list) = definition.list() {
+                    writeln!(f)?;
+                    f.with_indent(8, |f| list.fmt_plain(f))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Plain for Header<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        write!(f, "{} ", "#".repeat(self.level))?;
+        self.content.fmt_plain(f)
+    }
+}
+
+impl Plain for List<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            item.fmt_plain(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Plain for ListItem<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        let prefix = self.to_prefix();
+        f.write_indent()?;
+        write!(f, "{} ", prefix)?;
+
+        for (i, content) in self.contents.contents.iter().enumerate() {
+            match content.as_inner() {
+                ListItemContent::InlineContent(x) => {
+                    if i > 0 {
+                        writeln!(f)?;
+                        f.with_indent(prefix.len() + 1, |f| f.write_indent())?;
+                    }
+                    x.fmt_plain(f)?;
+                }
+                ListItemContent::List(x) => {
+                    writeln!(f)?;
+                    f.with_indent(4, |f| x.fmt_plain(f))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Plain for MathBlock<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        f.write_str(&self.lines.join("\n"))
+    }
+}
+
+impl Plain for FootnoteDef<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        write!(f, "[{}] ", self.label)?;
+        self.content.fmt_plain(f)
+    }
+}
+
+impl Plain for Paragraph<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        self.content.fmt_plain(f)
+    }
+}
+
+impl Plain for PreformattedText<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        f.write_str(&self.lines.join("\n"))
+    }
+}
+
+impl Plain for Table<'_> {
+    /// Writes each row of content on its own line with cells separated by
+    /// tabs, skipping divider rows, followed by the caption if there is one
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        let rows = self.rows.iter().filter_map(|row| match row.as_inner() {
+            Row::Content { cells } => Some(cells),
+            Row::Divider { .. } => None,
+        });
+
+        for (i, cells) in rows.enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for (j, cell) in cells.iter().enumerate() {
+                if j > 0 {
+                    f.write_str("\t")?;
+                }
+                if let Cell::Content(x) = cell.as_inner() {
+                    x.fmt_plain(f)?;
+                }
+            }
+        }
+
+        if let Some(caption) = self.caption.as_ref() {
+            writeln!(f)?;
+            caption.fmt_plain(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Plain for InlineElementContainer<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        for element in self.elements.iter() {
+            element.fmt_plain(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Plain for InlineElement<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        match self {
+            Self::Text(x) => x.fmt_plain(f),
+            Self::DecoratedText(x) => x.fmt_plain(f),
+            Self::Keyword(x) => write!(f, "{}", x),
+            Self::Link(x) => x.fmt_plain(f),
+            Self::Tags(x) => x.fmt_plain(f),
+            Self::Code(x) => f.write_str(&x.code),
+            Self::Math(x) => f.write_str(&x.formula),
+            Self::FootnoteRef(x) => write!(f, "[{}]", x.label),
+            Self::Hashtag(x) => write!(f, "#{}", x.name),
+            Self::Comment(_) => Ok(()),
+        }
+    }
+}
+
+impl Plain for Text<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Plain for DecoratedTextContent<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        match self {
+            Self::Text(x) => x.fmt_plain(f),
+            Self::DecoratedText(x) => x.fmt_plain(f),
+            Self::Keyword(x) => write!(f, "{}", x),
+            Self::Link(x) => x.fmt_plain(f),
+            Self::FootnoteRef(x) => write!(f, "[{}]", x.label),
+        }
+    }
+}
+
+impl Plain for DecoratedText<'_> {
+    /// Writes the contents without any of the decoration markers
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        let contents = match self {
+            Self::Bold(x)
+            | Self::Italic(x)
+            | Self::Strikeout(x)
+            | Self::Superscript(x)
+            | Self::Subscript(x) => x,
+        };
+
+        for content in contents.iter() {
+            content.fmt_plain(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Plain for Link<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        match self {
+            Self::Wiki(x) => {
+                fmt_link(f, &wiki_link_target(x), x.description.as_ref())
+            }
+            Self::InterWiki(x) => {
+                fmt_link(f, &wiki_link_target(x.link()), x.description())
+            }
+            Self::Diary(x) => {
+                let mut target = format!("diary:{}", x.date_str);
+                if let Some(anchor) = x.anchor.as_ref() {
+                    target.push_str(&anchor.to_string());
+                }
+                fmt_link(f, &target, x.description.as_ref())
+            }
+            Self::Raw(x) => {
+                fmt_link(f, &x.uri.to_string(), x.description.as_ref())
+            }
+            Self::ExternalFile(x) => {
+                fmt_link(f, &x.path.to_string_lossy(), x.description.as_ref())
+            }
+            Self::Transclusion(x) => {
+                fmt_link(f, &x.uri.to_string(), x.description.as_ref())
+            }
+            Self::Reference(x) => fmt_link(f, &x.target, Some(&x.description)),
+        }
+    }
+}
+
+/// Produces the target of a link to another wiki page, including its anchor
+pub(super) fn wiki_link_target(link: &WikiLink) -> String {
+    let mut target = link.path.to_string_lossy().into_owned();
+    if let Some(anchor) = link.anchor.as_ref() {
+        target.push_str(&anchor.to_string());
+    }
+    target
+}
+
+/// Writes the description of a link, falling back to its target when there
+/// is none, and follows it with the target in parentheses if configured
+fn fmt_link(
+    f: &mut PlainFormatter,
+    target: &str,
+    description: Option<&Description>,
+) -> fmt::Result {
+    match description {
+        Some(Description::Text(x)) => f.write_str(x)?,
+        Some(Description::Uri(x)) => write!(f, "{}", x)?,
+        Some(Description::Inline(x)) => x.fmt_plain(f)?,
+        None => return f.write_str(target),
+    }
+
+    if f.config().inline_urls {
+        write!(f, " ({})", target)?;
+    }
+
+    Ok(())
+}
+
+impl Plain for Tags<'_> {
+    fn fmt_plain(&self, f: &mut PlainFormatter) -> fmt::Result {
+        let tags: Vec<_> = self.0.iter().map(|tag| tag.0.as_ref()).collect();
+        f.write_str(&tags.join(" "))
+    }
+}
+
+impl Page<'_> {
+    /// Renders the page as plain text, placing each block on its own line
+    /// with decorations removed and headers prefixed by `#` per level
+    pub fn to_plain_text(&self, cfg: &PlainConfig) -> String {
+        self.to_plain_string(cfg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::output::parse_page;
+    use indoc::indoc;
+
+    #[test]
+    fn page_to_plain_text_should_flatten_headers_decorations_and_links() {
+        let page = parse_page(indoc! {"
+            == Some *Header* ==
+
+            some *bold* text
+
+            see [[other page|the _other_ page]] or [[index]]
+        "});
+
+        assert_eq!(
+            page.to_plain_text(&PlainConfig::default()),
+            "## Some Header\nsome bold text\nsee the other page or index",
+        );
+
+        assert_eq!(
+            page.to_plain_text(&PlainConfig {
+                inline_urls: true,
+                ..Default::default()
+            }),
+            "## Some Header\nsome bold text\nsee the other page (other page) or index",
+        );
+    }
+}
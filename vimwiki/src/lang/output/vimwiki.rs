@@ -0,0 +1,855 @@
+use super::{push_str_with_newline, Newline};
+use crate::lang::elements::*;
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
+
+/// Represents configuration options when rendering vimwiki
+#[derive(Clone, Debug)]
+pub struct VimwikiConfig {
+    /// Number of spaces to indent each level of nested lists whose original
+    /// indentation is unknown, such as lists built by hand or whose regions
+    /// were stripped
+    pub list_indent: usize,
+
+    /// Line ending written between lines of output
+    pub newline: Newline,
+}
+
+impl Default for VimwikiConfig {
+    fn default() -> Self {
+        Self {
+            list_indent: 4,
+            newline: Newline::default(),
+        }
+    }
+}
+
+/// Represents the destination of vimwiki output, tracking the configuration
+/// and current indentation used while rendering
+pub struct VimwikiFormatter<'a> {
+    config: &'a VimwikiConfig,
+    content: String,
+    indent: usize,
+}
+
+impl<'a> VimwikiFormatter<'a> {
+    pub fn new(config: &'a VimwikiConfig) -> Self {
+        Self {
+            config,
+            content: String::new(),
+            indent: 0,
+        }
+    }
+
+    /// Returns the configuration used by this formatter
+    pub fn config(&self) -> &VimwikiConfig {
+        self.config
+    }
+
+    /// Consumes the formatter, returning the vimwiki that was written
+    pub fn into_content(self) -> String {
+        self.content
+    }
+
+    /// Writes the current indentation as spaces
+    pub fn write_indent(&mut self) -> fmt::Result {
+        for _ in 0..self.indent {
+            self.content.push(' ');
+        }
+
+        Ok(())
+    }
+
+    /// Invokes the function with indentation increased by the given amount,
+    /// restoring the previous indentation afterwards
+    pub fn with_indent<F>(&mut self, amount: usize, f: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Self) -> fmt::Result,
+    {
+        self.indent += amount;
+        let result = f(self);
+        self.indent -= amount;
+        result
+    }
+}
+
+impl Write for VimwikiFormatter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        push_str_with_newline(&mut self.content, s, self.config.newline);
+        Ok(())
+    }
+}
+
+/// Represents an element that can be rendered as vimwiki
+pub trait Vimwiki {
+    /// Writes the vimwiki representation of this element to the formatter
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result;
+}
+
+/// Convenience trait to render an element into a standalone string of vimwiki
+pub trait ToVimwikiString {
+    fn to_vimwiki_string(&self, config: &VimwikiConfig) -> String;
+}
+
+impl<T: Vimwiki> ToVimwikiString for T {
+    fn to_vimwiki_string(&self, config: &VimwikiConfig) -> String {
+        let mut f = VimwikiFormatter::new(config);
+
+        // NOTE: Writing to a string buffer never fails
+        let _ = self.fmt_vimwiki(&mut f);
+        f.into_content()
+    }
+}
+
+impl<T: Vimwiki> Vimwiki for Located<T> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        self.as_inner().fmt_vimwiki(f)
+    }
+}
+
+impl Vimwiki for Page<'_> {
+    /// Writes each block separated by a blank line
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        for (i, element) in self.elements.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            element.fmt_vimwiki(f)?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Vimwiki for BlockElement<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        match self {
+            Self::Blockquote(x) => x.fmt_vimwiki(f),
+            Self::DefinitionList(x) => x.fmt_vimwiki(f),
+            Self::Divider(x) => x.fmt_vimwiki(f),
+            Self::FootnoteDef(x) => x.fmt_vimwiki(f),
+            Self::Header(x) => x.fmt_vimwiki(f),
+            Self::List(x) => x.fmt_vimwiki(f),
+            Self::Math(x) => x.fmt_vimwiki(f),
+            Self::Paragraph(x) => x.fmt_vimwiki(f),
+            Self::Placeholder(x) => x.fmt_vimwiki(f),
+            Self::PreformattedText(x) => x.fmt_vimwiki(f),
+            Self::Signature(x) => x.fmt_vimwiki(f),
+            Self::Table(x) => x.fmt_vimwiki(f),
+        }
+    }
+}
+
+impl Vimwiki for Blockquote<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        for (i, line) in self.lines.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            if line.trim().is_empty() {
+                f.write_str(">")?;
+            } else {
+                write!(f, "> {}", line)?;
+            }
+        }
+
+        if let Some(attribution) = self.attribution.as_ref() {
+            f.write_str("\n> -- ")?;
+            attribution.fmt_vimwiki(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Vimwiki for DefinitionList<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        // NOTE: Terms are stored without order, so we sort them by their
+        //       position in the source to produce stable output
+        let mut terms: Vec<_> = self.iter().collect();
+        terms.sort_by_key(|(term, _)| term.region().offset());
+
+        for (i, (term, definitions)) in terms.into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            term.as_inner().as_inner().fmt_vimwiki(f)?;
+            f.write_str("::")?;
+
+            for (j, definition) in definitions.iter().enumerate() {
+                if j > 0 {
+                    f.write_str("\n::")?;
+                }
+                f.write_str(" ")?;
+                definition.as_inner().as_inner().fmt_vimwiki(f)?;
+                if let Some(list) = definition.list() {
+                    let list_indent = f.config().list_indent;
+                    writeln!(f)?;
+                    f.with_indent(list_indent, |f| list.fmt_vimwiki(f))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Vimwiki for Divider {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        f.write_str("----")
+    }
+}
+
+impl Vimwiki for FootnoteDef<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        write!(f, "[^{}]: ", self.label)?;
+        self.content.fmt_vimwiki(f)
+    }
+}
+
+impl Vimwiki for Header<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        let marker = "=".repeat(self.level);
+        if self.centered {
+            f.write_str(" ")?;
+        }
+        write!(f, "{} ", marker)?;
+        self.content.fmt_vimwiki(f)?;
+        write!(f, " {}", marker)?;
+        if let Some(id) = self.custom_id.as_deref() {
+            write!(f, "#{}", id)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Vimwiki for List<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let column = item.region().start_position().column();
+            fmt_list_item(item.as_inner(), Some(column), f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Vimwiki for ListItem<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        fmt_list_item(self, None, f)
+    }
+}
+
+/// Writes a list item whose prefix starts at the given column in the original
+/// source, if known, so that sublists keep their original indentation
+/// relative to the item
+fn fmt_list_item(
+    item: &ListItem,
+    column: Option<usize>,
+    f: &mut VimwikiFormatter,
+) -> fmt::Result {
+    let prefix = item.to_prefix();
+    f.write_indent()?;
+    write!(f, "{} ", prefix)?;
+
+    if let Some(status) = item.attributes.todo_status {
+        let c = match status {
+            ListItemTodoStatus::Incomplete => ' ',
+            ListItemTodoStatus::PartiallyComplete1 => '.',
+            ListItemTodoStatus::PartiallyComplete2 => 'o',
+            ListItemTodoStatus::PartiallyComplete3 => 'O',
+            ListItemTodoStatus::InProgress => '/',
+            ListItemTodoStatus::Complete => 'X',
+            ListItemTodoStatus::Rejected => '-',
+        };
+        write!(f, "[{}] ", c)?;
+    }
+
+    let list_indent = f.config().list_indent;
+    for (i, content) in item.contents.contents.iter().enumerate() {
+        match content.as_inner() {
+            ListItemContent::InlineContent(x) => {
+                // Additional lines of content align with the text
+                // following the prefix of the item
+                if i > 0 {
+                    writeln!(f)?;
+                    f.with_indent(prefix.len() + 1, |f| f.write_indent())?;
+                }
+                x.fmt_vimwiki(f)?;
+            }
+            ListItemContent::List(x) => {
+                // NOTE: Sublists parsed from the source start further right
+                //       than their parent item, so that gap is the original
+                //       indentation; otherwise, fall back to the config
+                let indent = column
+                    .zip(x.items.first())
+                    .map(|(c, x)| {
+                        x.region().start_position().column().saturating_sub(c)
+                    })
+                    .filter(|indent| *indent > 0)
+                    .unwrap_or(list_indent);
+                writeln!(f)?;
+                f.with_indent(indent, |f| x.fmt_vimwiki(f))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl Vimwiki for MathBlock<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        f.write_str("{{$")?;
+        if let Some(env) = self.environment.as_deref() {
+            write!(f, "%{}%", env)?;
+        }
+        writeln!(f)?;
+        for line in self.lines.iter() {
+            writeln!(f, "{}", line)?;
+        }
+        f.write_str("}}$")
+    }
+}
+
+impl Vimwiki for Paragraph<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        self.content.fmt_vimwiki(f)
+    }
+}
+
+impl Vimwiki for Placeholder<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        match self {
+            Self::Title(x) => write!(f, "%title {}", x),
+            Self::NoHtml => f.write_str("%nohtml"),
+            Self::Template(x) => write!(f, "%template {}", x),
+            Self::Date(x) => write!(f, "%date {}", x),
+            Self::Include(x) => write!(f, "#include {}", x),
+            Self::LinkReference { label, target } => {
+                write!(f, "[{}]: {}", label, target)
+            }
+            Self::Other { name, value } => write!(f, "%{} {}", name, value),
+        }
+    }
+}
+
+impl Vimwiki for PreformattedText<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        f.write_str("{{{")?;
+        if let Some(lang) = self.lang.as_deref() {
+            f.write_str(lang)?;
+        }
+
+        // NOTE: Metadata is stored without order, so we sort it to produce
+        //       stable output
+        let mut metadata: Vec<_> = self.metadata.iter().collect();
+        metadata.sort();
+        for (key, value) in metadata {
+            write!(f, " {}=\"{}\"", key, value)?;
+        }
+        writeln!(f)?;
+
+        for line in self.lines.iter() {
+            writeln!(f, "{}", line)?;
+        }
+        f.write_str("}}}")
+    }
+}
+
+impl Vimwiki for Signature<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        write!(f, "-- {}", self.0)
+    }
+}
+
+impl Vimwiki for Table<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            if self.centered {
+                f.write_str(" ")?;
+            }
+            row.fmt_vimwiki(f)?;
+        }
+
+        if let Some(caption) = self.caption.as_ref() {
+            writeln!(f)?;
+            f.write_str("Table: ")?;
+            caption.fmt_vimwiki(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Vimwiki for Row<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        f.write_str("|")?;
+        match self {
+            Self::Content { cells } => {
+                for cell in cells.iter() {
+                    cell.fmt_vimwiki(f)?;
+                    f.write_str("|")?;
+                }
+            }
+            Self::Divider { columns } => {
+                for column in columns.iter() {
+                    f.write_str(match column {
+                        ColumnAlign::Left => "---",
+                        ColumnAlign::Center => ":-:",
+                        ColumnAlign::Right => "--:",
+                    })?;
+                    f.write_str("|")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Vimwiki for Cell<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        match self {
+            Self::Content(x) => {
+                f.write_str(" ")?;
+
+                // NOTE: Pipes within the text of a cell would otherwise be
+                //       read as column separators, so we escape them
+                for element in x.elements.iter() {
+                    match element.as_inner() {
+                        InlineElement::Text(x) => {
+                            let text = escape_text(&x.0);
+                            f.write_str(&text.replace('|', "\\|"))?
+                        }
+                        x => x.fmt_vimwiki(f)?,
+                    }
+                }
+                f.write_str(" ")
+            }
+            Self::SpanLeft => f.write_str(" > "),
+            Self::SpanAbove => f.write_str(" \\/ "),
+        }
+    }
+}
+
+impl Vimwiki for InlineElementContainer<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        for element in self.elements.iter() {
+            element.fmt_vimwiki(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Vimwiki for InlineElement<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        match self {
+            Self::Text(x) => x.fmt_vimwiki(f),
+            Self::DecoratedText(x) => x.fmt_vimwiki(f),
+            Self::Keyword(x) => x.fmt_vimwiki(f),
+            Self::Link(x) => x.fmt_vimwiki(f),
+            Self::Tags(x) => x.fmt_vimwiki(f),
+            Self::Code(x) => x.fmt_vimwiki(f),
+            Self::Math(x) => x.fmt_vimwiki(f),
+            Self::FootnoteRef(x) => x.fmt_vimwiki(f),
+            Self::Hashtag(x) => write!(f, "#{}", x.name),
+            Self::Comment(x) => x.fmt_vimwiki(f),
+        }
+    }
+}
+
+impl Vimwiki for Text<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        f.write_str(&escape_text(&self.0))
+    }
+}
+
+/// Escapes brackets within text that would otherwise be read as the start of
+/// a link when parsed again
+fn escape_text(text: &str) -> Cow<'_, str> {
+    if text.contains("[[") {
+        Cow::from(text.replace("[[", "\\[\\["))
+    } else {
+        Cow::from(text)
+    }
+}
+
+impl Vimwiki for DecoratedTextContent<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        match self {
+            Self::Text(x) => x.fmt_vimwiki(f),
+            Self::DecoratedText(x) => x.fmt_vimwiki(f),
+            Self::Keyword(x) => x.fmt_vimwiki(f),
+            Self::Link(x) => x.fmt_vimwiki(f),
+            Self::FootnoteRef(x) => x.fmt_vimwiki(f),
+        }
+    }
+}
+
+impl Vimwiki for DecoratedText<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        let (marker, contents) = match self {
+            Self::Bold(x) => ("*", x),
+            Self::Italic(x) => ("_", x),
+            Self::Strikeout(x) => ("~~", x),
+            Self::Superscript(x) => ("^", x),
+            Self::Subscript(x) => (",,", x),
+        };
+
+        f.write_str(marker)?;
+        for content in contents.iter() {
+            content.fmt_vimwiki(f)?;
+        }
+        f.write_str(marker)
+    }
+}
+
+impl Vimwiki for Keyword {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Vimwiki for Link<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        match self {
+            Self::Wiki(x) => {
+                f.write_str("[[")?;
+                fmt_wiki_link_target(f, x)?;
+                fmt_description(f, x.description.as_ref())?;
+                f.write_str("]]")
+            }
+            Self::InterWiki(x) => {
+                f.write_str("[[")?;
+                match x {
+                    InterWikiLink::Indexed(x) => write!(f, "wiki{}:", x.index)?,
+                    InterWikiLink::Named(x) => write!(f, "wn.{}:", x.name)?,
+                }
+                fmt_wiki_link_target(f, x.link())?;
+                fmt_description(f, x.description())?;
+                f.write_str("]]")
+            }
+            Self::Diary(x) => {
+                write!(f, "[[diary:{}", x.date_str)?;
+                if let Some(anchor) = x.anchor.as_ref() {
+                    write!(f, "{}", anchor)?;
+                }
+                fmt_description(f, x.description.as_ref())?;
+                f.write_str("]]")
+            }
+            // NOTE: A bare url cannot be described, so a described url is
+            //       written as a wiki link to the url
+            Self::Raw(x) => match x.description.as_ref() {
+                Some(description) => {
+                    write!(f, "[[{}", x.uri)?;
+                    fmt_description(f, Some(description))?;
+                    f.write_str("]]")
+                }
+                None => write!(f, "{}", x.uri),
+            },
+            Self::ExternalFile(x) => {
+                f.write_str(match x.scheme {
+                    ExternalFileLinkScheme::Local => "[[local:",
+                    ExternalFileLinkScheme::File => "[[file:",
+                    ExternalFileLinkScheme::Absolute => "[[//",
+                })?;
+                f.write_str(&x.path.to_string_lossy())?;
+                fmt_description(f, x.description.as_ref())?;
+                f.write_str("]]")
+            }
+            Self::Transclusion(x) => {
+                write!(f, "{{{{{}", x.uri)?;
+                if let Some(description) = x.description.as_ref() {
+                    write!(f, "|{}", description)?;
+                } else if !x.properties.is_empty() {
+                    f.write_str("|")?;
+                }
+
+                // NOTE: Properties are stored without order, so we sort them
+                //       to produce stable output
+                let mut properties: Vec<_> = x.properties.iter().collect();
+                properties.sort();
+                for (key, value) in properties {
+                    write!(f, "|{}=\"{}\"", key, value)?;
+                }
+                f.write_str("}}")
+            }
+            Self::Reference(x) => write!(f, "[{}][{}]", x.description, x.label),
+        }
+    }
+}
+
+/// Writes the path and anchor of a wiki link
+fn fmt_wiki_link_target(
+    f: &mut VimwikiFormatter,
+    link: &WikiLink,
+) -> fmt::Result {
+    f.write_str(&link.path.to_string_lossy())?;
+    if let Some(anchor) = link.anchor.as_ref() {
+        write!(f, "{}", anchor)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the description of a link preceded by a pipe, if there is one
+fn fmt_description(
+    f: &mut VimwikiFormatter,
+    description: Option<&Description>,
+) -> fmt::Result {
+    match description {
+        Some(Description::Text(x)) => write!(f, "|{}", x),
+        Some(Description::Uri(x)) => write!(f, "|{{{{{}}}}}", x),
+        Some(Description::Inline(x)) => {
+            f.write_str("|")?;
+            x.fmt_vimwiki(f)
+        }
+        None => Ok(()),
+    }
+}
+
+impl Vimwiki for Tags<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        f.write_str(":")?;
+        for tag in self.0.iter() {
+            write!(f, "{}:", tag.0)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Vimwiki for CodeInline<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        write!(f, "`{}`", self.code)
+    }
+}
+
+impl Vimwiki for FootnoteRef<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        write!(f, "[^{}]", self.label)
+    }
+}
+
+impl Vimwiki for MathInline<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        write!(f, "${}$", self.formula)
+    }
+}
+
+impl Vimwiki for Comment<'_> {
+    fn fmt_vimwiki(&self, f: &mut VimwikiFormatter) -> fmt::Result {
+        match self {
+            Self::Line(x) => write!(f, "%%{}", x.0),
+            Self::MultiLine(x) => write!(f, "%%+{}+%%", x.0.join("\n")),
+        }
+    }
+}
+
+impl List<'_> {
+    /// Renders the list as vimwiki using the default configuration,
+    /// preserving the marker style of each list and sublist as well as the
+    /// original indentation of sublists parsed from the source
+    pub fn to_vimwiki(&self) -> String {
+        self.to_vimwiki_string(&VimwikiConfig::default())
+    }
+}
+
+impl Paragraph<'_> {
+    /// Renders the paragraph as vimwiki with its words wrapped onto lines of
+    /// at most `width` columns. Elements other than text, such as links,
+    /// code, and decorated text, are never broken across lines, so a line
+    /// holding one wider than `width` will exceed it
+    pub fn reflow(&self, width: usize) -> String {
+        let config = VimwikiConfig::default();
+        let mut words = Vec::new();
+        let mut word = String::new();
+        let mut last_end = None;
+
+        for element in self.content.elements.iter() {
+            // NOTE: Lines of a paragraph are joined without whitespace, so a
+            //       gap between the regions of elements separates words
+            let region = element.region();
+            if last_end.is_some_and(|end| region.offset() > end)
+                && !word.is_empty()
+            {
+                words.push(std::mem::take(&mut word));
+            }
+            last_end = Some(region.end());
+
+            match element.as_inner() {
+                InlineElement::Text(x) => {
+                    for c in escape_text(&x.0).chars() {
+                        if !c.is_whitespace() {
+                            word.push(c);
+                        } else if !word.is_empty() {
+                            words.push(std::mem::take(&mut word));
+                        }
+                    }
+                }
+                x => word.push_str(&x.to_vimwiki_string(&config)),
+            }
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        let mut line_width = 0;
+        for word in words {
+            let word_width = word.chars().count();
+            if !line.is_empty() && line_width + 1 + word_width > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            if !line.is_empty() {
+                line.push(' ');
+                line_width += 1;
+            }
+            line.push_str(&word);
+            line_width += word_width;
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lang::output::parse_page, Language};
+    use std::convert::TryFrom;
+    use uriparse::URI;
+
+    #[test]
+    fn list_to_vimwiki_should_preserve_markers_of_sublists() {
+        let input = "- one\n    1. sub one\n    2. sub two\n- two\n    a) sub three\n- [X] three";
+        let page = parse_page(input);
+        let list = match page.elements[0].as_inner() {
+            BlockElement::List(x) => x,
+            x => panic!("Unexpected element: {:?}", x),
+        };
+
+        assert_eq!(list.to_vimwiki(), input);
+    }
+
+    #[test]
+    fn list_to_vimwiki_should_preserve_indentation_of_sublists() {
+        let input = "- one\n  1. sub one\n     - deep\n  2. sub two\n- two\n\
+                     \x20     a) sub three";
+        let page = parse_page(input);
+        let list = match page.elements[0].as_inner() {
+            BlockElement::List(x) => x,
+            x => panic!("Unexpected element: {:?}", x),
+        };
+
+        assert_eq!(list.to_vimwiki(), input);
+
+        // Without regions, sublists fall back to the configured indentation
+        let mut list = list.clone();
+        list.for_each_region_mut(&mut |region| *region = Region::default());
+        assert_eq!(
+            list.to_vimwiki(),
+            "- one\n    1. sub one\n        - deep\n    2. sub two\n- two\n\
+             \x20   a) sub three"
+        );
+    }
+
+    #[test]
+    fn link_to_vimwiki_string_should_preserve_decorated_description() {
+        let input = "[[page|*bold* and _italic_ desc]]";
+        let link: Located<Link> =
+            Language::from_vimwiki_str(input).parse().unwrap();
+
+        assert_eq!(link.to_vimwiki_string(&VimwikiConfig::default()), input);
+    }
+
+    #[test]
+    fn link_to_vimwiki_string_should_write_described_url_as_wiki_link() {
+        let link =
+            Link::url(URI::try_from("https://example.com/page").unwrap())
+                .with_description("example");
+
+        assert_eq!(
+            link.to_vimwiki_string(&VimwikiConfig::default()),
+            "[[https://example.com/page|example]]"
+        );
+        assert_eq!(
+            Link::url(URI::try_from("https://example.com/page").unwrap())
+                .to_vimwiki_string(&VimwikiConfig::default()),
+            "https://example.com/page"
+        );
+    }
+
+    #[test]
+    fn page_to_vimwiki_string_should_separate_blocks_with_blank_lines() {
+        let input = "= *Header* =\n\nsome `code` and [[link|text]]\n";
+        let page = parse_page(input);
+
+        assert_eq!(page.to_vimwiki_string(&VimwikiConfig::default()), input);
+    }
+
+    #[test]
+    fn page_to_vimwiki_string_should_keep_escaped_brackets_as_text() {
+        let input = "\\[\\[not a link]] and [[a link]]\n";
+        let page = parse_page(input);
+
+        assert_eq!(page.to_vimwiki_string(&VimwikiConfig::default()), input);
+    }
+
+    #[test]
+    fn paragraph_reflow_should_wrap_words_without_splitting_elements() {
+        let page = parse_page(
+            "This is a long paragraph with [[Page|a link]] and\n\
+             some `x + y` code that should *wrap nicely*.\n",
+        );
+        let paragraph = match page.elements[0].as_inner() {
+            BlockElement::Paragraph(x) => x,
+            x => panic!("Unexpected element: {:?}", x),
+        };
+
+        let text = paragraph.reflow(20);
+        assert_eq!(
+            text,
+            "This is a long\n\
+             paragraph with\n\
+             [[Page|a link]] and\n\
+             some `x + y` code\n\
+             that should\n\
+             *wrap nicely*."
+        );
+        assert!(text.lines().all(|line| line.chars().count() <= 20));
+        assert!(text.lines().any(|line| line.contains("[[Page|a link]]")));
+    }
+
+    #[test]
+    fn page_to_vimwiki_string_should_use_configured_newline() {
+        let page = parse_page("= Header =\n\nsome text\n");
+        let config = VimwikiConfig {
+            newline: Newline::Crlf,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            page.to_vimwiki_string(&config),
+            "= Header =\r\n\r\nsome text\r\n"
+        );
+    }
+}
@@ -35,12 +35,17 @@ impl<'a> fmt::Display for LangParserError<'a> {
 
         // Produce the first line of our input, limiting to no more than
         // 100 characters to prevent really long lines
+        //
+        // NOTE: The input is not guaranteed to be UTF-8, so we fall back to
+        //       a lossy representation rather than risk undefined behavior
+        let remaining = match self.input.remaining_str() {
+            Ok(x) => Cow::Borrowed(x),
+            Err(_) => String::from_utf8_lossy(self.input.as_remaining()),
+        };
         writeln!(
             f,
             "{}",
-            &self
-                .input
-                .as_unsafe_remaining_str()
+            &remaining
                 .lines()
                 .next()
                 .unwrap_or_default()
@@ -75,6 +80,49 @@ impl<'a> LangParserError<'a> {
             next: None,
         }
     }
+
+    /// Renders the error against the source it was produced from, showing
+    /// the line containing each failure with a caret beneath its column
+    pub fn render_with_source(&self, source: &str) -> String {
+        let mut output = String::new();
+        let mut next = Some(self);
+
+        while let Some(err) = next {
+            // NOTE: Clamp the offset to a character boundary in case the
+            //       source is not the one the error was produced from
+            let mut offset = err.input.start_offset().min(source.len());
+            while !source.is_char_boundary(offset) {
+                offset -= 1;
+            }
+
+            let start_of_line =
+                source[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+            let end_of_line = source[offset..]
+                .find('\n')
+                .map_or(source.len(), |pos| offset + pos);
+            let line =
+                source[start_of_line..end_of_line].trim_end_matches('\r');
+
+            // Keep tabs in the padding so the caret lines up with the line
+            let padding: String = source[start_of_line..offset]
+                .chars()
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+
+            output.push_str(&format!(
+                "{}: Line {}, Column {}\n{}\n{}^\n",
+                err.ctx,
+                source[..start_of_line].matches('\n').count() + 1,
+                padding.chars().count() + 1,
+                line,
+                padding,
+            ));
+
+            next = err.next.as_deref();
+        }
+
+        output
+    }
 }
 
 impl<'a, E> FromExternalError<Span<'a>, E> for LangParserError<'a> {
@@ -126,3 +174,45 @@ impl<'a> ContextError<Span<'a>> for LangParserError<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn error_should_be_send_and_sync() {
+        assert_send_sync::<LangParserError<'static>>();
+        assert_send_sync::<Span<'static>>();
+    }
+
+    #[test]
+    fn render_with_source_should_draw_caret_under_failing_column() {
+        let source = "first line\nsecond *bad line\nthird line";
+        let input = Span::from(source).starting_at(18);
+        let err = LangParserError::from_ctx(&input, "Decorated Text");
+
+        assert_eq!(
+            err.render_with_source(source),
+            "Decorated Text: Line 2, Column 8\nsecond *bad line\n       ^\n"
+        );
+    }
+
+    #[test]
+    fn render_with_source_should_render_each_error_in_chain() {
+        let source = "\tsome *text";
+        let inner = LangParserError::from_ctx(
+            &Span::from(source).starting_at(6),
+            "Inner",
+        );
+        let err =
+            LangParserError::add_context(Span::from(source), "Outer", inner);
+
+        assert_eq!(
+            err.render_with_source(source),
+            "Outer: Line 1, Column 1\n\tsome *text\n^\n\
+             Inner: Line 1, Column 7\n\tsome *text\n\t     ^\n"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 mod errors;
 mod span;
+mod state;
 mod utils;
 
 /// Vimwiki-specific parsers
@@ -8,6 +9,11 @@ pub mod vimwiki;
 /// Export the span used for input
 pub use span::Span;
 
+/// Export the configuration and diagnostics shared across a parse
+pub use state::{
+    ParseConfig, ParseDiagnostic, ParseDiagnosticKind, ParseState,
+};
+
 /// Alias to the type of error to use with parsing using nom
 pub use errors::LangParserError as Error;
 
@@ -1,3 +1,4 @@
+use super::{ParseConfig, ParseDiagnostic, ParseState};
 use memchr::{memchr2_iter, memchr_iter, memrchr};
 use nom::{
     error::{ErrorKind, ParseError},
@@ -7,22 +8,27 @@ use nom::{
 };
 use std::{
     borrow::Cow,
+    collections::HashMap,
     convert::TryFrom,
     fmt::{Display, Formatter, Result as FmtResult},
     iter::Enumerate,
     ops::{Range, RangeFrom, RangeFull, RangeTo},
     path::Path,
-    str::FromStr,
+    str::{FromStr, Utf8Error},
 };
 
 /// Represents a span across some input, which is passed around to various
 /// parser combinators to examine and process
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///
+/// NOTE: The parse state carried by a span is not part of its identity, so
+///       it is ignored when comparing or debugging spans
+#[derive(Copy, Clone)]
 pub struct Span<'a> {
     inner: &'a [u8],
     start: usize,
     end: usize,
     depth: u16,
+    state: Option<&'a ParseState>,
 }
 
 impl<'a> Span<'a> {
@@ -36,6 +42,7 @@ impl<'a> Span<'a> {
             start,
             end,
             depth,
+            state: None,
         }
     }
 
@@ -47,12 +54,10 @@ impl<'a> Span<'a> {
     pub fn starting_at(&self, start: usize) -> Self {
         let start = self.start + start;
         let end = self.end;
-        Self::new(
-            self.inner,
-            if start > end { end } else { start },
-            end,
-            self.depth,
-        )
+        Self {
+            start: if start > end { end } else { start },
+            ..*self
+        }
     }
 
     /// Creates a copy of the span ending at the new offset (exclusive)
@@ -60,7 +65,10 @@ impl<'a> Span<'a> {
     ///
     /// e.g. start = 2, end = 4, ending_at(1) yields end = 3
     pub fn ending_at(&self, end: usize) -> Self {
-        Self::new(self.inner, self.start, self.start + end, self.depth)
+        Self {
+            end: self.start + end,
+            ..*self
+        }
     }
 
     /// Creates a copy of the span starting at the end of its range. The
@@ -78,7 +86,10 @@ impl<'a> Span<'a> {
     /// greater than the current remaining len will do nothing.
     pub fn with_length(&self, len: usize) -> Self {
         if len < self.remaining_len() {
-            Self::new(self.inner, self.start, self.start + len, self.depth)
+            Self {
+                end: self.start + len,
+                ..*self
+            }
         } else {
             *self
         }
@@ -91,7 +102,7 @@ impl<'a> Span<'a> {
 
     /// Returns a copy of the span whose depth is the specified depth
     pub fn with_depth(&self, depth: u16) -> Self {
-        Self::new(self.inner, self.start, self.end, depth)
+        Self { depth, ..*self }
     }
 
     /// Returns a copy of the span with a depth one deeper than the current span
@@ -110,6 +121,139 @@ impl<'a> Span<'a> {
         self.with_depth(depth)
     }
 
+    /// Returns a copy of the span that uses the provided state to configure
+    /// parsers and collect diagnostics
+    pub fn with_state(&self, state: &'a ParseState) -> Self {
+        Self {
+            state: Some(state),
+            ..*self
+        }
+    }
+
+    /// Returns the maximum depth that elements can be nested, falling back
+    /// to the default configuration if no state was provided
+    pub fn max_nesting_depth(&self) -> u16 {
+        self.state
+            .map(|state| state.config().max_nesting_depth)
+            .unwrap_or_else(|| ParseConfig::default().max_nesting_depth)
+    }
+
+    /// Returns whether a single blank line is allowed between list items,
+    /// falling back to the default configuration if no state was provided
+    pub fn lists_allow_blank_lines(&self) -> bool {
+        self.state
+            .map(|state| state.config().lists_allow_blank_lines)
+            .unwrap_or_else(|| ParseConfig::default().lists_allow_blank_lines)
+    }
+
+    /// Returns whether the original text of elements should be retained,
+    /// falling back to the default configuration if no state was provided
+    pub fn retain_raw(&self) -> bool {
+        self.state
+            .map(|state| state.config().retain_raw)
+            .unwrap_or_else(|| ParseConfig::default().retain_raw)
+    }
+
+    /// Returns whether signature lines should be parsed, falling back to
+    /// the default configuration if no state was provided
+    pub fn parse_signatures(&self) -> bool {
+        self.state
+            .map(|state| state.config().parse_signatures)
+            .unwrap_or_else(|| ParseConfig::default().parse_signatures)
+    }
+
+    /// Returns the minimum number of hyphens that make up a divider, falling
+    /// back to the default configuration if no state was provided
+    pub fn min_divider_dashes(&self) -> usize {
+        self.state
+            .map(|state| state.config().min_divider_dashes)
+            .unwrap_or_else(|| ParseConfig::default().min_divider_dashes)
+    }
+
+    /// Returns the number of columns that a tab counts for within list
+    /// indentation, falling back to the default configuration if no state
+    /// was provided
+    pub fn tab_width(&self) -> usize {
+        self.state
+            .map(|state| state.config().tab_width)
+            .unwrap_or_else(|| ParseConfig::default().tab_width)
+    }
+
+    /// Returns whether hashtags should be parsed, falling back to the
+    /// default configuration if no state was provided
+    pub fn parse_hashtags(&self) -> bool {
+        self.state
+            .map(|state| state.config().parse_hashtags)
+            .unwrap_or_else(|| ParseConfig::default().parse_hashtags)
+    }
+
+    /// Returns whether bare URLs within text should be parsed as links,
+    /// falling back to the default configuration if no state was provided
+    pub fn autolink_urls(&self) -> bool {
+        self.state
+            .map(|state| state.config().autolink_urls)
+            .unwrap_or_else(|| ParseConfig::default().autolink_urls)
+    }
+
+    /// Returns whether reference links should be parsed, falling back to
+    /// the default configuration if no state was provided
+    pub fn reference_links(&self) -> bool {
+        self.state
+            .map(|state| state.config().reference_links)
+            .unwrap_or_else(|| ParseConfig::default().reference_links)
+    }
+
+    /// Returns whether indented lines should be parsed as preformatted
+    /// text, falling back to the default configuration if no state was
+    /// provided
+    pub fn indented_code_blocks(&self) -> bool {
+        self.state
+            .map(|state| state.config().indented_code_blocks)
+            .unwrap_or_else(|| ParseConfig::default().indented_code_blocks)
+    }
+
+    /// Returns whether input missing a trailing newline should be treated
+    /// as having one, falling back to the default configuration if no state
+    /// was provided
+    pub fn assume_trailing_newline(&self) -> bool {
+        self.state
+            .map(|state| state.config().assume_trailing_newline)
+            .unwrap_or_else(|| ParseConfig::default().assume_trailing_newline)
+    }
+
+    /// Returns whether emphasis should be parsed as in markdown, falling
+    /// back to the default configuration if no state was provided
+    pub fn markdown_emphasis(&self) -> bool {
+        self.state
+            .map(|state| state.config().markdown_emphasis)
+            .unwrap_or_else(|| ParseConfig::default().markdown_emphasis)
+    }
+
+    /// Reports a diagnostic to the state shared across the parse, doing
+    /// nothing if no state was provided
+    pub fn report(&self, diagnostic: ParseDiagnostic) {
+        if let Some(state) = self.state {
+            state.report(diagnostic);
+        }
+    }
+
+    /// Looks up the target of a reference definition with the given label,
+    /// gathering definitions using `collect` only once across the parse; if
+    /// no state was provided, they are gathered on every lookup
+    pub fn reference_definition<F>(
+        &self,
+        label: &str,
+        collect: F,
+    ) -> Option<String>
+    where
+        F: FnOnce() -> HashMap<String, String>,
+    {
+        match self.state {
+            Some(state) => state.reference_definition(label, collect),
+            None => collect().remove(label),
+        }
+    }
+
     /// Represents the inner byte slice starting from the original span
     /// (offset not applied)
     pub fn as_inner(&self) -> &[u8] {
@@ -166,6 +310,12 @@ impl<'a> Span<'a> {
         unsafe { std::str::from_utf8_unchecked(self.as_remaining()) }
     }
 
+    /// Represents the remaining input as a str, failing if the remaining
+    /// bytes are not valid UTF-8
+    pub fn remaining_str(&self) -> Result<&str, Utf8Error> {
+        std::str::from_utf8(self.as_remaining())
+    }
+
     /// Represents the total number of bytes remaining from the input
     pub fn remaining_len(&self) -> usize {
         if self.start <= self.end {
@@ -191,20 +341,20 @@ impl<'a> Span<'a> {
         // Iterate through all space and tabs until either we detect some
         // character that is not space (gap in index) or we find no more
         // whitespace even though we have more bytes remaining
-        let mut last_pos = 0;
+        let mut next_pos = 0;
         for pos in memchr2_iter(b' ', b'\t', self.as_remaining()) {
-            // If we skipped at least one position beyond what would be next,
-            // there was something that didn't match and we can exit
-            if pos - last_pos > 1 {
+            // If we skipped the position that would be next, there was
+            // something that didn't match and we can exit
+            if pos != next_pos {
                 return false;
             }
 
-            last_pos = pos;
+            next_pos = pos + 1;
         }
 
-        // If our last position doesn't correspond to the end of the remaining
+        // If our next position doesn't correspond to the end of the remaining
         // bytes, there is something that is not whitespace
-        last_pos == (len - 1)
+        next_pos == len
     }
 
     /// Calculates the line and column position of this span using newline (\n)
@@ -270,6 +420,17 @@ impl<'a> Span<'a> {
     }
 }
 
+impl<'a> std::fmt::Debug for Span<'a> {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        fmt.debug_struct("Span")
+            .field("inner", &self.inner)
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("depth", &self.depth)
+            .finish()
+    }
+}
+
 impl<'a> Display for Span<'a> {
     /// Displays the span's inner byte slice as a UTF-8 str starting from the
     /// span's offset, or if the byte slice is not a UTF-8 str will display
@@ -298,6 +459,19 @@ impl Default for Span<'_> {
 /* BEGIN EQUALITY HELPERS                                                    */
 /*****************************************************************************/
 
+impl<'a> PartialEq for Span<'a> {
+    /// Tests whether two spans cover the same bytes at the same depth,
+    /// regardless of the parse state they carry
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+            && self.start == other.start
+            && self.end == other.end
+            && self.depth == other.depth
+    }
+}
+
+impl<'a> Eq for Span<'a> {}
+
 impl<'a> PartialEq<&'a str> for Span<'a> {
     /// Tests whether the bytes represented by this span equal the given str
     fn eq(&self, other: &&'a str) -> bool {
@@ -546,7 +720,7 @@ impl<'a> InputIter for Span<'a> {
 
     #[inline]
     fn iter_elements(&self) -> Self::IterElem {
-        self.clone().into_iter().copied()
+        (*self).into_iter().copied()
     }
 
     #[inline]
@@ -776,7 +950,7 @@ mod tests {
         #[test]
         fn new_builder_should_create_an_empty_byte_vec() {
             let span1 = Span::from("abc");
-            assert_eq!(span1.new_builder(), vec![]);
+            assert_eq!(span1.new_builder(), Vec::<u8>::new());
         }
 
         #[test]
@@ -1162,4 +1336,37 @@ mod tests {
             assert_eq!(span2.as_bytes(), b"ab");
         }
     }
+
+    #[test]
+    fn remaining_str_should_match_unsafe_remaining_str_for_valid_utf8() {
+        let span = Span::from("abc ü 123").starting_at(2);
+        assert_eq!(
+            span.remaining_str().unwrap(),
+            span.as_unsafe_remaining_str()
+        );
+        assert_eq!(span.remaining_str().unwrap(), "c ü 123");
+    }
+
+    #[test]
+    fn remaining_str_should_fail_for_invalid_utf8() {
+        let span = Span::from(&[b'a', 0xff, b'b'][..]);
+        assert!(span.remaining_str().is_err());
+    }
+
+    #[test]
+    fn is_only_whitespace_should_yield_true_if_remaining_bytes_are_spaces_or_tabs(
+    ) {
+        assert!(Span::from("").is_only_whitespace());
+        assert!(Span::from(" \t ").is_only_whitespace());
+        assert!(Span::from("a  ").starting_at(1).is_only_whitespace());
+    }
+
+    #[test]
+    fn is_only_whitespace_should_yield_false_if_any_remaining_byte_is_not_whitespace(
+    ) {
+        assert!(!Span::from("}").is_only_whitespace());
+        assert!(!Span::from("a ").is_only_whitespace());
+        assert!(!Span::from(" a").is_only_whitespace());
+        assert!(!Span::from(" a ").is_only_whitespace());
+    }
 }
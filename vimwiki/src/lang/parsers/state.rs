@@ -0,0 +1,258 @@
+use crate::lang::elements::Region;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    sync::{Mutex, MutexGuard},
+};
+
+/// Represents configuration options when parsing
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ParseConfig {
+    /// Maximum depth that elements can be nested before inline decorations
+    /// and sublists stop being parsed, at which point decoration markers are
+    /// treated as text and sublist items as content of their parent item
+    pub max_nesting_depth: u16,
+
+    /// If true, a single blank line between list items keeps them within
+    /// the same list; otherwise, any blank line terminates a list
+    pub lists_allow_blank_lines: bool,
+
+    /// If true, block elements parsed as `Retained` keep the original text
+    /// they were parsed from so they can be written back out verbatim
+    pub retain_raw: bool,
+
+    /// If true, a final line of the form `-- Author, Date` is parsed as a
+    /// signature instead of as part of a paragraph
+    pub parse_signatures: bool,
+
+    /// Minimum number of hyphens that make up a divider, where lines with
+    /// fewer are parsed as other elements
+    pub min_divider_dashes: usize,
+
+    /// Number of columns that a tab counts for when measuring the
+    /// indentation of list items, so nesting can be compared between items
+    /// indented with tabs and those indented with spaces
+    pub tab_width: usize,
+
+    /// If true, `#word` within text (preceded by whitespace and not at the
+    /// start of a line) is parsed as a hashtag instead of as text
+    pub parse_hashtags: bool,
+
+    /// If true, bare `http://` and `https://` URLs within text are parsed
+    /// as raw links, leaving any trailing punctuation as text
+    pub autolink_urls: bool,
+
+    /// If true, markdown-style reference links (`[text][label]` and
+    /// `[text][]`) are parsed as links to the target of the matching
+    /// `[label]: target` definition within the page
+    pub reference_links: bool,
+
+    /// If true, markdown-style blocks of lines indented by four spaces or a
+    /// tab are parsed as preformatted text instead of as blockquotes
+    pub indented_code_blocks: bool,
+
+    /// If true, input missing a trailing newline is treated as if it had one
+    /// when computing the region of the last block, so that the region is
+    /// the same whether or not the input ends with a newline. Only the
+    /// region of the block itself is extended; elements nested within it,
+    /// such as the last item of a list, keep ending before the newline
+    pub assume_trailing_newline: bool,
+
+    /// If true, emphasis is parsed as in markdown, where `**text**` and
+    /// `__text__` are bold and `*text*` and `_text_` are italic, instead of
+    /// as in vimwiki, where `*text*` is bold and `_text_` is italic
+    pub markdown_emphasis: bool,
+}
+
+impl Default for ParseConfig {
+    fn default() -> Self {
+        Self {
+            max_nesting_depth: 64,
+            lists_allow_blank_lines: false,
+            retain_raw: false,
+            parse_signatures: false,
+            min_divider_dashes: 4,
+            tab_width: 1,
+            parse_hashtags: false,
+            autolink_urls: false,
+            reference_links: false,
+            indented_code_blocks: false,
+            assume_trailing_newline: false,
+            markdown_emphasis: false,
+        }
+    }
+}
+
+/// Represents the kind of issue reported by a `ParseDiagnostic`
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ParseDiagnosticKind {
+    /// Nesting went past the configured maximum depth and the remaining
+    /// markers were treated as text
+    MaxNestingDepthExceeded,
+
+    /// Lines within a list were indented using a mix of tabs and spaces,
+    /// which can nest items differently than they appear
+    MixedListIndentation,
+
+    /// An include referenced a file that was already being included, so it
+    /// was left in place instead of being resolved again
+    IncludeCycle,
+
+    /// An include referenced a file that could not be provided or parsed,
+    /// so it was left in place
+    IncludeUnresolved,
+
+    /// A reference link used a label that has no definition within the
+    /// page, so it was kept as text
+    UndefinedReference,
+}
+
+/// Represents a non-fatal issue encountered while parsing
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ParseDiagnostic {
+    kind: ParseDiagnosticKind,
+    region: Region,
+}
+
+impl ParseDiagnostic {
+    pub fn new(kind: ParseDiagnosticKind, region: Region) -> Self {
+        Self { kind, region }
+    }
+
+    /// The kind of issue that was encountered
+    pub fn kind(&self) -> ParseDiagnosticKind {
+        self.kind
+    }
+
+    /// The region of the input where the issue was encountered
+    pub fn region(&self) -> Region {
+        self.region
+    }
+}
+
+/// Represents the diagnostics reported during a parse in the order they were
+/// reported, alongside the kind and span of each to detect duplicates
+#[derive(Default)]
+struct Diagnostics {
+    list: Vec<ParseDiagnostic>,
+    seen: HashSet<(ParseDiagnosticKind, usize, usize)>,
+}
+
+/// Represents the state shared across a single parse, containing the
+/// configuration used by parsers and the diagnostics they report
+///
+/// NOTE: The state is guarded by mutexes rather than cells so that spans
+///       and errors referencing it remain `Send` and `Sync`
+#[derive(Default)]
+pub struct ParseState {
+    config: ParseConfig,
+    diagnostics: Mutex<Diagnostics>,
+    reference_definitions: Mutex<Option<HashMap<String, String>>>,
+}
+
+impl fmt::Debug for ParseState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseState")
+            .field("config", &self.config)
+            .field("diagnostics", &self.lock_diagnostics().list)
+            .finish()
+    }
+}
+
+impl ParseState {
+    pub fn new(config: ParseConfig) -> Self {
+        Self {
+            config,
+            diagnostics: Mutex::new(Diagnostics::default()),
+            reference_definitions: Mutex::new(None),
+        }
+    }
+
+    /// Locks the diagnostics, recovering them if a parser panicked while
+    /// holding the lock as they are never left partially updated
+    fn lock_diagnostics(&self) -> MutexGuard<'_, Diagnostics> {
+        match self.diagnostics.lock() {
+            Ok(x) => x,
+            Err(x) => x.into_inner(),
+        }
+    }
+
+    /// Returns the configuration used by parsers
+    pub fn config(&self) -> &ParseConfig {
+        &self.config
+    }
+
+    /// Returns a copy of the diagnostics reported so far
+    pub fn diagnostics(&self) -> Vec<ParseDiagnostic> {
+        self.lock_diagnostics().list.clone()
+    }
+
+    /// Consumes the state, returning the diagnostics that were reported
+    pub fn into_diagnostics(self) -> Vec<ParseDiagnostic> {
+        match self.diagnostics.into_inner() {
+            Ok(x) => x.list,
+            Err(x) => x.into_inner().list,
+        }
+    }
+
+    /// Records a diagnostic, ignoring it if one of the same kind was already
+    /// reported for the same span of input as parsers can revisit the input
+    /// when backtracking, possibly at a different depth
+    pub fn report(&self, diagnostic: ParseDiagnostic) {
+        let mut diagnostics = self.lock_diagnostics();
+        let key = (
+            diagnostic.kind,
+            diagnostic.region.offset(),
+            diagnostic.region.len(),
+        );
+        if diagnostics.seen.insert(key) {
+            diagnostics.list.push(diagnostic);
+        }
+    }
+
+    /// Returns the target of the reference definition with the given label,
+    /// using `collect` to gather the definitions of the input being parsed
+    /// the first time that any definition is looked up
+    pub fn reference_definition<F>(
+        &self,
+        label: &str,
+        collect: F,
+    ) -> Option<String>
+    where
+        F: FnOnce() -> HashMap<String, String>,
+    {
+        let mut definitions = match self.reference_definitions.lock() {
+            Ok(x) => x,
+            Err(x) => x.into_inner(),
+        };
+        definitions.get_or_insert_with(collect).get(label).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_should_ignore_diagnostic_of_same_kind_and_span() {
+        let state = ParseState::default();
+        let kind = ParseDiagnosticKind::UndefinedReference;
+        state.report(ParseDiagnostic::new(kind, Region::new_at_depth(3, 4, 0)));
+        state.report(ParseDiagnostic::new(kind, Region::new_at_depth(3, 4, 2)));
+        state.report(ParseDiagnostic::new(kind, Region::new_at_depth(3, 5, 0)));
+        state.report(ParseDiagnostic::new(
+            ParseDiagnosticKind::IncludeCycle,
+            Region::new_at_depth(3, 4, 0),
+        ));
+
+        assert_eq!(
+            state
+                .into_diagnostics()
+                .iter()
+                .map(|x| (x.kind(), x.region().len()))
+                .collect::<Vec<_>>(),
+            vec![(kind, 4), (kind, 5), (ParseDiagnosticKind::IncludeCycle, 4)]
+        );
+    }
+}
@@ -2,9 +2,10 @@ use crate::lang::{
     elements::{Blockquote, Located},
     parsers::{
         utils::{
-            beginning_of_line, blank_line, capture, context, cow_str,
+            beginning_of_line, blank_line, capture, context,
             end_of_line_or_input, locate,
         },
+        vimwiki::blocks::inline::inline_element_container,
         IResult, Span,
     },
 };
@@ -12,7 +13,7 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{not_line_ending, space0},
-    combinator::{map, map_parser, value, verify},
+    combinator::{map, value, verify},
     multi::{many0, many1},
     sequence::pair,
 };
@@ -20,7 +21,7 @@ use std::borrow::Cow;
 
 pub fn blockquote(input: Span) -> IResult<Located<Blockquote>> {
     fn inner(input: Span) -> IResult<Blockquote> {
-        let (input, lines) = alt((
+        let (input, mut lines) = alt((
             // NOTE: Indented blockquotes do not allow blank lines
             many1(blockquote_line_1),
             // NOTE: > blockquotes allow blank lines inbetween
@@ -29,7 +30,7 @@ pub fn blockquote(input: Span) -> IResult<Located<Blockquote>> {
                     many1(blockquote_line_2),
                     map(
                         many0(pair(
-                            many0(value(Cow::from(""), blank_line)),
+                            many0(value(Span::default(), blank_line)),
                             blockquote_line_2,
                         )),
                         |pairs| {
@@ -46,21 +47,61 @@ pub fn blockquote(input: Span) -> IResult<Located<Blockquote>> {
                 |(head, rest)| vec![head, rest].concat(),
             ),
         ))(input)?;
-        Ok((input, Blockquote::new(lines)))
+
+        // A trailing line of the form `-- Author` is treated as the source
+        // of the quote rather than part of it, so long as there is
+        // something left being quoted
+        let attribution = if lines.len() > 1 {
+            lines
+                .last()
+                .copied()
+                .and_then(attribution_content)
+                .and_then(|x| inline_element_container(x).ok())
+                .map(|(_, x)| x.into_inner())
+        } else {
+            None
+        };
+
+        if attribution.is_some() {
+            lines.pop();
+            while lines
+                .last()
+                .is_some_and(|x| x.as_unsafe_remaining_str().trim().is_empty())
+            {
+                lines.pop();
+            }
+        }
+
+        let lines = lines.into_iter().map(Cow::from).collect();
+        Ok((input, Blockquote::new(lines, attribution)))
     }
 
     context("Blockquote", locate(capture(inner)))(input)
 }
 
+/// Returns the portion of a blockquote line following a leading `-- `, with
+/// surrounding whitespace removed, if the line is an attribution
+fn attribution_content(line: Span) -> Option<Span> {
+    let text = line.as_unsafe_remaining_str();
+    let rest = text.trim_start().strip_prefix("-- ")?.trim_start();
+    let content = rest.trim_end();
+    if content.is_empty() {
+        return None;
+    }
+
+    Some(
+        line.starting_at(text.len() - rest.len())
+            .with_length(content.len()),
+    )
+}
+
 /// Parses a blockquote line that begins with four or more spaces
 #[inline]
-fn blockquote_line_1<'a>(input: Span<'a>) -> IResult<Cow<'a, str>> {
+fn blockquote_line_1<'a>(input: Span<'a>) -> IResult<'a, Span<'a>> {
     let (input, _) = beginning_of_line(input)?;
     let (input, _) = verify(space0, |s: &Span| s.remaining_len() >= 4)(input)?;
-    let (input, text) = map_parser(
-        verify(not_line_ending, |s: &Span<'a>| !s.is_only_whitespace()),
-        cow_str,
-    )(input)?;
+    let (input, text) =
+        verify(not_line_ending, |s: &Span<'a>| !s.is_only_whitespace())(input)?;
     let (input, _) = end_of_line_or_input(input)?;
 
     Ok((input, text))
@@ -68,10 +109,10 @@ fn blockquote_line_1<'a>(input: Span<'a>) -> IResult<Cow<'a, str>> {
 
 /// Parses a blockquote line that begins with >
 #[inline]
-fn blockquote_line_2<'a>(input: Span<'a>) -> IResult<Cow<'a, str>> {
+fn blockquote_line_2<'a>(input: Span<'a>) -> IResult<'a, Span<'a>> {
     let (input, _) = beginning_of_line(input)?;
     let (input, _) = tag("> ")(input)?;
-    let (input, text) = map_parser(not_line_ending, cow_str)(input)?;
+    let (input, text) = not_line_ending(input)?;
     let (input, _) = end_of_line_or_input(input)?;
 
     Ok((input, text))
@@ -211,4 +252,43 @@ mod tests {
         assert_eq!(bq.lines()[3], "This is another blockquote");
         assert_eq!(bq.lines()[4], "that is using prefixes");
     }
+
+    #[test]
+    fn blockquote_should_parse_trailing_attribution_line() {
+        let input = Span::from(indoc! {"
+        > Some quoted text
+        > spanning lines
+
+        > -- *Some* Author
+        "});
+        let (input, bq) = blockquote(input).unwrap();
+        assert!(input.is_empty(), "Did not consume blockquote");
+
+        assert_eq!(bq.lines(), &["Some quoted text", "spanning lines"]);
+
+        let attribution = bq.attribution().expect("Missing attribution");
+        assert_eq!(attribution.to_string(), "Some Author");
+        assert_eq!(
+            attribution.elements[0].region().offset(),
+            42,
+            "Attribution region not relative to input"
+        );
+    }
+
+    #[test]
+    fn blockquote_should_not_have_attribution_if_no_trailing_author_line() {
+        let input = Span::from(indoc! {"
+            Some quoted text
+            -- not at the end
+            spanning lines
+        regular line starts here and is needed for indoc to have a baseline
+        "});
+        let (_, bq) = blockquote(input).unwrap();
+
+        assert_eq!(
+            bq.lines(),
+            &["Some quoted text", "-- not at the end", "spanning lines"]
+        );
+        assert_eq!(bq.attribution(), None);
+    }
 }
@@ -1,20 +1,21 @@
 use crate::lang::{
     elements::{
-        Definition, DefinitionList, InlineElementContainer, Located, Term,
+        Definition, DefinitionList, DefinitionListValue,
+        InlineElementContainer, Located, Term,
     },
     parsers::{
         utils::{
             beginning_of_line, capture, context, deeper, end_of_line_or_input,
             locate, take_line_until1, take_until_end_of_line_or_input,
         },
-        vimwiki::blocks::inline::inline_element_container,
+        vimwiki::blocks::{inline::inline_element_container, lists::list},
         IResult, Span,
     },
 };
 use nom::{
     bytes::complete::tag,
     character::complete::{space0, space1},
-    combinator::{map, map_parser, opt, verify},
+    combinator::{cond, map, map_parser, opt, peek, verify},
     multi::{many0, many1},
     sequence::{pair, preceded, terminated},
 };
@@ -69,21 +70,13 @@ fn term_line(
     )))(input)?;
 
     // Now check if we have a definition following
-    let (input, maybe_def) = opt(locate(capture(preceded(
-        space1,
-        map_parser(
-            take_until_end_of_line_or_input,
-            map(
-                inline_element_container,
-                |l: Located<InlineElementContainer>| {
-                    Definition::new(l.into_inner())
-                },
-            ),
-        ),
-    ))))(input)?;
+    let (input, maybe_def) =
+        opt(locate(capture(preceded(space1, definition_tail))))(input)?;
 
-    // Conclude with any lingering space and newline
-    let (input, _) = pair(space0, end_of_line_or_input)(input)?;
+    // Conclude with any lingering space and newline, which a definition
+    // already consumes as part of its content
+    let (input, _) =
+        cond(maybe_def.is_none(), pair(space0, end_of_line_or_input))(input)?;
 
     Ok((input, (term, maybe_def)))
 }
@@ -95,27 +88,42 @@ fn definition_line(input: Span) -> IResult<Located<Definition>> {
         let (input, _) = beginning_of_line(input)?;
         let (input, _) = tag("::")(input)?;
         let (input, _) = space1(input)?;
-        let (input, def) = map_parser(
-            take_until_end_of_line_or_input,
-            map(
-                inline_element_container,
-                |l: Located<InlineElementContainer>| l.into_inner(),
-            ),
-        )(input)?;
-        let (input, _) = end_of_line_or_input(input)?;
-
-        Ok((input, Definition::new(def)))
+        definition_tail(input)
     }
 
     context("Definition Line", locate(capture(inner)))(input)
 }
 
+/// Parses the content of a definition through the end of its line, followed
+/// by any list indented on the lines beneath it, which belongs to the
+/// definition
+fn definition_tail(input: Span) -> IResult<Definition> {
+    let (input, value) = map_parser(
+        take_until_end_of_line_or_input,
+        map(
+            inline_element_container,
+            |l: Located<InlineElementContainer>| {
+                DefinitionListValue::new(l.into_inner())
+            },
+        ),
+    )(input)?;
+    let (input, _) = end_of_line_or_input(input)?;
+    let (input, maybe_list) = opt(preceded(peek(space1), deeper(list)))(input)?;
+
+    let definition = match maybe_list {
+        Some(list) => Definition::WithList { value, list },
+        None => Definition::Inline(value),
+    };
+
+    Ok((input, definition))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::lang::elements::{
         DecoratedText, DecoratedTextContent, InlineElement,
-        InlineElementContainer, Link, MathInline, Text, WikiLink,
+        InlineElementContainer, Link, ListItem, MathInline, Text, WikiLink,
     };
     use indoc::indoc;
 
@@ -344,6 +352,45 @@ mod tests {
         check_text_defs(defs, vec!["def 3", "def 4"]);
     }
 
+    #[test]
+    fn definition_list_should_attach_indented_list_to_preceding_definition() {
+        fn item_text<'a>(item: &'a ListItem) -> Vec<&'a InlineElement<'a>> {
+            item.contents.inline_content_iter().collect()
+        }
+
+        fn text(s: &str) -> InlineElement<'_> {
+            InlineElement::from(Text::from(s))
+        }
+
+        let input = Span::from(indoc! {r#"
+            term 1:: def 1
+              - item 1
+              - item 2
+            :: def 2
+            term 2::
+            :: def 3
+              - item 3
+        "#});
+        let (input, l) = definition_list(input).unwrap();
+        assert!(input.is_empty(), "Did not consume def list");
+
+        let defs = l.get("term 1").unwrap();
+        assert_eq!(defs.len(), 2, "Wrong number of definitions found");
+        assert_eq!(defs[0].to_string(), "def 1");
+        let list = defs[0].list().expect("Missing nested list");
+        assert_eq!(list.items.len(), 2);
+        assert_eq!(item_text(&list.items[0]), vec![&text("item 1")]);
+        assert_eq!(item_text(&list.items[1]), vec![&text("item 2")]);
+        assert_eq!(defs[1].as_inner(), &Definition::from("def 2"));
+
+        let defs = l.get("term 2").unwrap();
+        assert_eq!(defs.len(), 1, "Wrong number of definitions found");
+        assert_eq!(defs[0].to_string(), "def 3");
+        let list = defs[0].list().expect("Missing nested list");
+        assert_eq!(list.items.len(), 1);
+        assert_eq!(item_text(&list.items[0]), vec![&text("item 3")]);
+    }
+
     #[test]
     fn definition_list_should_support_inline_elements_in_terms_and_definitions()
     {
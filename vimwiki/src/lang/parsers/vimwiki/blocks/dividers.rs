@@ -14,8 +14,9 @@ use nom::{character::complete::char, combinator::verify};
 pub fn divider(input: Span) -> IResult<Located<Divider>> {
     fn inner(input: Span) -> IResult<Divider> {
         let (input, _) = beginning_of_line(input)?;
+        let min_len = input.min_divider_dashes();
         let (input, _) = verify(take_line_while1(char('-')), |s: &Span| {
-            s.remaining_len() >= 4
+            s.remaining_len() >= min_len
         })(input)?;
         let (input, _) = end_of_line_or_input(input)?;
 
@@ -28,6 +29,14 @@ pub fn divider(input: Span) -> IResult<Located<Divider>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lang::parsers::{ParseConfig, ParseState};
+
+    fn state_with_min_dashes(min_divider_dashes: usize) -> ParseState {
+        ParseState::new(ParseConfig {
+            min_divider_dashes,
+            ..Default::default()
+        })
+    }
 
     #[test]
     fn divider_should_fail_if_not_at_beginning_of_line() {
@@ -41,6 +50,21 @@ mod tests {
         assert!(divider(input).is_err());
     }
 
+    #[test]
+    fn divider_should_fail_if_fewer_hyphens_than_configured_minimum() {
+        let state = state_with_min_dashes(4);
+        let input = Span::from("---").with_state(&state);
+        assert!(divider(input).is_err());
+    }
+
+    #[test]
+    fn divider_should_succeed_if_at_least_configured_minimum_hyphens() {
+        let state = state_with_min_dashes(3);
+        let input = Span::from("---\nabcd").with_state(&state);
+        let (input, _) = divider(input).unwrap();
+        assert_eq!(input.as_unsafe_remaining_str(), "abcd");
+    }
+
     #[test]
     fn divider_should_fail_if_not_only_hyphens_within_line() {
         let input = Span::from("----a");
@@ -0,0 +1,74 @@
+use crate::lang::{
+    elements::{FootnoteDef, InlineElementContainer, Located},
+    parsers::{
+        utils::{
+            beginning_of_line, capture, context, end_of_line_or_input, locate,
+        },
+        vimwiki::blocks::inline::{
+            footnotes::footnote_label, inline_element_container,
+        },
+        IResult, Span,
+    },
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::space0,
+    combinator::{map, opt},
+    sequence::delimited,
+};
+
+/// Parses the definition of a footnote in the form of [^label]: content
+#[inline]
+pub fn footnote_def(input: Span) -> IResult<Located<FootnoteDef>> {
+    fn inner(input: Span) -> IResult<FootnoteDef> {
+        let (input, _) = beginning_of_line(input)?;
+        let (input, label) =
+            delimited(tag("[^"), footnote_label, tag("]:"))(input)?;
+        let (input, _) = space0(input)?;
+        let (input, content) = opt(map(
+            inline_element_container,
+            |c: Located<InlineElementContainer>| c.into_inner(),
+        ))(input)?;
+        let (input, _) = end_of_line_or_input(input)?;
+
+        Ok((
+            input,
+            FootnoteDef::new(
+                label,
+                content.unwrap_or_else(|| InlineElementContainer::new(vec![])),
+            ),
+        ))
+    }
+
+    context("Footnote Def", locate(capture(inner)))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::elements::{InlineElement, Text};
+
+    #[test]
+    fn footnote_def_should_fail_if_not_at_beginning_of_line() {
+        let input = Span::from(" [^1]: text");
+        assert!(footnote_def(input).is_err());
+    }
+
+    #[test]
+    fn footnote_def_should_fail_if_missing_colon() {
+        let input = Span::from("[^1] text");
+        assert!(footnote_def(input).is_err());
+    }
+
+    #[test]
+    fn footnote_def_should_consume_label_and_rest_of_line_as_content() {
+        let input = Span::from("[^1]: some text\nnext line");
+        let (input, footnote) = footnote_def(input).unwrap();
+        assert_eq!(input.as_unsafe_remaining_str(), "next line");
+        assert_eq!(footnote.label, "1");
+        assert_eq!(
+            footnote.content.elements,
+            vec![Located::from(InlineElement::from(Text::from("some text")))]
+        );
+    }
+}
@@ -15,6 +15,7 @@ use nom::{
     character::complete::{char, space0},
     combinator::{map, peek, verify},
 };
+use std::borrow::Cow;
 
 /// Parses a vimwiki header, returning the associated header if successful
 #[inline]
@@ -34,9 +35,10 @@ pub fn header(input: Span) -> IResult<Located<Header>> {
 
         // Third, get the content of the header by collecting all text until we
         // find a closing set of = matching our expected level
-        let (input, header) = map(header_tail(level), |content| {
-            Header::new(level, content, centered)
-        })(input)?;
+        let (input, header) =
+            map(header_tail(level), |(content, custom_id)| {
+                Header::new(level, content, centered, custom_id)
+            })(input)?;
 
         // Fourth, consume the end of line/input to indicate header complete
         let (input, _) = end_of_line_or_input(input)?;
@@ -49,13 +51,16 @@ pub fn header(input: Span) -> IResult<Located<Header>> {
 
 fn header_tail(
     level: usize,
-) -> impl Fn(Span) -> IResult<InlineElementContainer> {
+) -> impl Fn(Span) -> IResult<(InlineElementContainer, Option<Cow<str>>)> {
     use nom::{AsBytes, InputIter};
     move |input: Span| {
         // Get remainder of line and remove any excess whitespace
         let (input, rest_of_line) = take_until_end_of_line_or_input(input)?;
         let (rest_of_line, _) = trim_trailing_whitespace(rest_of_line)?;
 
+        // Split off an explicit anchor id following the closing equals signs
+        let (rest_of_line, custom_id) = split_custom_id(rest_of_line);
+
         // Verify that the end of the line (minus whitespace) has the same
         // number of equals signs, and chop them off
         let (rest_of_line, _) = context(
@@ -84,8 +89,33 @@ fn header_tail(
             |l: Located<InlineElementContainer>| l.into_inner(),
         )(rest_of_line)?;
 
-        Ok((input, container))
+        Ok((input, (container, custom_id)))
+    }
+}
+
+/// Splits a trailing `#custom-id` that directly follows the closing equals
+/// signs of a header from the rest of the line, leaving the line untouched
+/// if there is no such id
+fn split_custom_id(line: Span) -> (Span, Option<Cow<str>>) {
+    let bytes = line.as_remaining();
+    let pos = match bytes.iter().rposition(|b| *b == b'#') {
+        Some(pos) if pos > 0 && bytes[pos - 1] == b'=' => pos,
+        _ => return (line, None),
+    };
+
+    let id = &bytes[pos + 1..];
+    let is_id = !id.is_empty()
+        && id
+            .iter()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_'));
+    if !is_id {
+        return (line, None);
     }
+
+    (
+        line.with_length(pos),
+        Some(Cow::from(line.starting_at(pos + 1))),
+    )
 }
 
 #[cfg(test)]
@@ -247,4 +277,38 @@ mod tests {
         check!(h, 7, Text, " ");
         check!(h, 8, Math, "math");
     }
+
+    #[test]
+    fn header_should_recognize_keyword_and_link_within_padded_content() {
+        let input = Span::from("= TODO Fix [[this]] =");
+        let (input, h) = header(input).unwrap();
+        assert!(input.is_empty(), "Did not consume header");
+
+        check!(h, 0, Keyword, "TODO");
+        check!(h, 1, Text, " Fix ");
+        check!(h, 2, Link, "this");
+        assert_eq!(h.content.elements.len(), 3);
+    }
+
+    #[test]
+    fn header_should_support_custom_id_following_closing_marker() {
+        let input = Span::from("== Some Header ==#custom-id\n");
+        let (input, h) = header(input).unwrap();
+        assert!(input.is_empty(), "Did not consume header");
+        assert_eq!(h.level, 2, "Wrong header level");
+        assert_eq!(h.content.to_string(), "Some Header");
+        assert_eq!(h.custom_id.as_deref(), Some("custom-id"));
+    }
+
+    #[test]
+    fn header_should_not_have_custom_id_if_none_follows_closing_marker() {
+        let input = Span::from("== Some #tag Header ==\n");
+        let (input, h) = header(input).unwrap();
+        assert!(input.is_empty(), "Did not consume header");
+        assert_eq!(h.content.to_string(), "Some #tag Header");
+        assert_eq!(h.custom_id, None);
+
+        let input = Span::from("== Some Header == #custom-id\n");
+        assert!(header(input).is_err(), "Id not attached to marker");
+    }
 }
@@ -0,0 +1,65 @@
+use crate::lang::{
+    elements::{FootnoteRef, Located},
+    parsers::{
+        utils::{capture, context, cow_str, locate, take_line_until1},
+        IResult, Span,
+    },
+};
+use nom::{
+    bytes::complete::tag,
+    combinator::{map, map_parser, verify},
+    sequence::delimited,
+};
+use std::borrow::Cow;
+
+/// Parses a reference to a footnote in the form of [^label]
+#[inline]
+pub fn footnote_ref(input: Span) -> IResult<Located<FootnoteRef>> {
+    fn inner(input: Span) -> IResult<FootnoteRef> {
+        map(
+            delimited(tag("[^"), footnote_label, tag("]")),
+            FootnoteRef::new,
+        )(input)
+    }
+
+    context("Footnote Ref", locate(capture(inner)))(input)
+}
+
+/// Parses the label of a footnote, which is everything up to the closing
+/// bracket and cannot contain whitespace
+pub fn footnote_label<'a>(input: Span<'a>) -> IResult<'a, Cow<'a, str>> {
+    context(
+        "Footnote Label",
+        map_parser(
+            verify(take_line_until1("]"), |s: &Span| {
+                !s.as_remaining().iter().any(u8::is_ascii_whitespace)
+            }),
+            cow_str,
+        ),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn footnote_ref_should_fail_if_label_is_empty() {
+        let input = Span::from("[^]");
+        assert!(footnote_ref(input).is_err());
+    }
+
+    #[test]
+    fn footnote_ref_should_fail_if_label_contains_whitespace() {
+        let input = Span::from("[^some label]");
+        assert!(footnote_ref(input).is_err());
+    }
+
+    #[test]
+    fn footnote_ref_should_consume_label_between_brackets() {
+        let input = Span::from("[^note-1] text");
+        let (input, footnote) = footnote_ref(input).unwrap();
+        assert_eq!(input.as_unsafe_remaining_str(), " text");
+        assert_eq!(footnote.label, "note-1");
+    }
+}
@@ -0,0 +1,106 @@
+use crate::lang::{
+    elements::{Hashtag, Located},
+    parsers::{
+        utils::{capture, context, cow_str, locate},
+        Error, IResult, Span,
+    },
+};
+use nom::{
+    bytes::complete::{tag, take_while1},
+    combinator::map_parser,
+};
+
+/// Parses a hashtag in the form of `#word` when enabled by the parse
+/// configuration, where the word can contain `-`, `_`, and `/`. To avoid
+/// colliding with headers, the hashtag must be preceded by whitespace and
+/// cannot start a line
+#[inline]
+pub fn hashtag(input: Span) -> IResult<Located<Hashtag>> {
+    fn inner(input: Span) -> IResult<Hashtag> {
+        if !input.parse_hashtags() {
+            return Err(nom::Err::Error(Error::from_ctx(
+                &input,
+                "Hashtags not enabled",
+            )));
+        }
+
+        let preceded_by_space =
+            matches!(input.as_consumed().last(), Some(b' ') | Some(b'\t'));
+        if !preceded_by_space {
+            return Err(nom::Err::Error(Error::from_ctx(
+                &input,
+                "Hashtag not preceded by whitespace",
+            )));
+        }
+
+        let (input, _) = tag("#")(input)?;
+        let (input, name) = map_parser(
+            take_while1(|b: u8| {
+                b.is_ascii_alphanumeric()
+                    || b == b'-'
+                    || b == b'_'
+                    || b == b'/'
+                    || !b.is_ascii()
+            }),
+            cow_str,
+        )(input)?;
+
+        Ok((input, Hashtag::new(name)))
+    }
+
+    context("Hashtag", locate(capture(inner)))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::parsers::{ParseConfig, ParseState};
+
+    fn enabled() -> ParseState {
+        ParseState::new(ParseConfig {
+            parse_hashtags: true,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn hashtag_should_fail_if_not_enabled() {
+        let input = Span::from("some #tag").starting_at(5);
+        assert!(hashtag(input).is_err());
+    }
+
+    #[test]
+    fn hashtag_should_fail_if_at_start_of_line() {
+        let state = enabled();
+        let input = Span::from("#tag").with_state(&state);
+        assert!(hashtag(input).is_err());
+
+        let input = Span::from("text\n#tag").with_state(&state).starting_at(5);
+        assert!(hashtag(input).is_err());
+    }
+
+    #[test]
+    fn hashtag_should_fail_if_preceded_by_non_space() {
+        let state = enabled();
+        let input = Span::from("issue#tag").with_state(&state).starting_at(5);
+        assert!(hashtag(input).is_err());
+    }
+
+    #[test]
+    fn hashtag_should_fail_if_no_word_follows() {
+        let state = enabled();
+        let input = Span::from("some # text").with_state(&state).starting_at(5);
+        assert!(hashtag(input).is_err());
+    }
+
+    #[test]
+    fn hashtag_should_consume_word_with_dashes_underscores_and_slashes() {
+        let state = enabled();
+        let input = Span::from("see #my-project/sub_task, then")
+            .with_state(&state)
+            .starting_at(4);
+        let (input, hashtag) = hashtag(input).unwrap();
+        assert_eq!(input.as_unsafe_remaining_str(), ", then");
+        assert_eq!(hashtag.name, "my-project/sub_task");
+    }
+}
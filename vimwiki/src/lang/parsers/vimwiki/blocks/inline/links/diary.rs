@@ -1,16 +1,17 @@
-use super::{link_anchor, link_description, link_path};
+use super::{link_anchor, maybe_link_description};
 use crate::lang::{
     elements::{DiaryLink, Located},
     parsers::{
-        utils::{capture, context, locate, not_contains, surround_in_line1},
+        utils::{
+            capture, context, cow_str, locate, not_contains, surround_in_line1,
+            take_line_until_one_of_three1,
+        },
         IResult, Span,
     },
 };
-use chrono::NaiveDate;
 use nom::{
     bytes::complete::tag,
-    combinator::{map_parser, map_res, opt},
-    sequence::preceded,
+    combinator::{map_parser, opt},
 };
 
 #[inline]
@@ -19,23 +20,24 @@ pub fn diary_link(input: Span) -> IResult<Located<DiaryLink>> {
         // Diary is a specialized link that must start with diary:
         let (input, _) = tag("diary:")(input)?;
 
-        // After the specialized start, a valid date must follow
-        // TODO: Unsure if this would allocate a new string given that the
-        //       path is formed from a valid UTF-8 str; Cow<'_, str> yielded
-        //       might just be a pointer to the original slice
-        let (input, date) = map_res(link_path, |path| {
-            NaiveDate::parse_from_str(&path.to_string_lossy(), "%Y-%m-%d")
-        })(input)?;
+        // After the specialized start, a date should follow; we keep it as
+        // written so that a malformed date still yields a diary link
+        let (input, date) = map_parser(
+            take_line_until_one_of_three1("|", "#", "]]"),
+            cow_str,
+        )(input)?;
 
         // Next, check if there are any anchors
         let (input, maybe_anchor) = opt(link_anchor)(input)?;
 
         // Finally, check if there is a description (preceding with |), where
         // a special case is wrapped in {{...}} as a URL
-        let (input, maybe_description) =
-            opt(preceded(tag("|"), link_description))(input)?;
+        let (input, maybe_description) = maybe_link_description(input)?;
 
-        Ok((input, DiaryLink::new(date, maybe_description, maybe_anchor)))
+        Ok((
+            input,
+            DiaryLink::from_date_str(date, maybe_description, maybe_anchor),
+        ))
     }
 
     context(
@@ -51,6 +53,7 @@ pub fn diary_link(input: Span) -> IResult<Located<DiaryLink>> {
 mod tests {
     use super::*;
     use crate::lang::elements::{Anchor, Description};
+    use chrono::NaiveDate;
 
     #[test]
     fn diary_link_should_fail_if_not_using_diary_scheme() {
@@ -59,9 +62,16 @@ mod tests {
     }
 
     #[test]
-    fn diary_link_should_fail_if_not_using_correct_date_format() {
+    fn diary_link_should_keep_malformed_date_without_parsing_it() {
         let input = Span::from("[[diary:2012/03/05]]");
-        assert!(diary_link(input).is_err());
+        let (input, link) =
+            diary_link(input).expect("Parser unexpectedly failed");
+
+        // Link should be consumed
+        assert!(input.is_empty());
+
+        assert_eq!(link.date_str, "2012/03/05");
+        assert_eq!(link.date, None);
     }
 
     #[test]
@@ -73,7 +83,7 @@ mod tests {
         // Link should be consumed
         assert!(input.is_empty());
 
-        assert_eq!(link.date, NaiveDate::from_ymd(2012, 3, 5));
+        assert_eq!(link.date, NaiveDate::from_ymd_opt(2012, 3, 5));
         assert_eq!(link.description, None);
         assert_eq!(link.anchor, None);
     }
@@ -87,7 +97,7 @@ mod tests {
         // Link should be consumed
         assert!(input.is_empty());
 
-        assert_eq!(link.date, NaiveDate::from_ymd(2012, 3, 5));
+        assert_eq!(link.date, NaiveDate::from_ymd_opt(2012, 3, 5));
         assert_eq!(
             link.description,
             Some(Description::from("some description"))
@@ -104,7 +114,7 @@ mod tests {
         // Link should be consumed
         assert!(input.is_empty());
 
-        assert_eq!(link.date, NaiveDate::from_ymd(2012, 3, 5));
+        assert_eq!(link.date, NaiveDate::from_ymd_opt(2012, 3, 5));
         assert_eq!(link.description, None,);
         assert_eq!(link.anchor, Some(Anchor::from("Tomorrow")));
     }
@@ -119,7 +129,7 @@ mod tests {
         // Link should be consumed
         assert!(input.is_empty());
 
-        assert_eq!(link.date, NaiveDate::from_ymd(2012, 3, 5));
+        assert_eq!(link.date, NaiveDate::from_ymd_opt(2012, 3, 5));
         assert_eq!(
             link.description,
             Some(Description::from("Tasks for tomorrow"))
@@ -1,4 +1,4 @@
-use super::{link_anchor, link_description, link_path};
+use super::{link_anchor, link_path, maybe_link_description};
 use crate::lang::{
     elements::{
         Anchor, Description, IndexedInterWikiLink, InterWikiLink, Located,
@@ -16,7 +16,7 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     combinator::{map, map_parser, map_res, opt},
-    sequence::{delimited, pair, preceded},
+    sequence::{delimited, pair},
 };
 use std::{borrow::Cow, path::Path};
 
@@ -81,8 +81,7 @@ fn rest_of_link<'a>(
 
     // Finally, check if there is a description (preceding with |), where
     // a special case is wrapped in {{...}} as a URL
-    let (input, maybe_description) =
-        opt(preceded(tag("|"), link_description))(input)?;
+    let (input, maybe_description) = maybe_link_description(input)?;
 
     Ok((input, (path, maybe_anchor, maybe_description)))
 }
@@ -1,17 +1,20 @@
+use super::inline_element_container;
 use crate::lang::{
-    elements::{Anchor, Description, Link, Located},
+    elements::{Anchor, Description, InlineElement, Link, Located},
     parsers::{
         utils::{
-            context, cow_path, cow_str, take_line_until1,
+            context, cow_path, cow_str, take_line_until, take_line_until1,
             take_line_until_one_of_three1, uri,
         },
-        IResult, Span,
+        Error, IResult, Span,
     },
 };
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    combinator::{map, map_parser, not, rest},
+    combinator::{
+        all_consuming, map, map_parser, not, opt, rest, value, verify,
+    },
     multi::separated_list0,
     sequence::{delimited, preceded},
 };
@@ -21,6 +24,7 @@ pub(crate) mod diary;
 pub(crate) mod external;
 pub(crate) mod interwiki;
 pub(crate) mod raw;
+pub(crate) mod reference;
 pub(crate) mod transclusion;
 pub(crate) mod wiki;
 
@@ -69,6 +73,7 @@ pub fn link(input: Span) -> IResult<Located<Link>> {
             map(wiki::wiki_link, |c| c.map(Link::from)),
             map(raw::raw_link, |c| c.map(Link::from)),
             map(transclusion::transclusion_link, |c| c.map(Link::from)),
+            map(reference::reference_link, |c| c.map(Link::from)),
         )),
     )(input)
 }
@@ -94,17 +99,61 @@ fn link_anchor<'a>(input: Span<'a>) -> IResult<Anchor<'a>> {
     )(input)
 }
 
+/// Extracts the description-portion of a link if it follows a pipe, where
+/// an empty or whitespace-only description is consumed and treated as no
+/// description at all
+fn maybe_link_description<'a>(
+    input: Span<'a>,
+) -> IResult<'a, Option<Description<'a>>> {
+    map(
+        opt(preceded(
+            tag("|"),
+            alt((
+                value(
+                    None,
+                    verify(take_line_until("]]"), |s: &Span| {
+                        s.is_only_whitespace()
+                    }),
+                ),
+                map(link_description, Some),
+            )),
+        )),
+        Option::flatten,
+    )(input)
+}
+
 /// Extracts the description-portion of a link
 fn link_description<'a>(input: Span<'a>) -> IResult<Description<'a>> {
     map_parser(
         take_line_until1("]]"),
         alt((
             description_from_uri,
+            description_from_inline_elements,
             map(rest, |s: Span| Description::Text(s.into())),
         )),
     )(input)
 }
 
+/// Parses a description containing decorations or other inline elements,
+/// failing if the description is only text so it can be kept as text
+fn description_from_inline_elements<'a>(
+    input: Span<'a>,
+) -> IResult<'a, Description<'a>> {
+    let (input, container) = all_consuming(inline_element_container)(input)?;
+    let only_text = container
+        .elements
+        .iter()
+        .all(|x| matches!(x.as_inner(), InlineElement::Text(_)));
+    if only_text {
+        return Err(nom::Err::Error(Error::from_ctx(
+            &input,
+            "Description only contains text",
+        )));
+    }
+
+    Ok((input, Description::from(container.into_inner())))
+}
+
 // NOTE: This function exists purely because we were hitting some nom
 //       error about type-length limit being reached and that means that
 //       we've nested too many parsers without breaking them up into
@@ -123,6 +172,10 @@ fn description_from_uri<'a>(input: Span<'a>) -> IResult<Description<'a>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lang::elements::{
+        DecoratedText, DecoratedTextContent, Text, WikiLink,
+    };
+    use chrono::NaiveDate;
 
     #[test]
     fn link_should_return_external_link_where_appropriate() {
@@ -138,6 +191,39 @@ mod tests {
         assert!(matches!(l.into_inner(), Link::Diary(_)));
     }
 
+    #[test]
+    fn link_should_expose_date_of_diary_link() {
+        let input = Span::from("[[diary:2012-03-05#Tomorrow]]");
+        let (_, l) = link(input).unwrap();
+        assert_eq!(l.diary_date(), NaiveDate::from_ymd_opt(2012, 3, 5));
+    }
+
+    #[test]
+    fn link_should_return_diary_link_without_date_if_malformed() {
+        let input = Span::from("[[diary:2012-13-45]]");
+        let (_, l) = link(input).unwrap();
+        assert_eq!(l.diary_date(), None);
+        assert!(matches!(l.into_inner(), Link::Diary(_)));
+    }
+
+    #[test]
+    fn link_same_target_should_resolve_relative_paths_and_extensions() {
+        let root = Path::new("/wiki");
+        let current = Path::new("/wiki/index.wiki");
+        let parse = |x: &'static str| link(Span::from(x)).unwrap().1;
+
+        let page = parse("[[page]]");
+        assert!(page.same_target(&parse("[[./page.wiki]]"), root, current));
+        assert!(page.same_target(&parse("[[/page]]"), root, current));
+        assert!(page.same_target(&parse("[[page#anchor]]"), root, current));
+        assert!(!page.same_target(&parse("[[other]]"), root, current));
+        assert!(!page.same_target(
+            &parse("[[https://example.com/page]]"),
+            root,
+            current
+        ));
+    }
+
     #[test]
     fn link_should_return_interwiki_link_where_appropriate() {
         let input = Span::from("[[wiki1:Some Link]]");
@@ -156,6 +242,46 @@ mod tests {
         assert!(matches!(l.into_inner(), Link::Wiki(_)));
     }
 
+    #[test]
+    fn link_should_preserve_decorations_within_description() {
+        let input = Span::from("[[page|*bold desc*]]");
+        let (input, l) = link(input).unwrap();
+        assert!(input.is_empty(), "Did not consume link");
+
+        let link = match l.into_inner() {
+            Link::Wiki(x) => x,
+            x => panic!("Unexpected link: {:?}", x),
+        };
+        assert_eq!(link.path.to_string_lossy(), "page");
+
+        let container = match link.description {
+            Some(Description::Inline(x)) => x,
+            x => panic!("Unexpected description: {:?}", x),
+        };
+        assert_eq!(
+            container.elements,
+            vec![Located::from(InlineElement::from(DecoratedText::Bold(
+                vec![Located::from(DecoratedTextContent::from(Text::from(
+                    "bold desc"
+                )))]
+            )))]
+        );
+    }
+
+    #[test]
+    fn link_should_keep_plain_description_as_text() {
+        let input = Span::from("[[page|plain desc]]");
+        let (_, l) = link(input).unwrap();
+        assert_eq!(
+            l.into_inner(),
+            Link::from(WikiLink::new(
+                std::path::Path::new("page").into(),
+                Some(Description::from("plain desc")),
+                None,
+            ))
+        );
+    }
+
     #[test]
     fn link_should_return_raw_link_where_appropriate() {
         let input = Span::from("https://example.com");
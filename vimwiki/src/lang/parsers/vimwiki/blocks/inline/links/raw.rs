@@ -2,14 +2,30 @@ use crate::lang::{
     elements::{Located, RawLink},
     parsers::{
         utils::{capture, context, locate, uri},
-        IResult, Span,
+        Error, IResult, Span,
     },
 };
-use nom::combinator::verify;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take, take_till1},
+    combinator::{map_res, recognize, verify},
+    sequence::pair,
+};
+use std::convert::TryFrom;
+use uriparse::URI;
 
 #[inline]
 pub fn raw_link(input: Span) -> IResult<Located<RawLink>> {
     fn inner(input: Span) -> IResult<RawLink> {
+        if input.autolink_urls() {
+            let is_web =
+                alt::<_, _, Error, _>((tag("https://"), tag("http://")))(input)
+                    .is_ok();
+            if is_web {
+                return bare_url(input);
+            }
+        }
+
         // This will match any URI, but we only want to allow a certain set
         // to ensure that we don't mistake some text preceding a tag
         let (input, uri) = verify(uri, |uri| {
@@ -23,9 +39,50 @@ pub fn raw_link(input: Span) -> IResult<Located<RawLink>> {
     context("Raw Link", locate(capture(inner)))(input)
 }
 
+/// Parses a bare `http://` or `https://` URL found within text when
+/// autolinking is enabled. The URL must not continue a word and excludes
+/// trailing punctuation, along with a closing parenthesis that has no
+/// matching opening parenthesis within the URL
+fn bare_url(input: Span) -> IResult<RawLink> {
+    let continues_word = input
+        .as_consumed()
+        .last()
+        .is_some_and(|b| b.is_ascii_alphanumeric());
+    if continues_word {
+        return Err(nom::Err::Error(Error::from_ctx(
+            &input,
+            "URL not at start of word",
+        )));
+    }
+
+    let (_, url) = recognize(pair(
+        alt((tag("https://"), tag("http://"))),
+        take_till1(|b: u8| b.is_ascii_whitespace()),
+    ))(input)?;
+
+    let mut url = url.as_remaining();
+    while let Some((last, rest)) = url.split_last() {
+        let is_trailing = match last {
+            b')' => {
+                url.iter().filter(|b| **b == b'(').count()
+                    < url.iter().filter(|b| **b == b')').count()
+            }
+            _ => b".,;:!?'\"".contains(last),
+        };
+        if !is_trailing {
+            break;
+        }
+        url = rest;
+    }
+
+    let (input, uri) = map_res(take(url.len()), URI::try_from)(input)?;
+    Ok((input, RawLink::from(uri)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lang::parsers::{ParseConfig, ParseState};
 
     #[test]
     fn raw_link_should_support_http_scheme() {
@@ -111,4 +168,35 @@ mod tests {
         assert_eq!(link.uri.scheme(), "mailto");
         assert_eq!(link.uri.path(), "person@example.com");
     }
+
+    #[test]
+    fn raw_link_should_leave_trailing_punctuation_of_bare_url_if_autolinking() {
+        let state = ParseState::new(ParseConfig {
+            autolink_urls: true,
+            ..Default::default()
+        });
+        let input =
+            Span::from("https://example.com/a_(b)).").with_state(&state);
+        let (input, link) = raw_link(input).expect("Failed to parse uri");
+        assert_eq!(input, ").");
+        assert_eq!(link.uri.to_string(), "https://example.com/a_(b)");
+
+        // Without autolinking, the rest of the line is part of the link
+        let input = Span::from("https://example.com/a_(b)).");
+        let (input, link) = raw_link(input).expect("Failed to parse uri");
+        assert!(input.is_empty());
+        assert_eq!(link.uri.to_string(), "https://example.com/a_(b)).");
+    }
+
+    #[test]
+    fn raw_link_should_fail_if_bare_url_continues_word_and_autolinking() {
+        let state = ParseState::new(ParseConfig {
+            autolink_urls: true,
+            ..Default::default()
+        });
+        let input = Span::from("xhttps://example.com")
+            .with_state(&state)
+            .starting_at(1);
+        assert!(raw_link(input).is_err());
+    }
 }
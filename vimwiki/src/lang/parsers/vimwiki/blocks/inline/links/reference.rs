@@ -0,0 +1,203 @@
+use crate::lang::{
+    elements::{Description, Located, ReferenceLink, Region},
+    parsers::{
+        utils::{
+            capture, context, cow_str, locate, take_line_until,
+            take_line_until1,
+        },
+        Error, IResult, ParseDiagnostic, ParseDiagnosticKind, Span,
+    },
+};
+use nom::{
+    bytes::complete::tag,
+    combinator::{map_parser, verify},
+    sequence::delimited,
+};
+use std::{borrow::Cow, collections::HashMap};
+
+#[inline]
+pub fn reference_link(input: Span) -> IResult<Located<ReferenceLink>> {
+    fn inner(input: Span) -> IResult<ReferenceLink> {
+        if !input.reference_links() {
+            return Err(nom::Err::Error(Error::from_ctx(
+                &input,
+                "Reference links are not enabled",
+            )));
+        }
+
+        let start = input;
+        let (input, description) = delimited(
+            tag("["),
+            map_parser(
+                verify(take_line_until1("]"), |s: &Span| {
+                    !s.as_remaining().contains(&b'[')
+                }),
+                cow_str,
+            ),
+            tag("]"),
+        )(input)?;
+        let (input, label) = delimited(
+            tag("["),
+            map_parser(take_line_until("]"), cow_str),
+            tag("]"),
+        )(input)?;
+
+        // NOTE: An empty label means that the description is the label
+        let key = if label.is_empty() {
+            &description
+        } else {
+            &label
+        };
+        match find_definition(input, key) {
+            Some(target) => Ok((
+                input,
+                ReferenceLink::new(
+                    Description::Text(description),
+                    label,
+                    Cow::from(target),
+                ),
+            )),
+            None => {
+                let len = input.start_offset() - start.start_offset();
+                start.report(ParseDiagnostic::new(
+                    ParseDiagnosticKind::UndefinedReference,
+                    Region::from(start.with_length(len)),
+                ));
+                Err(nom::Err::Error(Error::from_ctx(
+                    &start,
+                    "Reference link label is not defined",
+                )))
+            }
+        }
+    }
+
+    context("Reference Link", locate(capture(inner)))(input)
+}
+
+/// Finds the target of the first definition within the entire input whose
+/// label matches, ignoring case and differences in whitespace
+fn find_definition(input: Span, label: &str) -> Option<String> {
+    input.reference_definition(&normalize_label(label), || {
+        collect_definitions(input)
+    })
+}
+
+/// Collects the targets of all lines within the entire input of the form
+/// `[label]: target`, keyed by normalized label where the first definition
+/// of a label wins
+fn collect_definitions(input: Span) -> HashMap<String, String> {
+    let mut definitions = HashMap::new();
+    let text = match std::str::from_utf8(input.as_inner()) {
+        Ok(text) => text,
+        Err(_) => return definitions,
+    };
+
+    for line in text.lines() {
+        let line = match line.strip_prefix('[') {
+            Some(line) => line,
+            None => continue,
+        };
+        let end = match line.find("]:") {
+            Some(end) => end,
+            None => continue,
+        };
+        let target = line[end + 2..].trim();
+        if !target.is_empty() && !target.contains(char::is_whitespace) {
+            definitions
+                .entry(normalize_label(&line[..end]))
+                .or_insert_with(|| target.to_string());
+        }
+    }
+
+    definitions
+}
+
+/// Lowercases the label and collapses runs of whitespace into a single space
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::{
+        elements::Position,
+        parsers::{ParseConfig, ParseState},
+    };
+    use indoc::indoc;
+
+    fn make_state() -> ParseState {
+        ParseState::new(ParseConfig {
+            reference_links: true,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn reference_link_should_fail_if_not_enabled() {
+        let input = Span::from("[text][ref]\n\n[ref]: https://example.com");
+        assert!(reference_link(input).is_err());
+    }
+
+    #[test]
+    fn reference_link_should_resolve_target_of_defined_label() {
+        let state = make_state();
+        let input = Span::from(indoc! {"
+            [some text][Ref] and more
+
+            [other]: other.html
+            [ref]: https://example.com
+        "})
+        .with_state(&state);
+        let (input, link) = reference_link(input).unwrap();
+        assert_eq!(
+            input.as_unsafe_remaining_str().lines().next(),
+            Some(" and more"),
+            "Unexpected input consumption"
+        );
+        assert_eq!(
+            link.into_inner(),
+            ReferenceLink::new(
+                Description::from("some text"),
+                Cow::from("Ref"),
+                Cow::from("https://example.com"),
+            )
+        );
+        assert!(state.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn reference_link_should_use_description_as_label_if_label_empty() {
+        let state = make_state();
+        let input =
+            Span::from("[Page][]\n\n[page]: page.html").with_state(&state);
+        let (_, link) = reference_link(input).unwrap();
+        assert_eq!(
+            link.into_inner(),
+            ReferenceLink::new(
+                Description::from("Page"),
+                Cow::from(""),
+                Cow::from("page.html"),
+            )
+        );
+    }
+
+    #[test]
+    fn reference_link_should_fail_and_report_if_label_undefined() {
+        let state = make_state();
+        let input = Span::from("[text][missing] after").with_state(&state);
+        assert!(reference_link(input).is_err());
+        assert_eq!(
+            state.diagnostics(),
+            vec![ParseDiagnostic::new(
+                ParseDiagnosticKind::UndefinedReference,
+                Region::new(0, 15)
+                    .with_positions(Position::new(1, 1), Position::new(1, 16)),
+            )]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{link_anchor, link_description, link_path};
+use super::{link_anchor, link_path, maybe_link_description};
 use crate::lang::{
     elements::{Located, WikiLink},
     parsers::{
@@ -6,11 +6,7 @@ use crate::lang::{
         Error, IResult, Span,
     },
 };
-use nom::{
-    bytes::complete::tag,
-    combinator::{map_parser, opt},
-    sequence::preceded,
-};
+use nom::combinator::{map_parser, opt};
 use std::{borrow::Cow, path::PathBuf};
 
 #[inline]
@@ -27,8 +23,7 @@ pub fn wiki_link(input: Span) -> IResult<Located<WikiLink>> {
 
         // Finally, check if there is a description (preceding with |), where
         // a special case is wrapped in {{...}} as a URL
-        let (input, maybe_description) =
-            opt(preceded(tag("|"), link_description))(input)?;
+        let (input, maybe_description) = maybe_link_description(input)?;
 
         match maybe_path {
             Some(path) => Ok((
@@ -62,7 +57,10 @@ pub fn wiki_link(input: Span) -> IResult<Located<WikiLink>> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::lang::elements::{Anchor, Description};
+    use crate::lang::{
+        elements::{Anchor, Description, Link},
+        output::{ToVimwikiString, VimwikiConfig},
+    };
     use std::convert::TryFrom;
     use uriparse::URI;
 
@@ -118,6 +116,42 @@ mod tests {
         assert_eq!(link.anchor, None);
     }
 
+    #[test]
+    fn wiki_link_should_treat_empty_description_as_no_description() {
+        let input = Span::from("[[page|]]");
+        let (input, link) =
+            wiki_link(input).expect("Parser unexpectedly failed");
+
+        // Link should be consumed
+        assert!(input.is_empty());
+
+        assert_eq!(link.path.to_str().unwrap(), "page");
+        assert_eq!(link.description, None);
+        assert_eq!(
+            Link::from(link.into_inner())
+                .to_vimwiki_string(&VimwikiConfig::default()),
+            "[[page]]"
+        );
+    }
+
+    #[test]
+    fn wiki_link_should_treat_whitespace_only_description_as_no_description() {
+        let input = Span::from("[[page| ]]");
+        let (input, link) =
+            wiki_link(input).expect("Parser unexpectedly failed");
+
+        // Link should be consumed
+        assert!(input.is_empty());
+
+        assert_eq!(link.path.to_str().unwrap(), "page");
+        assert_eq!(link.description, None);
+        assert_eq!(
+            Link::from(link.into_inner())
+                .to_vimwiki_string(&VimwikiConfig::default()),
+            "[[page]]"
+        );
+    }
+
     #[test]
     fn wiki_link_should_support_thumbnail_description() {
         let input = Span::from(
@@ -287,4 +321,26 @@ mod tests {
         assert_eq!(link.description, None,);
         assert_eq!(link.anchor, Some(Anchor::from("Tomorrow")));
     }
+
+    #[test]
+    fn wiki_link_should_decode_spaced_target() {
+        let input = Span::from("[[My   Page]]");
+        let (_, link) = wiki_link(input).expect("Parser unexpectedly failed");
+
+        assert_eq!(link.path.to_str().unwrap(), "My   Page");
+        assert_eq!(link.decoded_target(), "My Page");
+        assert_eq!(link.to_string(), "My   Page");
+    }
+
+    #[test]
+    fn wiki_link_should_decode_percent_encoded_target() {
+        let input = Span::from("[[caf%C3%A9%20menu]]");
+        let (_, link) = wiki_link(input).expect("Parser unexpectedly failed");
+
+        assert_eq!(link.path.to_str().unwrap(), "caf%C3%A9%20menu");
+        assert_eq!(
+            Link::from(link.into_inner()).decoded_target(),
+            "caf\u{e9} menu"
+        );
+    }
 }
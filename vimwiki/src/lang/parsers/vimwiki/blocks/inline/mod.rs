@@ -9,6 +9,8 @@ use nom::{branch::alt, combinator::map, multi::many1};
 
 pub mod code;
 pub mod comments;
+pub mod footnotes;
+pub mod hashtags;
 pub mod links;
 pub mod math;
 pub mod tags;
@@ -44,7 +46,9 @@ pub fn inline_element(input: Span) -> IResult<Located<InlineElement>> {
             map(math::math_inline, |c| c.map(InlineElement::from)),
             map(code::code_inline, |c| c.map(InlineElement::from)),
             map(tags::tags, |c| c.map(InlineElement::from)),
+            map(hashtags::hashtag, |c| c.map(InlineElement::from)),
             map(links::link, |c| c.map(InlineElement::from)),
+            map(footnotes::footnote_ref, |c| c.map(InlineElement::from)),
             map(typefaces::decorated_text, |c| c.map(InlineElement::from)),
             map(typefaces::keyword, |c| c.map(InlineElement::from)),
             map(typefaces::text, |c| c.map(InlineElement::from)),
@@ -1,28 +1,31 @@
 use super::{
-    code::code_inline, comments::comment, links::link, math::math_inline,
-    tags::tags,
+    code::code_inline, comments::comment, footnotes::footnote_ref,
+    hashtags::hashtag, links::link, math::math_inline, tags::tags,
 };
 use crate::lang::{
     elements::{
-        DecoratedText, DecoratedTextContent, Keyword, Link, Located, Text,
+        DecoratedText, DecoratedTextContent, FootnoteRef, Keyword, Link,
+        Located, Region, Text,
     },
     parsers::{
         utils::{
             capture, context, cow_str, deeper, locate, not_contains,
             surround_in_line1,
         },
-        IResult, Span,
+        Error, IResult, ParseDiagnostic, ParseDiagnosticKind, Span,
     },
 };
 
+use memchr::memchr_iter;
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take, take_till1},
-    character::complete::newline,
+    bytes::complete::{tag, take},
+    character::complete::{char, newline, one_of},
     combinator::{map, map_parser, not, recognize},
     multi::many1,
-    sequence::preceded,
+    sequence::{pair, preceded},
 };
+use std::borrow::Cow;
 
 #[inline]
 pub fn text(input: Span) -> IResult<Located<Text>> {
@@ -34,7 +37,9 @@ pub fn text(input: Span) -> IResult<Located<Text>> {
         let (input, _) = not(code_inline)(input)?;
         let (input, _) = not(math_inline)(input)?;
         let (input, _) = not(tags)(input)?;
+        let (input, _) = not(hashtag)(input)?;
         let (input, _) = not(link)(input)?;
+        let (input, _) = not(footnote_ref)(input)?;
         let (input, _) = not(decorated_text)(input)?;
         let (input, _) = not(keyword)(input)?;
         Ok((input, ()))
@@ -45,7 +50,9 @@ pub fn text(input: Span) -> IResult<Located<Text>> {
     #[inline]
     fn start_of_non_text(b: u8) -> bool {
         b == b'\n'
+            || b == b'\\'
             || b == b'%'
+            || b == b'#'
             || b == b'`'
             || b == b'$'
             || b == b':'
@@ -62,19 +69,71 @@ pub fn text(input: Span) -> IResult<Located<Text>> {
             || b == b'X'
     }
 
+    /// Takes bytes up to the start of anything that would not be regular
+    /// text, which includes a bare URL at the start of a word when
+    /// autolinking
+    fn plain_bytes(input: Span) -> IResult<Span> {
+        let bytes = input.as_remaining();
+        let mut end = bytes
+            .iter()
+            .position(|b| start_of_non_text(*b))
+            .unwrap_or(bytes.len());
+
+        // NOTE: Bare URLs are only links when autolinking, so we only need
+        //       to look for the start of one in that case
+        if input.autolink_urls() {
+            let starts_url = |pos: usize| {
+                (pos == 0 || !bytes[pos - 1].is_ascii_alphanumeric())
+                    && (bytes[pos..].starts_with(b"http://")
+                        || bytes[pos..].starts_with(b"https://"))
+            };
+            if let Some(pos) =
+                memchr_iter(b'h', &bytes[..end]).find(|pos| starts_url(*pos))
+            {
+                end = pos;
+            }
+        }
+
+        if end == 0 {
+            return Err(nom::Err::Error(Error::from_ctx(
+                &input,
+                "Not plain text",
+            )));
+        }
+
+        take(end)(input)
+    }
+
     fn text_line(input: Span) -> IResult<Span> {
         recognize(many1(alt((
-            take_till1(start_of_non_text),
+            plain_bytes,
+            escaped_bracket,
             preceded(is_text, take(1usize)),
         ))))(input)
     }
 
     context(
         "Text",
-        locate(capture(map(map_parser(text_line, cow_str), Text::new))),
+        locate(capture(map(
+            map_parser(text_line, cow_str),
+            |x: Cow<str>| {
+                if x.contains("\\[") || x.contains("\\]") {
+                    Text::from(x.replace("\\[", "[").replace("\\]", "]"))
+                } else {
+                    Text::new(x)
+                }
+            },
+        ))),
     )(input)
 }
 
+/// Parses a bracket escaped with a backslash (`\[` or `\]`), which is kept
+/// as text rather than starting or ending a link
+#[inline]
+fn escaped_bracket(input: Span) -> IResult<Span> {
+    recognize(pair(char('\\'), one_of("[]")))(input)
+}
+
 #[inline]
 pub fn decorated_text(input: Span) -> IResult<Located<DecoratedText>> {
     context(
@@ -90,12 +149,24 @@ pub fn decorated_text(input: Span) -> IResult<Located<DecoratedText>> {
 }
 
 fn italic_text(input: Span) -> IResult<DecoratedText> {
+    // NOTE: Markdown uses both single asterisks and single underscores for
+    //       italic, whereas vimwiki uses single asterisks for bold
+    fn markers(input: Span) -> IResult<Span> {
+        if input.markdown_emphasis() {
+            alt((surround_in_line1("*", "*"), surround_in_line1("_", "_")))(
+                input,
+            )
+        } else {
+            surround_in_line1("_", "_")(input)
+        }
+    }
+
     context(
         "Italic Decorated Text",
         map(
             map_parser(
-                not_contains("%%", surround_in_line1("_", "_")),
-                deeper(decorated_text_contents),
+                not_contains("%%", markers),
+                nested_decorated_text_contents,
             ),
             DecoratedText::Italic,
         ),
@@ -103,12 +174,24 @@ fn italic_text(input: Span) -> IResult<DecoratedText> {
 }
 
 fn bold_text(input: Span) -> IResult<DecoratedText> {
+    // NOTE: Markdown uses doubled asterisks or underscores for bold, which
+    //       must be tried before italic so the markers are not split
+    fn markers(input: Span) -> IResult<Span> {
+        if input.markdown_emphasis() {
+            alt((surround_in_line1("**", "**"), surround_in_line1("__", "__")))(
+                input,
+            )
+        } else {
+            surround_in_line1("*", "*")(input)
+        }
+    }
+
     context(
         "Bold Decorated Text",
         map(
             map_parser(
-                not_contains("%%", surround_in_line1("*", "*")),
-                deeper(decorated_text_contents),
+                not_contains("%%", markers),
+                nested_decorated_text_contents,
             ),
             DecoratedText::Bold,
         ),
@@ -121,7 +204,7 @@ fn strikeout_text(input: Span) -> IResult<DecoratedText> {
         map(
             map_parser(
                 not_contains("%%", surround_in_line1("~~", "~~")),
-                deeper(decorated_text_contents),
+                nested_decorated_text_contents,
             ),
             DecoratedText::Strikeout,
         ),
@@ -134,7 +217,7 @@ fn superscript_text(input: Span) -> IResult<DecoratedText> {
         map(
             map_parser(
                 not_contains("%%", surround_in_line1("^", "^")),
-                deeper(decorated_text_contents),
+                nested_decorated_text_contents,
             ),
             DecoratedText::Superscript,
         ),
@@ -147,19 +230,42 @@ fn subscript_text(input: Span) -> IResult<DecoratedText> {
         map(
             map_parser(
                 not_contains("%%", surround_in_line1(",,", ",,")),
-                deeper(decorated_text_contents),
+                nested_decorated_text_contents,
             ),
             DecoratedText::Subscript,
         ),
     )(input)
 }
 
+/// Parses the contents of decorated text one level deeper, failing once the
+/// maximum nesting depth is reached so that the surrounding markers are
+/// treated as text instead of recursing any further
+fn nested_decorated_text_contents<'a>(
+    input: Span<'a>,
+) -> IResult<'a, Vec<Located<DecoratedTextContent<'a>>>> {
+    if input.depth() >= input.max_nesting_depth() {
+        input.report(ParseDiagnostic::new(
+            ParseDiagnosticKind::MaxNestingDepthExceeded,
+            Region::from(input),
+        ));
+        return Err(nom::Err::Error(Error::from_ctx(
+            &input,
+            "Max nesting depth exceeded",
+        )));
+    }
+
+    deeper(decorated_text_contents)(input)
+}
+
 fn decorated_text_contents<'a>(
     input: Span<'a>,
-) -> IResult<Vec<Located<DecoratedTextContent<'a>>>> {
+) -> IResult<'a, Vec<Located<DecoratedTextContent<'a>>>> {
     fn inner(input: Span) -> IResult<Vec<Located<DecoratedTextContent>>> {
         many1(alt((
             map(link, |l: Located<Link>| l.map(DecoratedTextContent::from)),
+            map(footnote_ref, |l: Located<FootnoteRef>| {
+                l.map(DecoratedTextContent::from)
+            }),
             map(keyword, |l: Located<Keyword>| {
                 l.map(DecoratedTextContent::from)
             }),
@@ -196,7 +302,10 @@ pub fn keyword(input: Span) -> IResult<Located<Keyword>> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::lang::elements::{Link, WikiLink};
+    use crate::lang::{
+        elements::{Link, Position, WikiLink},
+        parsers::{ParseConfig, ParseState},
+    };
 
     #[test]
     fn text_should_fail_if_input_empty() {
@@ -288,6 +397,42 @@ mod tests {
         assert_eq!(t.into_inner(), Text::from("abc123"));
     }
 
+    #[test]
+    fn text_should_consume_until_bare_url_at_start_of_word_if_autolinking() {
+        let state = ParseState::new(ParseConfig {
+            autolink_urls: true,
+            ..Default::default()
+        });
+        let input = Span::from("the hash of xhttps://a.com is https://b.com")
+            .with_state(&state);
+        let (input, t) = text(input).unwrap();
+        assert_eq!(
+            input.as_unsafe_remaining_str(),
+            "https://b.com",
+            "Unexpected input consumption"
+        );
+        assert_eq!(
+            t.into_inner(),
+            Text::from("the hash of xhttps://a.com is ")
+        );
+    }
+
+    #[test]
+    fn text_should_treat_escaped_brackets_as_literal_text() {
+        let input = Span::from(r"\[\[not a link\]\] and [[a link]]");
+        let (input, t) = text(input).unwrap();
+        assert_eq!(
+            input.as_unsafe_remaining_str(),
+            "[[a link]]",
+            "Unexpected input consumption"
+        );
+        assert_eq!(t.into_inner(), Text::from("[[not a link]] and "));
+
+        let (input, l) = link(input).unwrap();
+        assert!(input.is_empty(), "Did not consume link");
+        assert_eq!(l.into_inner(), Link::from(WikiLink::from("a link")));
+    }
+
     #[test]
     fn decorated_text_should_fail_if_input_empty() {
         let input = Span::from("");
@@ -326,6 +471,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decorated_text_should_keep_single_asterisks_bold_without_markdown_emphasis(
+    ) {
+        let state = ParseState::new(ParseConfig::default());
+        let input = Span::from("*x*").with_state(&state);
+        let (input, dt) = decorated_text(input).unwrap();
+        assert!(input.is_empty(), "Did not consume decorated text");
+        assert_eq!(
+            dt.into_inner(),
+            DecoratedText::Bold(vec![Located::from(
+                DecoratedTextContent::from(Text::from("x"))
+            )])
+        );
+    }
+
+    #[test]
+    fn decorated_text_should_support_markdown_bold_if_markdown_emphasis() {
+        let state = ParseState::new(ParseConfig {
+            markdown_emphasis: true,
+            ..Default::default()
+        });
+
+        for text in &["**x**", "__x__"] {
+            let input = Span::from(*text).with_state(&state);
+            let (input, dt) = decorated_text(input).unwrap();
+            assert!(input.is_empty(), "Did not consume {:?}", text);
+            assert_eq!(
+                dt.into_inner(),
+                DecoratedText::Bold(vec![Located::from(
+                    DecoratedTextContent::from(Text::from("x"))
+                )]),
+                "Unexpected decoration for {:?}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn decorated_text_should_support_markdown_italic_if_markdown_emphasis() {
+        let state = ParseState::new(ParseConfig {
+            markdown_emphasis: true,
+            ..Default::default()
+        });
+
+        for text in &["*x*", "_x_"] {
+            let input = Span::from(*text).with_state(&state);
+            let (input, dt) = decorated_text(input).unwrap();
+            assert!(input.is_empty(), "Did not consume {:?}", text);
+            assert_eq!(
+                dt.into_inner(),
+                DecoratedText::Italic(vec![Located::from(
+                    DecoratedTextContent::from(Text::from("x"))
+                )]),
+                "Unexpected decoration for {:?}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn decorated_text_should_support_markdown_italic_nested_in_bold() {
+        let state = ParseState::new(ParseConfig {
+            markdown_emphasis: true,
+            ..Default::default()
+        });
+        let input = Span::from("**a *b* c**").with_state(&state);
+        let (input, dt) = decorated_text(input).unwrap();
+        assert!(input.is_empty(), "Did not consume decorated text");
+        assert_eq!(
+            dt.into_inner(),
+            DecoratedText::Bold(vec![
+                Located::from(DecoratedTextContent::from(Text::from("a "))),
+                Located::from(DecoratedTextContent::from(
+                    DecoratedText::Italic(vec![Located::from(
+                        DecoratedTextContent::from(Text::from("b"))
+                    )])
+                )),
+                Located::from(DecoratedTextContent::from(Text::from(" c"))),
+            ])
+        );
+    }
+
     #[test]
     fn decorated_text_should_support_strikeout() {
         let input = Span::from("~~strikeout text~~");
@@ -393,6 +620,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decorated_text_should_support_footnote_references() {
+        let input = Span::from("*bold[^2]*");
+        let (input, dt) = decorated_text(input).unwrap();
+        assert!(input.is_empty(), "Did not consume decorated text");
+        assert_eq!(
+            dt.into_inner(),
+            DecoratedText::Bold(vec![
+                Located::from(DecoratedTextContent::from(Text::from("bold"))),
+                Located::from(DecoratedTextContent::from(FootnoteRef::from(
+                    "2"
+                ))),
+            ])
+        );
+    }
+
     #[test]
     fn decorated_text_should_support_nested_decorated_text() {
         let input = Span::from("*bold _italic_*");
@@ -428,6 +671,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decorated_text_should_treat_markers_as_text_beyond_max_nesting_depth() {
+        let state = ParseState::new(ParseConfig {
+            max_nesting_depth: 2,
+            ..Default::default()
+        });
+        let input =
+            Span::from("*bold _italic ~~strikeout~~_*").with_state(&state);
+        let (input, dt) = decorated_text(input).unwrap();
+        assert!(input.is_empty(), "Did not consume decorated text");
+        assert_eq!(
+            dt.into_inner(),
+            DecoratedText::Bold(vec![
+                Located::from(DecoratedTextContent::from(Text::from("bold "))),
+                Located::from(DecoratedTextContent::from(
+                    DecoratedText::Italic(vec![Located::from(
+                        DecoratedTextContent::from(Text::from(
+                            "italic ~~strikeout~~"
+                        ))
+                    )])
+                ))
+            ])
+        );
+        assert_eq!(
+            state.into_diagnostics(),
+            vec![ParseDiagnostic::new(
+                ParseDiagnosticKind::MaxNestingDepthExceeded,
+                Region::new_at_depth(16, 9, 2)
+                    .with_positions(Position::new(1, 17), Position::new(1, 26)),
+            )]
+        );
+    }
+
     #[test]
     fn keyword_should_fail_if_input_empty() {
         let input = Span::from("");
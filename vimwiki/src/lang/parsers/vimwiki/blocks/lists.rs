@@ -2,17 +2,19 @@ use crate::lang::{
     elements::{
         InlineElementContainer, List, ListItem, ListItemAttributes,
         ListItemContent, ListItemContents, ListItemSuffix, ListItemTodoStatus,
-        ListItemType, Located, OrderedListItemType, UnorderedListItemType,
+        ListItemType, Located, OrderedListItemType, Region,
+        UnorderedListItemType,
     },
     parsers::{
         utils::{
-            beginning_of_line, capture, context, deeper, end_of_line_or_input,
-            locate,
+            beginning_of_line, blank_line, capture, context, deeper,
+            end_of_line_or_input, locate,
         },
         vimwiki::blocks::inline::inline_element_container,
-        IResult, Span,
+        Error, IResult, ParseDiagnostic, ParseDiagnosticKind, Span,
     },
 };
+use memchr::memchr;
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_while1},
@@ -25,6 +27,8 @@ use nom::{
 #[inline]
 pub fn list(input: Span) -> IResult<Located<List>> {
     fn inner(input: Span) -> IResult<List> {
+        let start = input;
+
         // A list must at least have one item, whose indentation level we will
         // use to determine how far to go
         let (input, (indentation, item)) = deeper(list_item)(input)?;
@@ -40,10 +44,16 @@ pub fn list(input: Span) -> IResult<Located<List>> {
         //          start with any other list prefix
         //       4. Any item with less indentation terminates a list
         //       5. Non-blank line not starting with a list item terminates a list
-        //       6. Blank line terminates a list
+        //       6. Blank line terminates a list unless the parse config
+        //          allows a single blank line between items
         let (input, (_, items)) = fold_many0(
             preceded(
-                verify(indentation_level(false), |level| *level == indentation),
+                pair(
+                    opt(blank_line_between_items),
+                    verify(indentation_level(false), |level| {
+                        *level == indentation
+                    }),
+                ),
                 map(deeper(list_item), |x| x.1),
             ),
             (1, vec![item]),
@@ -57,6 +67,8 @@ pub fn list(input: Span) -> IResult<Located<List>> {
             },
         )(input)?;
 
+        report_mixed_indentation(start, input);
+
         // NOTE: When parsing list item types, we aren't able to distinguish
         //       alphabetic versus roman numerals as they both involve
         //       alphabetic characters. We need to analyze the entire list after
@@ -68,6 +80,26 @@ pub fn list(input: Span) -> IResult<Located<List>> {
     context("List", locate(capture(inner)))(input)
 }
 
+/// Parses a sublist one level deeper, failing once the maximum nesting depth
+/// is reached so that its items are kept as lines of content of the current
+/// item instead of recursing any further
+fn sublist(input: Span) -> IResult<Located<List>> {
+    if input.depth() >= input.max_nesting_depth() {
+        let len = memchr(b'\n', input.as_remaining())
+            .unwrap_or_else(|| input.remaining_len());
+        input.report(ParseDiagnostic::new(
+            ParseDiagnosticKind::MaxNestingDepthExceeded,
+            Region::from(input.with_length(len)),
+        ));
+        return Err(nom::Err::Error(Error::from_ctx(
+            &input,
+            "Max nesting depth exceeded",
+        )));
+    }
+
+    deeper(list)(input)
+}
+
 /// Parse space/tabs before a list item, followed by the list item
 #[inline]
 pub fn list_item(input: Span) -> IResult<(usize, Located<ListItem>)> {
@@ -114,19 +146,32 @@ fn list_item_tail(
         //    a. The indentation must be GREATER than that of the current item,
         //       otherwise the line would either be a sibling line item or
         //       a parent line item
-        //    b. The line is not blank
+        //    b. The line is not blank, unless the parse config allows a single
+        //       blank line before a sublist
         //
         //    Note that each following line can be additional content or the
         //    start of a sublist, so we need to check for each
-        let (input, mut contents) = many0(preceded(
-            verify(indentation_level(false), |level| *level > indentation),
-            alt((
-                map(deeper(list), |c| c.map(ListItemContent::from)),
-                map(preceded(space0, deeper(list_item_line_content)), |c| {
-                    c.map(ListItemContent::from)
-                }),
-            )),
-        ))(input)?;
+        let (input, mut contents) = many0(alt((
+            preceded(
+                verify(indentation_level(false), |level| *level > indentation),
+                alt((
+                    map(sublist, |c| c.map(ListItemContent::from)),
+                    map(
+                        preceded(space0, deeper(list_item_line_content)),
+                        |c| c.map(ListItemContent::from),
+                    ),
+                )),
+            ),
+            preceded(
+                pair(
+                    blank_line_between_items,
+                    verify(indentation_level(false), |level| {
+                        *level > indentation
+                    }),
+                ),
+                map(sublist, |c| c.map(ListItemContent::from)),
+            ),
+        )))(input)?;
 
         contents.insert(0, content);
 
@@ -143,15 +188,71 @@ fn list_item_tail(
 }
 
 /// Parser that determines the indentation level of the current line based
-/// on its current position
+/// on its current position, where a tab counts for the configured number of
+/// columns
 #[inline]
 fn indentation_level(consume: bool) -> impl Fn(Span) -> IResult<usize> {
+    fn level(s: Span) -> usize {
+        let tab_width = s.tab_width();
+        s.as_remaining()
+            .iter()
+            .map(|b| if *b == b'\t' { tab_width } else { 1 })
+            .sum()
+    }
+
     move |input: Span| {
         if consume {
-            map(space0, |s: Span| s.remaining_len())(input)
+            map(space0, level)(input)
         } else {
-            map(peek(space0), |s: Span| s.remaining_len())(input)
+            map(peek(space0), level)(input)
+        }
+    }
+}
+
+/// Reports the indentation of lines within the list spanning from *start* to
+/// *end* that mix tabs and spaces, either within the line itself or compared
+/// to the first indented line of the list
+fn report_mixed_indentation(start: Span, end: Span) {
+    let len = end.start_offset() - start.start_offset();
+    let mut unit = None;
+    let mut offset = start.start_offset();
+
+    for line in start.as_remaining()[..len].split(|b| *b == b'\n') {
+        let indent = line
+            .iter()
+            .take_while(|b| **b == b' ' || **b == b'\t')
+            .count();
+
+        // NOTE: Blank lines have no bearing on how items are nested
+        if indent > 0 && indent < line.len() {
+            let first = *unit.get_or_insert(line[0]);
+            if line[..indent].iter().any(|b| *b != first) {
+                start.report(ParseDiagnostic::new(
+                    ParseDiagnosticKind::MixedListIndentation,
+                    Region::from(
+                        start
+                            .starting_at(offset - start.start_offset())
+                            .with_length(indent),
+                    ),
+                ));
+            }
         }
+
+        offset += line.len() + 1;
+    }
+}
+
+/// Parser that consumes a single blank line separating list items if the
+/// parse config allows it, otherwise failing without consuming anything
+#[inline]
+fn blank_line_between_items(input: Span) -> IResult<()> {
+    if input.lists_allow_blank_lines() {
+        value((), blank_line)(input)
+    } else {
+        Err(nom::Err::Error(Error::from_ctx(
+            &input,
+            "Blank lines between list items not allowed",
+        )))
     }
 }
 
@@ -172,6 +273,7 @@ fn todo_status(input: Span) -> IResult<ListItemTodoStatus> {
         value(ListItemTodoStatus::PartiallyComplete1, tag(".")),
         value(ListItemTodoStatus::PartiallyComplete2, tag("o")),
         value(ListItemTodoStatus::PartiallyComplete3, tag("O")),
+        value(ListItemTodoStatus::InProgress, tag("/")),
         value(ListItemTodoStatus::Complete, tag("X")),
         value(ListItemTodoStatus::Rejected, tag("-")),
     ))(input)?;
@@ -339,8 +441,9 @@ mod tests {
     use super::*;
     use crate::lang::elements::{
         DecoratedText, DecoratedTextContent, InlineElement, Keyword, Link,
-        MathInline, Tags, Text, WikiLink,
+        MathInline, Position, Tags, Text, WikiLink,
     };
+    use crate::lang::parsers::{ParseConfig, ParseState};
     use indoc::indoc;
     use std::path::PathBuf;
 
@@ -381,6 +484,137 @@ mod tests {
         assert!(list(Span::from("#some item with no space")).is_err());
     }
 
+    #[test]
+    fn list_should_keep_items_separated_by_single_blank_line_if_allowed() {
+        let state = ParseState::new(ParseConfig {
+            lists_allow_blank_lines: true,
+            ..Default::default()
+        });
+        let input = Span::from(indoc! {"
+            - list item 1
+
+            - list item 2
+                - sublist item 1
+
+                - sublist item 2
+
+            - list item 3
+        "})
+        .with_state(&state);
+
+        let (input, l) = list(input).unwrap();
+        assert!(input.is_empty(), "Did not consume list");
+        assert_eq!(l.items.len(), 3, "Unexpected number of list items");
+
+        let sublist = match l.items[1].contents[1].as_inner() {
+            ListItemContent::List(x) => x,
+            x => panic!("Unexpected list item content: {:?}", x),
+        };
+        assert_eq!(
+            sublist.items.len(),
+            2,
+            "Unexpected number of sublist items"
+        );
+
+        // Without the config, the blank line terminates the list
+        let input = Span::from("- list item 1\n\n- list item 2\n");
+        let (input, l) = list(input).unwrap();
+        assert_eq!(input.as_unsafe_remaining_str(), "\n- list item 2\n");
+        assert_eq!(l.items.len(), 1, "Unexpected number of list items");
+    }
+
+    #[test]
+    fn list_should_terminate_at_a_line_of_only_tabs() {
+        let input = Span::from("- list item 1\n\t\n- list item 2\n");
+
+        let (input, l) = list(input).unwrap();
+        assert_eq!(input.as_unsafe_remaining_str(), "\t\n- list item 2\n");
+        assert_eq!(l.items.len(), 1, "Unexpected number of list items");
+    }
+
+    #[test]
+    fn list_should_nest_items_indented_with_two_spaces() {
+        let state = ParseState::default();
+        let input = Span::from("- a\n  - b\n  - c\n- d\n").with_state(&state);
+
+        let (input, l) = list(input).unwrap();
+        assert!(input.is_empty(), "Did not consume list");
+        assert_eq!(l.items.len(), 2, "Unexpected number of list items");
+
+        let sublist = l.items[0].contents.sublist_iter().next().unwrap();
+        assert_eq!(
+            sublist.items.len(),
+            2,
+            "Unexpected number of sublist items"
+        );
+        assert!(state.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn list_should_nest_items_indented_with_tabs() {
+        let state = ParseState::default();
+        let input = Span::from("- a\n\t- b\n\t\t- c\n- d\n").with_state(&state);
+
+        let (input, l) = list(input).unwrap();
+        assert!(input.is_empty(), "Did not consume list");
+        assert_eq!(l.items.len(), 2, "Unexpected number of list items");
+
+        let sublist = l.items[0].contents.sublist_iter().next().unwrap();
+        assert_eq!(
+            sublist.items.len(),
+            1,
+            "Unexpected number of sublist items"
+        );
+        let subsublist =
+            sublist.items[0].contents.sublist_iter().next().unwrap();
+        assert_eq!(subsublist.items.len(), 1);
+        assert!(state.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn list_should_report_diagnostic_if_indentation_mixes_tabs_and_spaces() {
+        let state = ParseState::new(ParseConfig {
+            tab_width: 4,
+            ..Default::default()
+        });
+        let input = Span::from("- a\n\t- b\n    - c\n").with_state(&state);
+
+        let (input, l) = list(input).unwrap();
+        assert!(input.is_empty(), "Did not consume list");
+        assert_eq!(l.items.len(), 1, "Unexpected number of list items");
+
+        // A tab counting for four columns lines up with four spaces
+        let sublist = l.items[0].contents.sublist_iter().next().unwrap();
+        assert_eq!(
+            sublist.items.len(),
+            2,
+            "Unexpected number of sublist items"
+        );
+
+        assert_eq!(
+            state.into_diagnostics(),
+            vec![ParseDiagnostic::new(
+                ParseDiagnosticKind::MixedListIndentation,
+                Region::new_at_depth(9, 4, 2)
+                    .with_positions(Position::new(3, 1), Position::new(3, 5)),
+            )]
+        );
+    }
+
+    #[test]
+    fn list_should_terminate_at_two_blank_lines_even_if_allowed() {
+        let state = ParseState::new(ParseConfig {
+            lists_allow_blank_lines: true,
+            ..Default::default()
+        });
+        let input =
+            Span::from("- list item 1\n\n\n- list item 2\n").with_state(&state);
+
+        let (input, l) = list(input).unwrap();
+        assert_eq!(input.as_unsafe_remaining_str(), "\n\n- list item 2\n");
+        assert_eq!(l.items.len(), 1, "Unexpected number of list items");
+    }
+
     #[test]
     fn list_should_properly_adjust_depth_for_list_items_and_contents() {
         let input = Span::from(indoc! {"
@@ -663,10 +897,11 @@ mod tests {
             - [O] list item 4
             - [X] list item 5
             - [-] list item 6
+            - [/] list item 7
         "});
         let (input, l) = list(input).unwrap();
         assert!(input.is_empty(), "Did not consume list");
-        assert_eq!(l.items.len(), 6, "Unexpected number of list items");
+        assert_eq!(l.items.len(), 7, "Unexpected number of list items");
 
         assert!(l.items[0].is_todo_incomplete());
         assert_eq!(
@@ -703,5 +938,11 @@ mod tests {
             l.items[5].contents.inline_content_iter().next(),
             Some(&InlineElement::Text(Text::from("list item 6"))),
         );
+
+        assert!(l.items[6].is_todo_in_progress());
+        assert_eq!(
+            l.items[6].contents.inline_content_iter().next(),
+            Some(&InlineElement::Text(Text::from("list item 7"))),
+        );
     }
 }
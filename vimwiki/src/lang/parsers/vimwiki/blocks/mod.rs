@@ -1,12 +1,16 @@
 use crate::lang::{
-    elements::{BlockElement, Located},
-    parsers::{utils::context, IResult, Span},
+    elements::{BlockElement, Located, Retained},
+    parsers::{
+        utils::{capture, context},
+        IResult, Span,
+    },
 };
 use nom::{branch::alt, combinator::map};
 
 pub mod blockquotes;
 pub mod definitions;
 pub mod dividers;
+pub mod footnotes;
 pub mod headers;
 pub mod inline;
 pub mod lists;
@@ -14,13 +18,41 @@ pub mod math;
 pub mod paragraphs;
 pub mod placeholders;
 pub mod preformatted;
+pub mod signatures;
 pub mod tables;
 
+/// Parses a block element, keeping the text it was parsed from if the
+/// parse was configured to retain raw text
+pub fn retained_block_element<'a>(
+    input: Span<'a>,
+) -> IResult<'a, Retained<'a, Located<BlockElement<'a>>>> {
+    fn inner<'a>(
+        input: Span<'a>,
+    ) -> IResult<'a, Retained<'a, Located<BlockElement<'a>>>> {
+        let retain_raw = input.retain_raw();
+        let (input, c) = capture(block_element)(input)?;
+        let raw = if retain_raw {
+            Some(c.input().into())
+        } else {
+            None
+        };
+        Ok((input, Retained::new(c.into_inner(), raw)))
+    }
+
+    context("Retained Block Element", inner)(input)
+}
+
 /// Parses a block element
 pub fn block_element(input: Span) -> IResult<Located<BlockElement>> {
     context(
         "Block Element",
         alt((
+            // NOTE: Indented code blocks come first when enabled as their
+            //       indentation would otherwise be read as part of another
+            //       element such as a blockquote
+            map(preformatted::indented_preformatted_text, |c| {
+                c.map(BlockElement::from)
+            }),
             map(headers::header, |c| c.map(BlockElement::from)),
             map(definitions::definition_list, |c| c.map(BlockElement::from)),
             map(lists::list, |c| c.map(BlockElement::from)),
@@ -31,6 +63,8 @@ pub fn block_element(input: Span) -> IResult<Located<BlockElement>> {
             map(math::math_block, |c| c.map(BlockElement::from)),
             map(blockquotes::blockquote, |c| c.map(BlockElement::from)),
             map(dividers::divider, |c| c.map(BlockElement::from)),
+            map(signatures::signature, |c| c.map(BlockElement::from)),
+            map(footnotes::footnote_def, |c| c.map(BlockElement::from)),
             map(placeholders::placeholder, |c| c.map(BlockElement::from)),
             // NOTE: Final type because will match literally anything in a line
             map(paragraphs::paragraph, |c| c.map(BlockElement::from)),
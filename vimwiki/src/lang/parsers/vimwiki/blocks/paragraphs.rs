@@ -1,8 +1,8 @@
 use super::{
     blockquotes::blockquote, definitions::definition_list, dividers::divider,
-    headers::header, inline::inline_element_container, lists::list,
-    math::math_block, placeholders::placeholder,
-    preformatted::preformatted_text, tables::table,
+    footnotes::footnote_def, headers::header, inline::inline_element_container,
+    lists::list, math::math_block, placeholders::placeholder,
+    preformatted::preformatted_text, signatures::signature, tables::table,
 };
 use crate::lang::{
     elements::{InlineElementContainer, Located, Paragraph},
@@ -61,6 +61,13 @@ fn paragraph_line(input: Span) -> IResult<InlineElementContainer> {
 //       includes an Option<BlockElement> so that we don't waste
 //       the processing spent
 fn continue_paragraph(input: Span) -> IResult<()> {
+    // NOTE: Indented code blocks cannot interrupt a paragraph, so an
+    //       indented line continues the paragraph rather than starting a
+    //       blockquote when they are enabled
+    if input.indented_code_blocks() && is_indented_line(input) {
+        return Ok((input, ()));
+    }
+
     let (input, _) = not(header)(input)?;
     let (input, _) = not(definition_list)(input)?;
     let (input, _) = not(list)(input)?;
@@ -71,9 +78,20 @@ fn continue_paragraph(input: Span) -> IResult<()> {
     let (input, _) = not(blockquote)(input)?;
     let (input, _) = not(divider)(input)?;
     let (input, _) = not(placeholder)(input)?;
+    let (input, _) = not(footnote_def)(input)?;
+    let (input, _) = not(signature)(input)?;
     Ok((input, ()))
 }
 
+/// Returns true if the input starts with a non-blank line indented by four
+/// spaces or a tab
+fn is_indented_line(input: Span) -> bool {
+    let text = input.as_unsafe_remaining_str();
+    let line = text.split('\n').next().unwrap_or_default();
+    (line.starts_with("    ") || line.starts_with('\t'))
+        && !line.trim().is_empty()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,6 +99,7 @@ mod tests {
         DecoratedText, DecoratedTextContent, InlineElement, Link, MathInline,
         Text, WikiLink,
     };
+    use crate::lang::parsers::{ParseConfig, ParseState};
     use indoc::indoc;
     use std::path::PathBuf;
 
@@ -189,6 +208,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn paragraph_should_stop_at_a_line_of_only_spaces() {
+        let input = Span::from("Some paragraph\ncontinued\n   \nAnother\n");
+        let (input, p) = paragraph(input).unwrap();
+        assert_eq!(
+            input.as_unsafe_remaining_str(),
+            "   \nAnother\n",
+            "Unexpected consumption of input"
+        );
+        assert_eq!(p.content.to_string(), "Some paragraphcontinued");
+    }
+
     #[test]
     fn paragraph_should_stop_at_a_blank_line() {
         let input = Span::from(indoc! {"
@@ -227,4 +258,23 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn paragraph_should_continue_with_indented_line_if_indented_code_blocks() {
+        let state = ParseState::new(ParseConfig {
+            indented_code_blocks: true,
+            ..Default::default()
+        });
+        let input = Span::from(indoc! {"
+        Some paragraph
+            indented continuation
+        "})
+        .with_state(&state);
+        let (input, p) = paragraph(input).unwrap();
+        assert!(input.is_empty(), "Did not consume paragraph");
+        assert_eq!(
+            p.content.to_string(),
+            "Some paragraphindented continuation"
+        );
+    }
 }
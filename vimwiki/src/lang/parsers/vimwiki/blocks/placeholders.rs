@@ -3,18 +3,19 @@ use crate::lang::{
     parsers::{
         utils::{
             beginning_of_line, capture, context, cow_str, end_of_line_or_input,
-            locate, take_line_until_one_of_three1,
+            locate, take_line_until1, take_line_until_one_of_three1,
             take_until_end_of_line_or_input,
         },
-        IResult, Span,
+        Error, IResult, Span,
     },
 };
 use chrono::NaiveDate;
 use nom::{
     branch::alt,
-    bytes::complete::tag,
+    bytes::complete::{tag, take_while1},
     character::complete::{space0, space1},
     combinator::{map_parser, map_res, not, verify},
+    sequence::delimited,
 };
 
 #[inline]
@@ -26,6 +27,8 @@ pub fn placeholder(input: Span) -> IResult<Located<Placeholder>> {
             placeholder_nohtml,
             placeholder_template,
             placeholder_date,
+            placeholder_include,
+            placeholder_link_reference,
             placeholder_other,
         ))))(input)?;
         let (input, _) = end_of_line_or_input(input)?;
@@ -94,6 +97,56 @@ fn placeholder_date(input: Span) -> IResult<Placeholder> {
     context("Placeholder Date", inner)(input)
 }
 
+/// Parses `#include path`, where the path cannot contain whitespace and is
+/// the only thing on the line
+fn placeholder_include(input: Span) -> IResult<Placeholder> {
+    fn inner(input: Span) -> IResult<Placeholder> {
+        let (input, _) = tag("#include")(input)?;
+        let (input, _) = space1(input)?;
+        let (input, path) = map_parser(
+            take_while1(|b: u8| !b.is_ascii_whitespace()),
+            cow_str,
+        )(input)?;
+        let (input, _) = space0(input)?;
+        Ok((input, Placeholder::Include(path)))
+    }
+
+    context("Placeholder Include", inner)(input)
+}
+
+/// Parses `[label]: target` defining the target of reference links with the
+/// label, which is only done when reference links are enabled
+fn placeholder_link_reference(input: Span) -> IResult<Placeholder> {
+    fn inner(input: Span) -> IResult<Placeholder> {
+        if !input.reference_links() {
+            return Err(nom::Err::Error(Error::from_ctx(
+                &input,
+                "Reference links are not enabled",
+            )));
+        }
+
+        let (input, label) = delimited(
+            tag("["),
+            map_parser(
+                verify(take_line_until1("]"), |s: &Span| {
+                    !s.as_remaining().contains(&b'[')
+                }),
+                cow_str,
+            ),
+            tag("]:"),
+        )(input)?;
+        let (input, _) = space0(input)?;
+        let (input, target) = map_parser(
+            take_while1(|b: u8| !b.is_ascii_whitespace()),
+            cow_str,
+        )(input)?;
+        let (input, _) = space0(input)?;
+        Ok((input, Placeholder::LinkReference { label, target }))
+    }
+
+    context("Placeholder Link Reference", inner)(input)
+}
+
 fn placeholder_other(input: Span) -> IResult<Placeholder> {
     fn inner(input: Span) -> IResult<Placeholder> {
         let (input, _) = not(tag("%title"))(input)?;
@@ -122,6 +175,7 @@ fn placeholder_other(input: Span) -> IResult<Placeholder> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lang::parsers::{ParseConfig, ParseState};
 
     #[test]
     fn placeholder_should_fail_if_input_empty() {
@@ -200,6 +254,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn placeholder_should_fail_if_include_with_no_path() {
+        let input = Span::from("#include");
+        assert!(placeholder(input).is_err());
+    }
+
+    #[test]
+    fn placeholder_should_fail_if_include_path_contains_whitespace() {
+        let input = Span::from("#include some path");
+        assert!(placeholder(input).is_err());
+    }
+
+    #[test]
+    fn placeholder_should_succeed_if_include_with_path_input() {
+        let input = Span::from("#include path/to/file.wiki");
+        let (input, placeholder) = placeholder(input).unwrap();
+        assert!(input.is_empty(), "Did not consume placeholder");
+        assert_eq!(
+            placeholder.into_inner(),
+            Placeholder::include_from_str("path/to/file.wiki"),
+        );
+    }
+
+    #[test]
+    fn placeholder_should_fail_if_link_reference_not_enabled() {
+        let input = Span::from("[label]: https://example.com");
+        assert!(placeholder(input).is_err());
+    }
+
+    #[test]
+    fn placeholder_should_succeed_if_link_reference_enabled() {
+        let state = ParseState::new(ParseConfig {
+            reference_links: true,
+            ..Default::default()
+        });
+        let input =
+            Span::from("[label]: https://example.com").with_state(&state);
+        let (input, placeholder) = placeholder(input).unwrap();
+        assert!(input.is_empty(), "Did not consume placeholder");
+        assert_eq!(
+            placeholder.into_inner(),
+            Placeholder::link_reference_from_str(
+                "label",
+                "https://example.com"
+            ),
+        );
+    }
+
     #[test]
     fn placeholder_fallback_should_fail_if_double_percent_at_start() {
         let input = Span::from("%%other something else");
@@ -2,18 +2,19 @@ use crate::lang::{
     elements::{Located, PreformattedText},
     parsers::{
         utils::{
-            any_line, beginning_of_line, capture, context, cow_str,
+            any_line, beginning_of_line, blank_line, capture, context, cow_str,
             end_of_line_or_input, locate, take_line_until, take_line_until1,
         },
-        IResult, Span,
+        Error, IResult, Span,
     },
 };
 use nom::{
+    branch::alt,
     bytes::complete::tag,
-    character::complete::{char, space0, space1},
-    combinator::{map_parser, not, opt, verify},
+    character::complete::{char, not_line_ending, space0, space1},
+    combinator::{map, map_parser, not, opt, value, verify},
     multi::{many0, separated_list0},
-    sequence::{delimited, preceded, separated_pair},
+    sequence::{delimited, pair, preceded, separated_pair},
 };
 use std::{borrow::Cow, collections::HashMap};
 
@@ -36,6 +37,54 @@ pub fn preformatted_text(input: Span) -> IResult<Located<PreformattedText>> {
     context("Preformatted Text", locate(capture(inner)))(input)
 }
 
+/// Parses a markdown-style block of lines indented by four spaces or a tab
+/// as preformatted text with no language, removing one level of indentation
+/// from each line. Blank lines are kept so long as more indented lines
+/// follow them
+#[inline]
+pub fn indented_preformatted_text(
+    input: Span,
+) -> IResult<Located<PreformattedText>> {
+    fn inner(input: Span) -> IResult<PreformattedText> {
+        if !input.indented_code_blocks() {
+            return Err(nom::Err::Error(Error::from_ctx(
+                &input,
+                "Indented code blocks are not enabled",
+            )));
+        }
+
+        let (input, first) = indented_line(input)?;
+        let (input, rest) = many0(map(
+            pair(many0(value(Span::default(), blank_line)), indented_line),
+            |(mut blanks, line)| {
+                blanks.push(line);
+                blanks
+            },
+        ))(input)?;
+
+        let lines = std::iter::once(first)
+            .chain(rest.into_iter().flatten())
+            .map(Cow::from)
+            .collect();
+        Ok((input, PreformattedText::new(None, HashMap::new(), lines)))
+    }
+
+    context("Indented Preformatted Text", locate(capture(inner)))(input)
+}
+
+/// Parses a non-blank line indented by four spaces or a tab, returning the
+/// line without that indentation
+#[inline]
+fn indented_line<'a>(input: Span<'a>) -> IResult<'a, Span<'a>> {
+    let (input, _) = beginning_of_line(input)?;
+    let (input, _) = alt((tag("    "), tag("\t")))(input)?;
+    let (input, text) =
+        verify(not_line_ending, |s: &Span<'a>| !s.is_only_whitespace())(input)?;
+    let (input, _) = end_of_line_or_input(input)?;
+
+    Ok((input, text))
+}
+
 #[inline]
 fn preformatted_text_start<'a>(
     input: Span<'a>,
@@ -95,8 +144,16 @@ fn preformatted_text_end(input: Span) -> IResult<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lang::parsers::{ParseConfig, ParseState};
     use indoc::indoc;
 
+    fn make_state() -> ParseState {
+        ParseState::new(ParseConfig {
+            indented_code_blocks: true,
+            ..Default::default()
+        })
+    }
+
     #[test]
     fn preformatted_text_should_fail_if_does_not_have_starting_line() {
         let input = Span::from(indoc! {r"
@@ -255,4 +312,55 @@ mod tests {
             Some(&Cow::from("position: relative"))
         );
     }
+
+    #[test]
+    fn indented_preformatted_text_should_fail_if_not_enabled() {
+        let input = Span::from("    some code\n");
+        assert!(indented_preformatted_text(input).is_err());
+    }
+
+    #[test]
+    fn indented_preformatted_text_should_dedent_lines_by_one_level() {
+        let state = make_state();
+        let input = Span::from(indoc! {"
+                fn main() {
+                    println!(\"hi\");
+                }
+
+                // done
+
+            after
+        "})
+        .with_state(&state);
+        let (input, p) = indented_preformatted_text(input).unwrap();
+        assert_eq!(
+            input.as_unsafe_remaining_str(),
+            "\nafter\n",
+            "Unexpected input consumption"
+        );
+        assert!(p.lang.is_none(), "Has unexpected language");
+        assert!(p.metadata.is_empty(), "Has unexpected metadata");
+        assert_eq!(
+            p.lines,
+            vec![
+                Cow::from("fn main() {"),
+                Cow::from("    println!(\"hi\");"),
+                Cow::from("}"),
+                Cow::from(""),
+                Cow::from("// done"),
+            ]
+        );
+    }
+
+    #[test]
+    fn indented_preformatted_text_should_support_tab_indentation() {
+        let state = make_state();
+        let input = Span::from("\tsome code\n\t\tmore code").with_state(&state);
+        let (input, p) = indented_preformatted_text(input).unwrap();
+        assert!(input.is_empty(), "Did not consume preformatted block");
+        assert_eq!(
+            p.lines,
+            vec![Cow::from("some code"), Cow::from("\tmore code")]
+        );
+    }
 }
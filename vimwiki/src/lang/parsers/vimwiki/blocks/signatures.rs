@@ -0,0 +1,99 @@
+use crate::lang::{
+    elements::{Located, Signature},
+    parsers::{
+        utils::{
+            beginning_of_line, capture, context, cow_str, end_of_line_or_input,
+            locate, take_until_end_of_line_or_input,
+        },
+        Error, IResult, Span,
+    },
+};
+use nom::{
+    bytes::complete::tag,
+    combinator::{map_parser, verify},
+};
+use std::borrow::Cow;
+
+/// Parses a signature in the form of `-- Author, Date` when enabled by the
+/// parse configuration, only matching the last line of the input
+#[inline]
+pub fn signature(input: Span) -> IResult<Located<Signature>> {
+    fn inner(input: Span) -> IResult<Signature> {
+        if !input.parse_signatures() {
+            return Err(nom::Err::Error(Error::from_ctx(
+                &input,
+                "Signatures not enabled",
+            )));
+        }
+
+        let (input, _) = beginning_of_line(input)?;
+        let (input, _) = tag("-- ")(input)?;
+        let (input, text) = map_parser(
+            verify(take_until_end_of_line_or_input, |s: &Span| {
+                !s.as_remaining().iter().all(u8::is_ascii_whitespace)
+            }),
+            cow_str,
+        )(input)?;
+        let (input, _) = end_of_line_or_input(input)?;
+
+        // NOTE: A signature must end the document, so anything other than
+        //       trailing whitespace means this is not a signature
+        if !input.as_remaining().iter().all(u8::is_ascii_whitespace) {
+            return Err(nom::Err::Error(Error::from_ctx(
+                &input,
+                "Signature not at end of input",
+            )));
+        }
+
+        let text = match text {
+            Cow::Borrowed(x) => Cow::Borrowed(x.trim_end()),
+            Cow::Owned(x) => Cow::Owned(x.trim_end().to_string()),
+        };
+
+        Ok((input, Signature::new(text)))
+    }
+
+    context("Signature", locate(capture(inner)))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::parsers::{ParseConfig, ParseState};
+
+    fn enabled() -> ParseState {
+        ParseState::new(ParseConfig {
+            parse_signatures: true,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn signature_should_fail_if_not_enabled() {
+        let input = Span::from("-- Author, Date");
+        assert!(signature(input).is_err());
+    }
+
+    #[test]
+    fn signature_should_fail_if_not_last_line() {
+        let state = enabled();
+        let input = Span::from("-- Author, Date\nmore text").with_state(&state);
+        assert!(signature(input).is_err());
+    }
+
+    #[test]
+    fn signature_should_fail_if_divider() {
+        let state = enabled();
+        let input = Span::from("----").with_state(&state);
+        assert!(signature(input).is_err());
+    }
+
+    #[test]
+    fn signature_should_consume_last_line_starting_with_dashes() {
+        let state = enabled();
+        let input = Span::from("-- Author, Date  \n\n").with_state(&state);
+        let (input, s) = signature(input).unwrap();
+        assert_eq!(input.as_unsafe_remaining_str(), "\n");
+        assert_eq!(s.into_inner(), Signature::from("Author, Date"));
+    }
+}
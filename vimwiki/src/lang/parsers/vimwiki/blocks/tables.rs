@@ -1,28 +1,34 @@
 use super::inline::inline_element_container;
 use crate::lang::{
     elements::{
-        Cell, ColumnAlign, InlineElementContainer, Located, Row, Table,
+        Cell, ColumnAlign, InlineElement, InlineElementContainer, Located, Row,
+        Table, Text,
     },
     parsers::{
         utils::{
             capture, context, deeper, end_of_line_or_input, locate,
-            take_line_until1, take_line_while1,
+            take_line_while1,
         },
-        IResult, Span,
+        Error, IResult, Span,
     },
 };
+use memchr::memchr2_iter;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, space0},
+    character::complete::{char, not_line_ending, space0},
     combinator::{map, map_parser, opt, value, verify},
     multi::{many0, separated_list1},
     sequence::{delimited, pair, preceded, terminated},
+    AsBytes, InputLength, InputTake,
 };
 
 #[inline]
 pub fn table(input: Span) -> IResult<Located<Table>> {
     fn inner(input: Span) -> IResult<Table> {
+        // A caption can come directly before the table
+        let (input, leading_caption) = opt(caption)(input)?;
+
         // Assume a table is centered if the first row is indented
         let (input, (table_header, centered)) =
             map(pair(space0, deeper(row)), |x| (x.1, !x.0.is_empty()))(input)?;
@@ -32,7 +38,15 @@ pub fn table(input: Span) -> IResult<Located<Table>> {
         //       the earlier row parse
         let (input, mut rows) = many0(preceded(space0, deeper(row)))(input)?;
         rows.insert(0, table_header);
-        Ok((input, Table::new(rows, centered)))
+
+        // If there was no caption before the table, it can come directly
+        // after the table instead
+        let (input, caption) = match leading_caption {
+            Some(x) => (input, Some(x)),
+            None => opt(caption)(input)?,
+        };
+
+        Ok((input, Table::new(rows, centered, caption)))
     }
 
     // Parse the table and make sure it isn't comprised entirely of divider rows
@@ -46,6 +60,35 @@ pub fn table(input: Span) -> IResult<Located<Table>> {
     )(input)
 }
 
+/// Parses a line of the form `Table: caption`, returning the content of
+/// the caption with surrounding whitespace removed
+#[inline]
+fn caption(input: Span) -> IResult<InlineElementContainer> {
+    fn inner(input: Span) -> IResult<InlineElementContainer> {
+        let (input, _) = preceded(space0, tag("Table:"))(input)?;
+        let (input, line) =
+            terminated(not_line_ending, end_of_line_or_input)(input)?;
+
+        let text = line.as_unsafe_remaining_str();
+        let rest = text.trim_start();
+        let content = rest.trim_end();
+        if content.is_empty() {
+            return Err(nom::Err::Error(Error::from_ctx(
+                &line,
+                "Caption is empty",
+            )));
+        }
+
+        let (_, container) = inline_element_container(
+            line.starting_at(text.len() - rest.len())
+                .with_length(content.len()),
+        )?;
+        Ok((input, container.into_inner()))
+    }
+
+    context("Caption", inner)(input)
+}
+
 #[inline]
 fn row(input: Span) -> IResult<Located<Row>> {
     fn inner(input: Span) -> IResult<Row> {
@@ -88,9 +131,9 @@ fn cell(input: Span) -> IResult<Located<Cell>> {
             cell_span_above,
             cell_span_left,
             map(
-                map_parser(take_line_until1("|"), inline_element_container),
+                map_parser(cell_content, inline_element_container),
                 |l: Located<InlineElementContainer>| {
-                    Cell::Content(l.into_inner())
+                    Cell::Content(unescape_pipes(l.into_inner()))
                 },
             ),
         ))(input)
@@ -99,6 +142,43 @@ fn cell(input: Span) -> IResult<Located<Cell>> {
     context("Cell", locate(capture(inner)))(input)
 }
 
+/// Consumes the content of a cell up to the next pipe or end of line,
+/// skipping over pipes that are escaped as `\|`
+#[inline]
+fn cell_content(input: Span) -> IResult<Span> {
+    let bytes = input.as_bytes();
+    let end = memchr2_iter(b'\n', b'|', bytes)
+        .find(|&pos| bytes[pos] == b'\n' || pos == 0 || bytes[pos - 1] != b'\\')
+        .unwrap_or_else(|| input.input_len());
+
+    if end == 0 {
+        return Err(nom::Err::Error(Error::from_ctx(
+            &input,
+            "Cell content is empty",
+        )));
+    }
+
+    Ok(input.take_split(end))
+}
+
+/// Replaces escaped pipes within the text of a cell with literal pipes
+fn unescape_pipes(container: InlineElementContainer) -> InlineElementContainer {
+    let elements = container
+        .elements
+        .into_iter()
+        .map(|element| {
+            element.map(|element| match element {
+                InlineElement::Text(x) if x.0.contains("\\|") => {
+                    InlineElement::Text(Text::from(x.0.replace("\\|", "|")))
+                }
+                x => x,
+            })
+        })
+        .collect();
+
+    InlineElementContainer::new(elements)
+}
+
 #[inline]
 fn cell_span_left(input: Span) -> IResult<Cell> {
     value(Cell::SpanLeft, delimited(space0, tag(">"), space0))(input)
@@ -195,6 +275,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn table_should_treat_escaped_pipe_as_literal_pipe_within_cell() {
+        let input = Span::from(indoc! {r"
+        |a \| b|c|
+        "});
+        let (_, t) = table(input).unwrap();
+
+        let cells = match t.rows[0].as_inner() {
+            Row::Content { cells } => cells,
+            x => panic!("Unexpected row: {:?}", x),
+        };
+        assert_eq!(cells.len(), 2, "Unexpected number of cells");
+        check_cell_text_value(cells[0].as_inner(), "a | b");
+        check_cell_text_value(cells[1].as_inner(), "c");
+    }
+
     #[test]
     fn table_should_succeed_if_uneven_columns_found() {
         let input = Span::from(indoc! {"
@@ -441,4 +537,49 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn table_should_support_caption_line_before_table() {
+        let input = Span::from(indoc! {"
+        Table: Some *important* values
+        |value1|
+        "});
+        let (input, t) = table(input).unwrap();
+        assert!(input.is_empty(), "Did not consume table");
+        assert_eq!(t.rows.len(), 1);
+
+        let caption = t.caption().expect("Missing caption");
+        assert_eq!(caption.to_string(), "Some important values");
+        assert_eq!(
+            caption.elements[0].region().offset(),
+            7,
+            "Caption region not relative to input"
+        );
+    }
+
+    #[test]
+    fn table_should_support_caption_line_after_table() {
+        let input = Span::from(indoc! {"
+        |value1|
+        |value2|
+        Table: Some values
+        "});
+        let (input, t) = table(input).unwrap();
+        assert!(input.is_empty(), "Did not consume table");
+        assert_eq!(t.rows.len(), 2);
+
+        let caption = t.caption().expect("Missing caption");
+        assert_eq!(caption.to_string(), "Some values");
+    }
+
+    #[test]
+    fn table_should_not_have_caption_if_caption_line_is_empty() {
+        let input = Span::from(indoc! {"
+        |value1|
+        Table:
+        "});
+        let (input, t) = table(input).unwrap();
+        assert_eq!(input.as_unsafe_remaining_str(), "Table:\n");
+        assert_eq!(t.caption(), None);
+    }
 }
@@ -15,6 +15,8 @@ pub mod blocks;
 
 pub fn page<'a>(input: Span<'a>) -> IResult<Page<'a>> {
     fn inner<'a>(input: Span<'a>) -> IResult<Page<'a>> {
+        let start = input;
+
         // Parses one or more lines, either eating blank lines or producing
         // a block element
         fn maybe_block_element(
@@ -25,9 +27,36 @@ pub fn page<'a>(input: Span<'a>) -> IResult<Page<'a>> {
             )
         }
 
-        map(all_consuming(many0(maybe_block_element)), |mut elements| {
-            Page::new(elements.drain(..).flatten().collect())
-        })(input)
+        let (input, mut elements): (_, Vec<Located<BlockElement>>) =
+            map(all_consuming(many0(maybe_block_element)), |elements| {
+                elements.into_iter().flatten().collect()
+            })(input)?;
+
+        // NOTE: The last block only covers a newline if the input ends with
+        //       one, so we extend the block by the missing newline when
+        //       configured to keep its region the same either way. Elements
+        //       nested within the block are left as they are, as only some
+        //       of them would cover the newline
+        if start.assume_trailing_newline()
+            && !start.is_empty()
+            && !start.as_remaining().ends_with(b"\n")
+        {
+            if let Some(element) = elements.last_mut() {
+                let region = element.region();
+                if region.end() == start.end_offset() {
+                    let end =
+                        Position::new(region.end_position().line() + 1, 1);
+                    *element.region_mut() = Region::new_at_depth(
+                        region.offset(),
+                        region.len() + 1,
+                        region.depth(),
+                    )
+                    .with_positions(region.start_position(), end);
+                }
+            }
+        }
+
+        Ok((input, Page::new(elements)))
     }
 
     context("Page", inner)(input)
@@ -36,6 +65,7 @@ pub fn page<'a>(input: Span<'a>) -> IResult<Page<'a>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lang::parsers::{ParseConfig, ParseState};
 
     #[test]
     fn page_should_skip_blank_lines_not_within_block_elements() {
@@ -55,4 +85,80 @@ mod tests {
             ])))]
         );
     }
+
+    #[test]
+    fn page_should_parse_tables_separated_by_blank_line_as_separate_blocks() {
+        let input = "| a | b |\n|---|---|\n| c | d |\n\n| e |\n| f |\n";
+        let (_, page) = page(Span::from(input)).unwrap();
+        assert_eq!(page.elements().len(), 2, "Unexpected number of blocks");
+
+        let tables: Vec<_> = page
+            .elements()
+            .iter()
+            .map(|x| match x.as_inner() {
+                BlockElement::Table(t) => (t.rows.len(), x.region()),
+                x => panic!("Unexpected element: {:?}", x),
+            })
+            .collect();
+
+        assert_eq!(tables[0].0, 3, "Wrong number of rows in first table");
+        assert_eq!(tables[1].0, 2, "Wrong number of rows in second table");
+        assert_eq!(tables[0].1.offset(), 0);
+        assert_eq!(tables[1].1.offset(), input.find("| e |").unwrap());
+    }
+
+    #[test]
+    fn page_should_keep_last_region_without_trailing_newline_if_configured() {
+        let state = ParseState::new(ParseConfig {
+            assume_trailing_newline: true,
+            ..Default::default()
+        });
+        let last_region = |input: &str| {
+            let (_, page) = page(Span::from(input).with_state(&state)).unwrap();
+            page.elements().last().unwrap().region()
+        };
+
+        for input in &["= Header =\n\nsome text", "- one\n- two", "|a|b|"] {
+            assert_eq!(
+                last_region(input),
+                last_region(&format!("{}\n", input)),
+                "Regions differ for {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn page_should_only_extend_region_of_last_block_and_not_its_contents() {
+        let state = ParseState::new(ParseConfig {
+            assume_trailing_newline: true,
+            ..Default::default()
+        });
+        let last_item_region = |input: &str| {
+            let (_, page) = page(Span::from(input).with_state(&state)).unwrap();
+            match page.elements().last().unwrap().as_inner() {
+                BlockElement::List(x) => x.items.last().unwrap().region(),
+                x => panic!("Unexpected element: {:?}", x),
+            }
+        };
+
+        // NOTE: Only the block itself is extended, so the last item of a
+        //       list still ends before the newline that is assumed
+        let region = last_item_region("- one\n- two");
+        assert_eq!((region.offset(), region.len()), (6, 5));
+        assert_eq!(region.end_position(), Position::new(2, 6));
+        let region = last_item_region("- one\n- two\n");
+        assert_eq!((region.offset(), region.len()), (6, 6));
+        assert_eq!(region.end_position(), Position::new(3, 1));
+    }
+
+    #[test]
+    fn page_should_not_extend_last_region_by_default() {
+        let (_, page) = page(Span::from("some text")).unwrap();
+        assert_eq!(
+            page.elements()[0].region(),
+            Region::new(0, 9)
+                .with_positions(Position::new(1, 1), Position::new(1, 10))
+        );
+    }
 }
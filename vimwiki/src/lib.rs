@@ -4,11 +4,19 @@ mod utils;
 // Export all elements at top level
 pub use lang::elements::*;
 
+// Export our output formats for rendering elements
+pub use lang::output::*;
+
 // Export our parser error, which is used for language parsing
 pub use lang::parsers::Error as ParseError;
 
+// Export our parser configuration and the diagnostics it can report
+pub use lang::parsers::{
+    ParseConfig, ParseDiagnostic, ParseDiagnosticKind, ParseState,
+};
+
 // Export our primary language structure and trait
-pub use lang::{FromLanguage, Language};
+pub use lang::{FromLanguage, IncludedPage, Language};
 
 // Export our trait to do stronger comparsisons that include the region of elements
 pub use utils::StrictEq;
@@ -20,5 +28,11 @@ pub mod vendor {
     pub use uriparse;
 }
 
+#[cfg(feature = "bench")]
+pub mod bench;
+
+#[cfg(any(test, feature = "test-util"))]
+pub mod testing;
+
 #[cfg(feature = "timekeeper")]
 pub mod timekeeper;
@@ -0,0 +1,92 @@
+use crate::{Language, Page, ToVimwikiString, VimwikiConfig};
+
+/// Parses the source as a vimwiki page, writes the page back out as vimwiki,
+/// and parses the written text again, asserting that both pages contain the
+/// same elements. Regions are not compared, so the written text is free to
+/// lay out elements differently than the source
+///
+/// ## Panics
+///
+/// Panics if either parse fails or if the pages contain different elements
+pub fn assert_roundtrip(source: &str) {
+    let page: Page = match Language::from_vimwiki_str(source).parse() {
+        Ok(x) => x,
+        Err(x) => panic!("Failed to parse source: {}", x),
+    };
+
+    let text = page.to_vimwiki_string(&VimwikiConfig::default());
+    let reparsed: Page = match Language::from_vimwiki_str(&text).parse() {
+        Ok(x) => x,
+        Err(x) => panic!("Failed to parse written text: {}\n{}", x, text),
+    };
+
+    // NOTE: Rather than comparing pages directly, we iterate through the
+    //       elements so we can report which element changed
+    for (i, (before, after)) in page
+        .elements
+        .iter()
+        .zip(reparsed.elements.iter())
+        .enumerate()
+    {
+        assert_eq!(
+            before, after,
+            "Elements at index {} changed after round trip through:\n{}",
+            i, text
+        );
+    }
+
+    assert_eq!(
+        page.elements.len(),
+        reparsed.elements.len(),
+        "Varying number of elements after round trip through:\n{}",
+        text
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn assert_roundtrip_should_succeed_for_fixtures() {
+        let fixtures = &[
+            indoc! {"
+                = *Header* =
+
+                some _italic_ text with `code` and a [[link|description]]
+            "},
+            indoc! {"
+                - one
+                    1. sub one
+                    2. sub two
+                - [X] two
+            "},
+            indoc! {"
+                %title Some Title
+
+                {{{rust
+                fn main() {}
+                }}}
+
+                ----
+
+                > some quote
+            "},
+            indoc! {"
+                x^2^ and a,,x,, and ~~gone~~ TODO
+            "},
+        ];
+
+        for fixture in fixtures {
+            assert_roundtrip(fixture);
+        }
+    }
+
+    #[test]
+    fn assert_roundtrip_should_keep_escaped_brackets_as_text() {
+        // NOTE: Text that looks like a link must be written back out escaped,
+        //       otherwise it would be parsed as a link the second time around
+        assert_roundtrip("\\[\\[not a link]] and [[a link]]\n");
+    }
+}
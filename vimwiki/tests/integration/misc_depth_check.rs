@@ -15,6 +15,7 @@ fn test() {
                     Region::new_at_depth(2, 8, 1),
                 )]),
                 false,
+                None,
             )),
             Region::new_at_depth(0, 13, 0),
         ),
@@ -308,3 +309,65 @@ fn test() {
         expected_page
     );
 }
+
+#[test]
+fn positions() {
+    let contents = VimwikiFile::MiscDepthCheck.load().unwrap();
+    let page: Page = Language::from_vimwiki_str(&contents).parse().unwrap();
+    let positions =
+        |region: Region| (region.start_position(), region.end_position());
+
+    // Blocks span whole lines, ending at the start of the following line
+    let elements = page.elements();
+    assert_eq!(
+        positions(elements[0].region()),
+        (Position::new(1, 1), Position::new(2, 1))
+    );
+    assert_eq!(
+        positions(elements[1].region()),
+        (Position::new(3, 1), Position::new(5, 1))
+    );
+    assert_eq!(
+        positions(elements[2].region()),
+        (Position::new(6, 1), Position::new(14, 1))
+    );
+
+    // Inline elements are positioned within their lines, including those
+    // nested within decorations and those after a line break
+    let paragraph = match elements[1].as_inner() {
+        BlockElement::Paragraph(x) => x,
+        x => panic!("Unexpected element: {:?}", x),
+    };
+    let bold = &paragraph.content.elements[1];
+    assert_eq!(
+        positions(bold.region()),
+        (Position::new(3, 22), Position::new(3, 28))
+    );
+    match bold.as_inner() {
+        InlineElement::DecoratedText(x) => assert_eq!(
+            positions(x.as_contents()[0].region()),
+            (Position::new(3, 23), Position::new(3, 27))
+        ),
+        x => panic!("Unexpected element: {:?}", x),
+    }
+    assert_eq!(
+        positions(paragraph.content.elements[11].region()),
+        (Position::new(4, 1), Position::new(4, 14))
+    );
+
+    // List items are positioned from their prefix to the start of the next
+    // line, and their contents from where their text begins
+    let list = match elements[2].as_inner() {
+        BlockElement::List(x) => x,
+        x => panic!("Unexpected element: {:?}", x),
+    };
+    let last_item = list.items.last().unwrap();
+    assert_eq!(
+        positions(last_item.region()),
+        (Position::new(13, 1), Position::new(14, 1))
+    );
+    assert_eq!(
+        positions(last_item.contents.contents[0].region()),
+        (Position::new(13, 3), Position::new(13, 31))
+    );
+}
@@ -109,6 +109,8 @@ impl_macro_vimwiki!(line_comment, Located<LineComment>);
 impl_macro_vimwiki!(multi_line_comment, Located<MultiLineComment>);
 impl_macro_vimwiki!(definition_list, Located<DefinitionList>);
 impl_macro_vimwiki!(divider, Located<Divider>);
+impl_macro_vimwiki!(footnote_def, Located<FootnoteDef>);
+impl_macro_vimwiki!(footnote_ref, Located<FootnoteRef>);
 impl_macro_vimwiki!(header, Located<Header>);
 impl_macro_vimwiki!(link, Located<Link>);
 impl_macro_vimwiki!(diary_link, Located<DiaryLink>);
@@ -1,4 +1,6 @@
-use crate::tokens::{utils::root_crate, Tokenize, TokenizeContext};
+use crate::tokens::{
+    utils::root_crate, utils::tokenize_option, Tokenize, TokenizeContext,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use vimwiki::Blockquote;
@@ -10,9 +12,14 @@ fn tokenize_blockquote(
 ) -> TokenStream {
     let root = root_crate();
     let lines = blockquote.lines().iter().map(|x| do_tokenize!(ctx, x));
+    let attribution =
+        tokenize_option(ctx, &blockquote.attribution, |ctx, x| {
+            do_tokenize!(ctx, x)
+        });
     quote! {
         #root::Blockquote {
             lines: ::std::vec![#(#lines),*],
+            attribution: #attribution,
         }
     }
 }
@@ -44,3 +44,27 @@ fn tokenize_definition_list_value(
         #root::DefinitionListValue::new(#inner)
     }
 }
+
+impl_tokenize!(tokenize_definition, Definition<'a>, 'a);
+fn tokenize_definition(
+    ctx: &TokenizeContext,
+    definition: &Definition,
+) -> TokenStream {
+    let root = root_crate();
+    match definition {
+        Definition::Inline(x) => {
+            let t = do_tokenize!(ctx, &x);
+            quote! { #root::Definition::Inline(#t) }
+        }
+        Definition::WithList { value, list } => {
+            let value = do_tokenize!(ctx, &value);
+            let list = do_tokenize!(ctx, &list);
+            quote! {
+                #root::Definition::WithList {
+                    value: #value,
+                    list: #list,
+                }
+            }
+        }
+    }
+}
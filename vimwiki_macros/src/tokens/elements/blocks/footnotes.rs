@@ -0,0 +1,21 @@
+use crate::tokens::{utils::root_crate, Tokenize, TokenizeContext};
+use proc_macro2::TokenStream;
+use quote::quote;
+use vimwiki::FootnoteDef;
+
+impl_tokenize!(tokenize_footnote_def, FootnoteDef<'a>, 'a);
+fn tokenize_footnote_def(
+    ctx: &TokenizeContext,
+    footnote_def: &FootnoteDef,
+) -> TokenStream {
+    let root = root_crate();
+    let FootnoteDef { label, content } = footnote_def;
+    let label_t = do_tokenize!(ctx, &label);
+    let content_t = do_tokenize!(ctx, &content);
+    quote! {
+        #root::FootnoteDef {
+            label: #label_t,
+            content: #content_t,
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use crate::tokens::{utils::root_crate, Tokenize, TokenizeContext};
+use crate::tokens::{
+    utils::root_crate, utils::tokenize_option, Tokenize, TokenizeContext,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use vimwiki::Header;
@@ -10,13 +12,17 @@ fn tokenize_header(ctx: &TokenizeContext, header: &Header) -> TokenStream {
         level,
         content,
         centered,
+        custom_id,
     } = header;
     let content_t = do_tokenize!(ctx, &content);
+    let custom_id_t =
+        tokenize_option(ctx, custom_id, |ctx, x| do_tokenize!(ctx, x));
     quote! {
         #root::Header {
             level: #level,
             content: #content_t,
             centered: #centered,
+            custom_id: #custom_id_t,
         }
     }
 }
@@ -0,0 +1,18 @@
+use crate::tokens::{utils::root_crate, Tokenize, TokenizeContext};
+use proc_macro2::TokenStream;
+use quote::quote;
+use vimwiki::FootnoteRef;
+
+impl_tokenize!(tokenize_footnote_ref, FootnoteRef<'a>, 'a);
+fn tokenize_footnote_ref(
+    ctx: &TokenizeContext,
+    footnote_ref: &FootnoteRef,
+) -> TokenStream {
+    let root = root_crate();
+    let label = do_tokenize!(ctx, &footnote_ref.label);
+    quote! {
+        #root::FootnoteRef {
+            label: #label,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::tokens::{utils::root_crate, Tokenize, TokenizeContext};
+use proc_macro2::TokenStream;
+use quote::quote;
+use vimwiki::Hashtag;
+
+impl_tokenize!(tokenize_hashtag, Hashtag<'a>, 'a);
+fn tokenize_hashtag(ctx: &TokenizeContext, hashtag: &Hashtag) -> TokenStream {
+    let root = root_crate();
+    let name = do_tokenize!(ctx, &hashtag.name);
+    quote! {
+        #root::Hashtag {
+            name: #name,
+        }
+    }
+}
@@ -9,7 +9,7 @@ use quote::quote;
 use vimwiki::{
     Anchor, Description, DiaryLink, ExternalFileLink, ExternalFileLinkScheme,
     IndexedInterWikiLink, InterWikiLink, Link, NamedInterWikiLink, RawLink,
-    TransclusionLink, WikiLink,
+    ReferenceLink, TransclusionLink, WikiLink,
 };
 
 impl_tokenize!(tokenize_link, Link<'a>, 'a);
@@ -32,6 +32,10 @@ fn tokenize_link(ctx: &TokenizeContext, link: &Link) -> TokenStream {
             let t = tokenize_raw_link(ctx, &x);
             quote! { #root::Link::Raw(#t) }
         }
+        Link::Reference(x) => {
+            let t = tokenize_reference_link(ctx, x);
+            quote! { #root::Link::Reference(#t) }
+        }
         Link::Transclusion(x) => {
             let t = tokenize_transclusion_link(ctx, &x);
             quote! { #root::Link::Transclusion(#t) }
@@ -49,13 +53,16 @@ fn tokenize_diary_link(
     diary_link: &DiaryLink,
 ) -> TokenStream {
     let root = root_crate();
-    let date = do_tokenize!(ctx, &diary_link.date);
+    let date =
+        tokenize_option(ctx, &diary_link.date, |ctx, x| do_tokenize!(ctx, x));
+    let date_str = do_tokenize!(ctx, &diary_link.date_str);
     let description =
         tokenize_option(ctx, &diary_link.description, tokenize_description);
     let anchor = tokenize_option(ctx, &diary_link.anchor, tokenize_anchor);
     quote! {
         #root::DiaryLink {
             date: #date,
+            date_str: #date_str,
             description: #description,
             anchor: #anchor,
         }
@@ -108,9 +115,30 @@ impl_tokenize!(tokenize_raw_link, RawLink<'a>, 'a);
 fn tokenize_raw_link(ctx: &TokenizeContext, raw_link: &RawLink) -> TokenStream {
     let root = root_crate();
     let uri = do_tokenize!(ctx, &raw_link.uri);
+    let description =
+        tokenize_option(ctx, &raw_link.description, tokenize_description);
     quote! {
         #root::RawLink {
             uri: #uri,
+            description: #description,
+        }
+    }
+}
+
+impl_tokenize!(tokenize_reference_link, ReferenceLink<'a>, 'a);
+fn tokenize_reference_link(
+    ctx: &TokenizeContext,
+    reference_link: &ReferenceLink,
+) -> TokenStream {
+    let root = root_crate();
+    let description = tokenize_description(ctx, &reference_link.description);
+    let label = do_tokenize!(ctx, &reference_link.label);
+    let target = do_tokenize!(ctx, &reference_link.target);
+    quote! {
+        #root::ReferenceLink {
+            description: #description,
+            label: #label,
+            target: #target,
         }
     }
 }
@@ -227,6 +255,10 @@ fn tokenize_description(
             let t = do_tokenize!(ctx, &x);
             quote! { #root::Description::Uri(#t) }
         }
+        Description::Inline(x) => {
+            let t = do_tokenize!(ctx, &x);
+            quote! { #root::Description::Inline(#t) }
+        }
     }
 }
 
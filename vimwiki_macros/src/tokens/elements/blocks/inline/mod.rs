@@ -5,6 +5,8 @@ use vimwiki::{InlineElement, InlineElementContainer};
 
 pub mod code;
 pub mod comments;
+pub mod footnotes;
+pub mod hashtags;
 pub mod links;
 pub mod math;
 pub mod tags;
@@ -62,6 +64,14 @@ fn tokenize_inline_element(
             let t = do_tokenize!(ctx, &x);
             quote! { #root::InlineElement::Math(#t) }
         }
+        InlineElement::FootnoteRef(x) => {
+            let t = do_tokenize!(ctx, &x);
+            quote! { #root::InlineElement::FootnoteRef(#t) }
+        }
+        InlineElement::Hashtag(x) => {
+            let t = do_tokenize!(ctx, &x);
+            quote! { #root::InlineElement::Hashtag(#t) }
+        }
         InlineElement::Comment(x) => {
             let t = do_tokenize!(ctx, &x);
             quote! { #root::InlineElement::Comment(#t) }
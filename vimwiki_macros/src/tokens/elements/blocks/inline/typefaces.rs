@@ -35,6 +35,10 @@ fn tokenize_decorated_text_content(
             let t = do_tokenize!(ctx, &x);
             quote! { #root::DecoratedTextContent::Text(#t) }
         }
+        DecoratedTextContent::FootnoteRef(x) => {
+            let t = do_tokenize!(ctx, &x);
+            quote! { #root::DecoratedTextContent::FootnoteRef(#t) }
+        }
     }
 }
 
@@ -204,6 +204,9 @@ fn tokenize_list_item_todo_status(
         ListItemTodoStatus::PartiallyComplete3 => {
             quote! { #root::ListItemTodoStatus::PartiallyComplete3 }
         }
+        ListItemTodoStatus::InProgress => {
+            quote! { #root::ListItemTodoStatus::InProgress }
+        }
         ListItemTodoStatus::Complete => {
             quote! { #root::ListItemTodoStatus::Complete }
         }
@@ -6,6 +6,7 @@ use vimwiki::{BlockElement, InlineBlockElement};
 pub mod blockquotes;
 pub mod definitions;
 pub mod dividers;
+pub mod footnotes;
 pub mod headers;
 pub mod inline;
 pub mod lists;
@@ -13,6 +14,7 @@ pub mod math;
 pub mod paragraphs;
 pub mod placeholders;
 pub mod preformatted;
+pub mod signatures;
 pub mod tables;
 
 impl_tokenize!(tokenize_block_element, BlockElement<'a>, 'a);
@@ -34,6 +36,10 @@ fn tokenize_block_element(
             let t = do_tokenize!(ctx, &x);
             quote! { #root::BlockElement::Divider(#t) }
         }
+        BlockElement::FootnoteDef(x) => {
+            let t = do_tokenize!(ctx, &x);
+            quote! { #root::BlockElement::FootnoteDef(#t) }
+        }
         BlockElement::Header(x) => {
             let t = do_tokenize!(ctx, &x);
             quote! { #root::BlockElement::Header(#t) }
@@ -58,6 +64,10 @@ fn tokenize_block_element(
             let t = do_tokenize!(ctx, &x);
             quote! { #root::BlockElement::PreformattedText(#t) }
         }
+        BlockElement::Signature(x) => {
+            let t = do_tokenize!(ctx, &x);
+            quote! { #root::BlockElement::Signature(#t) }
+        }
         BlockElement::Table(x) => {
             let t = do_tokenize!(ctx, &x);
             quote! { #root::BlockElement::Table(#t) }
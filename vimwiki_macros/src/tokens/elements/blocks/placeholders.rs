@@ -14,6 +14,20 @@ fn tokenize_placeholder(
             let t = do_tokenize!(ctx, &x);
             quote! { #root::Placeholder::Date(#t) }
         }
+        Placeholder::Include(x) => {
+            let t = do_tokenize!(ctx, &x);
+            quote! { #root::Placeholder::Include(#t) }
+        }
+        Placeholder::LinkReference { label, target } => {
+            let label_t = do_tokenize!(ctx, &label);
+            let target_t = do_tokenize!(ctx, &target);
+            quote! {
+                #root::Placeholder::LinkReference {
+                    label: #label_t,
+                    target: #target_t,
+                }
+            }
+        }
         Placeholder::NoHtml => {
             quote! { #root::Placeholder::NoHtml }
         }
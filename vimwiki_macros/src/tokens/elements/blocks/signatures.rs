@@ -0,0 +1,16 @@
+use crate::tokens::{utils::root_crate, Tokenize, TokenizeContext};
+use proc_macro2::TokenStream;
+use quote::quote;
+use vimwiki::Signature;
+
+impl_tokenize!(tokenize_signature, Signature<'a>, 'a);
+fn tokenize_signature(
+    ctx: &TokenizeContext,
+    signature: &Signature,
+) -> TokenStream {
+    let root = root_crate();
+    let inner = do_tokenize!(ctx, &signature.0);
+    quote! {
+        #root::Signature::new(#inner)
+    }
+}
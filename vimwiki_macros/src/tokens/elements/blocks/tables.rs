@@ -1,4 +1,6 @@
-use crate::tokens::{utils::root_crate, Tokenize, TokenizeContext};
+use crate::tokens::{
+    utils::root_crate, utils::tokenize_option, Tokenize, TokenizeContext,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use vimwiki::{Cell, ColumnAlign, Row, Table};
@@ -8,10 +10,13 @@ fn tokenize_table(ctx: &TokenizeContext, table: &Table) -> TokenStream {
     let root = root_crate();
     let rows = table.rows.iter().map(|x| do_tokenize!(ctx, x));
     let centered = table.centered;
+    let caption =
+        tokenize_option(ctx, &table.caption, |ctx, x| do_tokenize!(ctx, x));
     quote! {
         #root::Table {
             rows: ::std::vec![#(#rows),*],
             centered: #centered,
+            caption: #caption,
         }
     }
 }
@@ -27,10 +27,18 @@ fn tokenize_region(_ctx: &TokenizeContext, region: &Region) -> TokenStream {
     let root = root_crate();
     let offset = region.offset();
     let len = region.len();
+    let start_line = region.start_position().line();
+    let start_column = region.start_position().column();
+    let end_line = region.end_position().line();
+    let end_column = region.end_position().column();
     quote! {
         #root::Region::new(
             #offset,
             #len,
         )
+        .with_positions(
+            #root::Position::new(#start_line, #start_column),
+            #root::Position::new(#end_line, #end_column),
+        )
     }
 }
@@ -51,7 +51,7 @@ fn vimwiki_blockquote() {
     let x = vimwiki_blockquote!("> some text");
     assert_eq!(
         x.into_inner(),
-        Blockquote::new(vec![Cow::from("some text")])
+        Blockquote::new(vec![Cow::from("some text")], None)
     );
 }
 
@@ -96,10 +96,12 @@ fn vimwiki_definition_list() {
                         InlineElement::from(Text::from("term"))
                     )])
                 )),
-                vec![Located::from(DefinitionListValue::new(
-                    InlineElementContainer::new(vec![Located::from(
-                        InlineElement::from(Text::from("definition"))
-                    )])
+                vec![Located::from(Definition::Inline(
+                    DefinitionListValue::new(InlineElementContainer::new(
+                        vec![Located::from(InlineElement::from(Text::from(
+                            "definition"
+                        )))]
+                    ))
                 ))],
             ),
             (
@@ -109,15 +111,19 @@ fn vimwiki_definition_list() {
                     )])
                 )),
                 vec![
-                    Located::from(DefinitionListValue::new(
-                        InlineElementContainer::new(vec![Located::from(
-                            InlineElement::from(Text::from("def 2"))
-                        )])
+                    Located::from(Definition::Inline(
+                        DefinitionListValue::new(InlineElementContainer::new(
+                            vec![Located::from(InlineElement::from(
+                                Text::from("def 2")
+                            ))]
+                        ))
                     )),
-                    Located::from(DefinitionListValue::new(
-                        InlineElementContainer::new(vec![Located::from(
-                            InlineElement::from(Text::from("def 3"))
-                        )])
+                    Located::from(Definition::Inline(
+                        DefinitionListValue::new(InlineElementContainer::new(
+                            vec![Located::from(InlineElement::from(
+                                Text::from("def 3")
+                            ))]
+                        ))
                     ))
                 ],
             )
@@ -141,7 +147,8 @@ fn vimwiki_header() {
             InlineElementContainer::new(vec![Located::from(
                 InlineElement::from(Text::from("header"))
             )]),
-            false
+            false,
+            None
         )
     );
 }
@@ -182,7 +189,7 @@ fn vimwiki_raw_link() {
     let x = vimwiki_raw_link!("https://example.com");
     assert_eq!(
         x.into_inner(),
-        RawLink::new(
+        RawLink::from(
             URI::try_from("https://example.com").unwrap().into_owned()
         )
     );
@@ -373,6 +380,10 @@ fn vimwiki_placeholder() {
         vimwiki_placeholder!("%date 2012-03-05").into_inner(),
         Placeholder::Date(NaiveDate::from_ymd(2012, 3, 5)),
     );
+    assert_eq!(
+        vimwiki_placeholder!("#include other.wiki").into_inner(),
+        Placeholder::Include(Cow::from("other.wiki")),
+    );
     assert_eq!(
         vimwiki_placeholder!("%nohtml").into_inner(),
         Placeholder::NoHtml,
@@ -439,7 +450,8 @@ fn vimwiki_table() {
                     )])
                 ))],
             })],
-            false
+            false,
+            None
         )
     );
 }
@@ -53,7 +53,7 @@ fn vimwiki_blockquote() {
     let x = vimwiki_blockquote_format!("> some {} text", "cool");
     assert_eq!(
         x.into_inner(),
-        Blockquote::new(vec![Cow::from("some cool text")])
+        Blockquote::new(vec![Cow::from("some cool text")], None)
     );
 }
 
@@ -107,10 +107,12 @@ fn vimwiki_definition_list() {
                         InlineElement::from(Text::from("term"))
                     )])
                 )),
-                vec![Located::from(DefinitionListValue::new(
-                    InlineElementContainer::new(vec![Located::from(
-                        InlineElement::from(Text::from("first definition"))
-                    )])
+                vec![Located::from(Definition::Inline(
+                    DefinitionListValue::new(InlineElementContainer::new(
+                        vec![Located::from(InlineElement::from(Text::from(
+                            "first definition"
+                        )))]
+                    ))
                 ))],
             ),
             (
@@ -120,15 +122,19 @@ fn vimwiki_definition_list() {
                     )])
                 )),
                 vec![
-                    Located::from(DefinitionListValue::new(
-                        InlineElementContainer::new(vec![Located::from(
-                            InlineElement::from(Text::from("def 2"))
-                        )])
+                    Located::from(Definition::Inline(
+                        DefinitionListValue::new(InlineElementContainer::new(
+                            vec![Located::from(InlineElement::from(
+                                Text::from("def 2")
+                            ))]
+                        ))
                     )),
-                    Located::from(DefinitionListValue::new(
-                        InlineElementContainer::new(vec![Located::from(
-                            InlineElement::from(Text::from("def third 3"))
-                        )])
+                    Located::from(Definition::Inline(
+                        DefinitionListValue::new(InlineElementContainer::new(
+                            vec![Located::from(InlineElement::from(
+                                Text::from("def third 3")
+                            ))]
+                        ))
                     ))
                 ],
             )
@@ -146,7 +152,8 @@ fn vimwiki_header() {
             InlineElementContainer::new(vec![Located::from(
                 InlineElement::from(Text::from("cool header"))
             )]),
-            false
+            false,
+            None
         )
     );
 }
@@ -383,7 +390,8 @@ fn vimwiki_table() {
                     )])
                 ))],
             })],
-            false
+            false,
+            None
         )
     );
 }